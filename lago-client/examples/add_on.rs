@@ -0,0 +1,100 @@
+use lago_client::LagoClient;
+use lago_types::{
+    models::PaginationParams,
+    requests::{
+        add_on::{
+            CreateAddOnInput, CreateAddOnRequest, DeleteAddOnRequest, GetAddOnRequest,
+            ListAddOnsRequest, UpdateAddOnInput, UpdateAddOnRequest,
+        },
+        invoice::{CreateInvoiceFeeInput, CreateInvoiceInput, CreateInvoiceRequest},
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = LagoClient::from_env()?;
+
+    // Create an add-on
+    let input = CreateAddOnInput::new(
+        "Setup Fee".to_string(),
+        "setup_fee".to_string(),
+        50000, // $500.00 in cents
+        "USD".to_string(),
+    )
+    .with_invoice_display_name("Onboarding".to_string())
+    .with_description("One-time onboarding and setup".to_string());
+
+    let create_request = CreateAddOnRequest::new(input);
+    match client.create_add_on(create_request).await {
+        Ok(response) => {
+            println!("Created add-on: {}", response.add_on.code);
+            println!("  Name: {}", response.add_on.name);
+            println!(
+                "  Amount: {} {}",
+                response.add_on.amount_cents, response.add_on.amount_currency
+            );
+        }
+        Err(e) => println!("Add-on may already exist or error: {e}"),
+    }
+
+    // List add-ons with pagination
+    println!("\n--- Listing add-ons ---");
+    let list_request =
+        ListAddOnsRequest::new().with_pagination(PaginationParams::default().with_per_page(10));
+    let add_ons = client.list_add_ons(Some(list_request)).await?;
+    println!(
+        "Found {} add-ons (page {}/{})",
+        add_ons.add_ons.len(),
+        add_ons.meta.current_page,
+        add_ons.meta.total_pages
+    );
+
+    for add_on in &add_ons.add_ons {
+        println!(
+            "  - {} ({}): {} {}",
+            add_on.name, add_on.code, add_on.amount_cents, add_on.amount_currency
+        );
+    }
+
+    // Get a specific add-on
+    println!("\n--- Getting specific add-on ---");
+    let get_request = GetAddOnRequest::new("setup_fee".to_string());
+    let add_on = client.get_add_on(get_request).await?.add_on;
+    println!("Retrieved add-on: {}", add_on.code);
+    println!(
+        "  Invoice Display Name: {}",
+        add_on.invoice_display_name.as_deref().unwrap_or("N/A")
+    );
+
+    // Update the add-on price
+    println!("\n--- Updating add-on ---");
+    let update_input = UpdateAddOnInput::new().with_amount(60000, "USD".to_string());
+    let update_request = UpdateAddOnRequest::new("setup_fee".to_string(), update_input);
+    match client.update_add_on(update_request).await {
+        Ok(response) => println!("Updated amount: {}", response.add_on.amount_cents),
+        Err(e) => println!("Failed to update add-on: {e}"),
+    }
+
+    // Bill the add-on through a one-off invoice
+    println!("\n--- Creating one-off invoice ---");
+    let fee = CreateInvoiceFeeInput::from_add_on(&add_on, 1.0);
+    let invoice_input =
+        CreateInvoiceInput::new("customer_123".to_string(), "USD".to_string(), vec![fee]);
+    match client
+        .create_invoice(CreateInvoiceRequest::new(invoice_input))
+        .await
+    {
+        Ok(response) => println!("Created invoice: {:?}", response.invoice.lago_id),
+        Err(e) => println!("Failed to create invoice: {e}"),
+    }
+
+    // Delete the add-on
+    println!("\n--- Deleting add-on ---");
+    let delete_request = DeleteAddOnRequest::new("setup_fee".to_string());
+    match client.delete_add_on(delete_request).await {
+        Ok(response) => println!("Deleted add-on: {}", response.add_on.code),
+        Err(e) => println!("Failed to delete add-on: {e}"),
+    }
+
+    Ok(())
+}
@@ -4,7 +4,8 @@ use lago_types::filters::invoice::InvoiceFilters;
 use lago_types::models::{CreditNoteReason, InvoiceStatus};
 use lago_types::requests::credit_note::{
     CreateCreditNoteInput, CreateCreditNoteItemInput, CreateCreditNoteRequest,
-    GetCreditNoteRequest, ListCreditNotesRequest,
+    DownloadCreditNoteRequest, EstimateCreditNoteInput, EstimateCreditNoteRequest,
+    GetCreditNoteRequest, ListCreditNotesRequest, VoidCreditNoteRequest,
 };
 use lago_types::requests::invoice::{GetInvoiceRequest, ListInvoicesRequest};
 
@@ -97,6 +98,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         credit_amount,
     )];
 
+    // Check the amounts Lago allows before creating anything
+    let estimate_input = EstimateCreditNoteInput::new(invoice_lago_id.clone(), items.clone());
+    let estimate = client
+        .estimate_credit_note(EstimateCreditNoteRequest::new(estimate_input))
+        .await?
+        .estimated_credit_note;
+    println!(
+        "Estimate: up to {} cents creditable, {} cents refundable, {} cents of taxes",
+        estimate.max_creditable_amount_cents,
+        estimate.max_refundable_amount_cents,
+        estimate.taxes_amount_cents
+    );
+
     let input = CreateCreditNoteInput::new(
         invoice_lago_id.clone(),
         CreditNoteReason::Other,
@@ -188,5 +202,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         filtered.credit_notes.len()
     );
 
+    // Step 9: Download the credit note PDF
+    println!("\n=== Downloading the credit note ===");
+    let download_request = DownloadCreditNoteRequest::new(created_credit_note_id.clone());
+    match client.download_credit_note(download_request).await {
+        Ok(response) => match response.credit_note.file_url {
+            Some(url) => println!("PDF available at: {url}"),
+            None => println!("PDF generation in progress, try again later"),
+        },
+        Err(e) => println!("Failed to download credit note: {e}"),
+    }
+
+    // Step 10: Void the remaining credit
+    println!("\n=== Voiding the credit note ===");
+    let void_request = VoidCreditNoteRequest::new(created_credit_note_id);
+    match client.void_credit_note(void_request).await {
+        Ok(response) => println!(
+            "Voided credit note: {} ({:?})",
+            response.credit_note.number, response.credit_note.credit_status
+        ),
+        Err(e) => println!("Failed to void credit note: {e}"),
+    }
+
     Ok(())
 }
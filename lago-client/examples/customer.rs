@@ -1,11 +1,12 @@
 use lago_client::LagoClient;
 use lago_types::{
     filters::customer::CustomerFilter,
-    models::{CustomerFinalizeZeroAmountInvoice, CustomerType},
+    models::{CustomerFinalizeZeroAmountInvoice, CustomerIntegrationType, CustomerType},
     requests::customer::{
-        CreateCustomerBillingConfiguration, CreateCustomerInput, CreateCustomerMetadata,
-        CreateCustomerRequest, CreateCustomerShippingAddress, GetCustomerRequest,
-        ListCustomersRequest,
+        CreateCustomerBillingConfiguration, CreateCustomerInput, CreateCustomerIntegration,
+        CreateCustomerMetadata, CreateCustomerRequest, CreateCustomerShippingAddress,
+        DeleteCustomerRequest, GenerateCustomerCheckoutUrlRequest, GetCustomerPortalUrlRequest,
+        GetCustomerRequest, ListCustomersRequest, UpdateCustomerInput, UpdateCustomerRequest,
     },
 };
 
@@ -92,15 +93,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let customer = client.get_customer(get_request).await?;
     println!("Retrieved customer: {:?}", customer.customer.external_id);
 
-    // Example 5: Update an existing customer
-    let updated_customer = CreateCustomerInput::new("customer_123".to_string())
+    // Example 5: Update an existing customer, leaving the other fields untouched
+    let updated_customer = UpdateCustomerInput::new()
         .with_name("Acme Corporation Ltd.".to_string())
         .with_email("accounts@acme.com".to_string())
-        .with_phone("+1-555-0124".to_string());
+        .with_phone("+1-555-0124".to_string())
+        .with_integration_customers(vec![
+            CreateCustomerIntegration::new(
+                CustomerIntegrationType::Netsuite,
+                "netsuite".to_string(),
+            )
+            .with_subsidiary_id("2".to_string())
+            .with_sync_with_provider(true),
+        ]);
 
-    let update_request = CreateCustomerRequest::new(updated_customer);
-    let updated = client.create_customer(update_request).await?;
+    let update_request = UpdateCustomerRequest::new("customer_123".to_string(), updated_customer);
+    let updated = client.update_customer(update_request).await?;
     println!("Updated customer: {:?}", updated.customer.external_id);
 
+    // Example 6: Get the customer portal URL
+    let portal_request = GetCustomerPortalUrlRequest::new("customer_123".to_string());
+    let portal = client.get_customer_portal_url(portal_request).await?;
+    println!("Customer portal: {}", portal.customer.portal_url);
+
+    // Example 7: Generate a checkout URL to collect a payment method
+    let checkout_request = GenerateCustomerCheckoutUrlRequest::new("customer_123".to_string());
+    match client
+        .generate_customer_checkout_url(checkout_request)
+        .await
+    {
+        Ok(response) => println!("Checkout URL: {}", response.customer.checkout_url),
+        Err(e) => println!("No checkout URL (is a payment provider set?): {e}"),
+    }
+
+    // Example 8: Delete the customer
+    let delete_request = DeleteCustomerRequest::new("customer_123".to_string());
+    let deleted = client.delete_customer(delete_request).await?;
+    println!("Deleted customer: {:?}", deleted.customer.external_id);
+
     Ok(())
 }
@@ -66,11 +66,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             }
                         }
                     }
-                    Err(LagoError::Api {
-                        status: 404,
-                        message,
-                    }) => {
-                        println!("Customer or subscription not found: {}", message);
+                    Err(LagoError::NotFound { resource }) => {
+                        println!("Not found: {}", resource);
                     }
                     Err(e) => {
                         println!("Error getting customer usage: {}", e);
@@ -113,7 +110,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         );
                         println!("  Total (cents): {}", usage.total_amount_cents);
                     }
-                    Err(LagoError::Api { status: 404, .. }) => {
+                    Err(LagoError::NotFound { .. }) => {
                         println!("Customer or subscription not found");
                     }
                     Err(e) => {
@@ -2,7 +2,10 @@ use lago_client::LagoClient;
 use lago_types::{
     error::LagoError,
     models::PaginationParams,
-    requests::event::{CreateEventInput, CreateEventRequest, GetEventRequest, ListEventsRequest},
+    requests::event::{
+        CreateEventInput, CreateEventRequest, CreateEventsBatchRequest, GetEventRequest,
+        ListEventsRequest,
+    },
 };
 use serde_json::json;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -20,7 +23,9 @@ async fn create_or_get_event(
             println!("Created event: {}", response.event.transaction_id);
             Ok(response.event.transaction_id)
         }
-        Err(LagoError::Api { status: 422, .. }) => {
+        Err(e @ LagoError::Validation { .. })
+            if e.field_errors("transaction_id") == ["value_already_exist"] =>
+        {
             // Event already exists, fetch it instead
             println!("Event {} already exists, fetching it...", transaction_id);
             let get_request = GetEventRequest::new(transaction_id.clone());
@@ -166,6 +171,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => println!("Failed to list filtered events: {}", e),
     }
 
+    // Example 8: Create events in batches
+    println!("\n--- Example 8: Create events in batches ---");
+    let batch_events = (0..250)
+        .map(|i| {
+            CreateEventInput::for_customer(
+                format!("txn_batch_{}_{}", unique_id, i),
+                "customer_123".to_string(),
+                "api_calls".to_string(),
+            )
+            .with_properties(json!({"calls": 1}))
+        })
+        .collect();
+
+    let response = client
+        .create_events_batch(CreateEventsBatchRequest::new(batch_events))
+        .await?;
+    println!(
+        "Sent {} chunks, {} events acknowledged",
+        response.chunks.len(),
+        response.events().count()
+    );
+    for chunk in response.chunks.iter().filter(|chunk| !chunk.is_success()) {
+        println!(
+            "  - chunk of {} events rejected: {:?}",
+            chunk.transaction_ids.len(),
+            chunk.error
+        );
+    }
+
     println!("\n--- All examples completed successfully! ---");
     Ok(())
 }
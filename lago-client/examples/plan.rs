@@ -116,11 +116,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
             created_plan_code = Some(response.plan.code.clone());
         }
-        Err(LagoError::Api {
-            status: 422,
-            message,
-        }) => {
-            println!("Plan creation failed (validation error): {}", message);
+        Err(LagoError::Validation { code, details }) => {
+            println!(
+                "Plan creation failed (validation error): {} {:?}",
+                code, details
+            );
         }
         Err(e) => {
             println!("Error creating plan: {}", e);
@@ -222,7 +222,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("  Code: {}", response.plan.code);
                 println!("  Name: {}", response.plan.name);
             }
-            Err(LagoError::Api { status: 404, .. }) => {
+            Err(LagoError::NotFound { .. }) => {
                 println!("Plan not found");
             }
             Err(e) => println!("Error: {}", e),
@@ -65,13 +65,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("  Billing Time: {:?}", response.subscription.billing_time);
                 created_subscription_id = Some(response.subscription.external_id.clone());
             }
-            Err(LagoError::Api {
-                status: 422,
-                message,
-            }) => {
+            Err(LagoError::Validation { code, details }) => {
                 println!(
-                    "Subscription creation failed (validation error): {}",
-                    message
+                    "Subscription creation failed (validation error): {} {:?}",
+                    code, details
                 );
             }
             Err(e) => {
@@ -114,7 +111,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("  Name: {}", name);
                 }
             }
-            Err(LagoError::Api { status: 404, .. }) => {
+            Err(LagoError::NotFound { .. }) => {
                 println!("Subscription not found");
             }
             Err(e) => {
@@ -316,7 +313,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("  External ID: {}", response.subscription.external_id);
                 println!("  Status: {:?}", response.subscription.status);
             }
-            Err(LagoError::Api { status: 404, .. }) => {
+            Err(LagoError::NotFound { .. }) => {
                 println!("Subscription not found");
             }
             Err(e) => println!("Error: {}", e),
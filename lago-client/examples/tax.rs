@@ -0,0 +1,106 @@
+use lago_client::LagoClient;
+use lago_types::{
+    models::PaginationParams,
+    requests::{
+        customer::{AttachCustomerTaxesRequest, DetachCustomerTaxesRequest},
+        tax::{
+            CreateTaxInput, CreateTaxRequest, DeleteTaxRequest, GetTaxRequest, ListTaxesRequest,
+            UpdateTaxInput, UpdateTaxRequest,
+        },
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = LagoClient::from_env()?;
+
+    // Create a tax
+    let input = CreateTaxInput::new("VAT".to_string(), "vat_20".to_string(), 20.0)
+        .with_description("French standard VAT".to_string());
+
+    let create_request = CreateTaxRequest::new(input);
+    match client.create_tax(create_request).await {
+        Ok(response) => {
+            println!("Created tax: {}", response.tax.code);
+            println!("  Rate: {}%", response.tax.rate);
+        }
+        Err(e) => println!("Tax may already exist or error: {e}"),
+    }
+
+    // List taxes with pagination
+    println!("\n--- Listing taxes ---");
+    let list_request =
+        ListTaxesRequest::new().with_pagination(PaginationParams::default().with_per_page(10));
+    let taxes = client.list_taxes(Some(list_request)).await?;
+    println!(
+        "Found {} taxes (page {}/{})",
+        taxes.taxes.len(),
+        taxes.meta.current_page,
+        taxes.meta.total_pages
+    );
+
+    for tax in &taxes.taxes {
+        println!(
+            "  - {} ({}): {}%{}",
+            tax.name,
+            tax.code,
+            tax.rate,
+            if tax.applied_to_organization {
+                " [organization]"
+            } else {
+                ""
+            }
+        );
+    }
+
+    // Get a specific tax
+    println!("\n--- Getting specific tax ---");
+    let get_request = GetTaxRequest::new("vat_20".to_string());
+    let tax = client.get_tax(get_request).await?.tax;
+    println!("Retrieved tax: {}", tax.code);
+    println!("  Customers: {}", tax.customers_count.unwrap_or_default());
+
+    // Update the tax rate
+    println!("\n--- Updating tax ---");
+    let update_input = UpdateTaxInput::new().with_rate(21.0);
+    let update_request = UpdateTaxRequest::new("vat_20".to_string(), update_input);
+    match client.update_tax(update_request).await {
+        Ok(response) => println!("Updated rate: {}%", response.tax.rate),
+        Err(e) => println!("Failed to update tax: {e}"),
+    }
+
+    // Attach the tax to a customer, then detach it
+    println!("\n--- Attaching tax to customer ---");
+    let attach_request =
+        AttachCustomerTaxesRequest::new("customer_123".to_string(), vec!["vat_20".to_string()]);
+    match client.attach_customer_taxes(attach_request).await {
+        Ok(response) => {
+            let codes: Vec<String> = response
+                .customer
+                .taxes
+                .unwrap_or_default()
+                .into_iter()
+                .map(|tax| tax.code)
+                .collect();
+            println!("Customer taxes: {codes:?}");
+        }
+        Err(e) => println!("Failed to attach tax: {e}"),
+    }
+
+    let detach_request =
+        DetachCustomerTaxesRequest::new("customer_123".to_string(), vec!["vat_20".to_string()]);
+    match client.detach_customer_taxes(detach_request).await {
+        Ok(_) => println!("Detached tax from customer"),
+        Err(e) => println!("Failed to detach tax: {e}"),
+    }
+
+    // Delete the tax
+    println!("\n--- Deleting tax ---");
+    let delete_request = DeleteTaxRequest::new("vat_20".to_string());
+    match client.delete_tax(delete_request).await {
+        Ok(response) => println!("Deleted tax: {}", response.tax.code),
+        Err(e) => println!("Failed to delete tax: {e}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use lago_client::LagoClient;
+use lago_types::{
+    filters::wallet::WalletTransactionFilter,
+    models::{RecurringTransactionInterval, WalletTransactionMetadata, WalletTransactionType},
+    requests::wallet::{
+        CreateWalletInput, CreateWalletRequest, CreateWalletTransactionInput,
+        CreateWalletTransactionRequest, GetWalletRequest, ListWalletTransactionsRequest,
+        ListWalletsRequest, RecurringTransactionRuleInput, TerminateWalletRequest,
+        UpdateWalletInput, UpdateWalletRequest,
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = LagoClient::from_env()?;
+    let customer_id = "customer_123".to_string();
+
+    // Create a wallet with 100 paid credits and a monthly top-up
+    let input = CreateWalletInput::new(customer_id.clone(), "1.0".to_string(), "USD".to_string())
+        .with_name("Prepaid credits".to_string())
+        .with_paid_credits("100".to_string())
+        .with_granted_credits("10".to_string())
+        .with_recurring_transaction_rules(vec![
+            RecurringTransactionRuleInput::interval(RecurringTransactionInterval::Monthly)
+                .with_fixed_credits("50".to_string(), "0".to_string()),
+        ]);
+
+    let wallet = client
+        .create_wallet(CreateWalletRequest::new(input))
+        .await?
+        .wallet;
+    println!("Created wallet: {}", wallet.lago_id);
+    println!("  Credits balance: {}", wallet.credits_balance);
+    println!("  Balance: {} {}", wallet.balance_cents, wallet.currency);
+
+    // Retrieve the wallet
+    let wallet = client
+        .get_wallet(GetWalletRequest::new(wallet.lago_id.to_string()))
+        .await?
+        .wallet;
+    println!("\nRetrieved wallet: {:?}", wallet.name);
+
+    // Add a top-up rule triggered when the balance drops below 20 credits
+    let update = UpdateWalletInput::new()
+        .with_name("Prepaid credits (auto top-up)".to_string())
+        .with_recurring_transaction_rules(vec![
+            RecurringTransactionRuleInput::threshold("20".to_string())
+                .with_target_ongoing_balance("200".to_string()),
+        ]);
+    let updated = client
+        .update_wallet(UpdateWalletRequest::new(wallet.lago_id.to_string(), update))
+        .await?;
+    println!("Updated wallet: {:?}", updated.wallet.name);
+
+    // Top up paid credits and void some of the granted ones
+    let transaction =
+        CreateWalletTransactionInput::top_up(wallet.lago_id.to_string(), "25".to_string())
+            .with_voided_credits("5".to_string())
+            .with_metadata(vec![WalletTransactionMetadata::new(
+                "reason".to_string(),
+                "manual adjustment".to_string(),
+            )]);
+    let transactions = client
+        .create_wallet_transaction(CreateWalletTransactionRequest::new(transaction))
+        .await?;
+    for transaction in &transactions.wallet_transactions {
+        println!(
+            "  Transaction {}: {:?} {} credits ({:?})",
+            transaction.lago_id,
+            transaction.transaction_type,
+            transaction.credit_amount,
+            transaction.status
+        );
+    }
+
+    // List inbound transactions of the wallet
+    let request = ListWalletTransactionsRequest::new(wallet.lago_id.to_string()).with_filters(
+        WalletTransactionFilter::new().with_transaction_type(WalletTransactionType::Inbound),
+    );
+    let inbound = client.list_wallet_transactions(request).await?;
+    println!(
+        "\nFound {} inbound transactions",
+        inbound.wallet_transactions.len()
+    );
+
+    // List the wallets of the customer
+    let wallets = client
+        .list_wallets(ListWalletsRequest::new(customer_id.clone()))
+        .await?;
+    println!(
+        "Customer {} has {} wallets",
+        customer_id,
+        wallets.wallets.len()
+    );
+
+    // Terminate the wallet
+    let terminated = client
+        .terminate_wallet(TerminateWalletRequest::new(wallet.lago_id.to_string()))
+        .await?;
+    println!("Terminated wallet: {:?}", terminated.wallet.status);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use lago_client::LagoClient;
+use lago_types::{
+    models::WebhookSignatureAlgorithm,
+    requests::webhook_endpoint::{
+        CreateWebhookEndpointInput, CreateWebhookEndpointRequest, DeleteWebhookEndpointRequest,
+        GetWebhookEndpointRequest, GetWebhookPublicKeyRequest, ListWebhookEndpointsRequest,
+        UpdateWebhookEndpointInput, UpdateWebhookEndpointRequest,
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = LagoClient::from_env()?;
+
+    // Register a webhook endpoint
+    let input = CreateWebhookEndpointInput::new("https://example.com/webhooks/lago".to_string())
+        .with_signature_algo(WebhookSignatureAlgorithm::Jwt);
+
+    let create_request = CreateWebhookEndpointRequest::new(input);
+    let endpoint = client
+        .create_webhook_endpoint(create_request)
+        .await?
+        .webhook_endpoint;
+    println!("Created webhook endpoint: {}", endpoint.lago_id);
+    println!("  URL: {}", endpoint.webhook_url);
+    println!("  Signature: {}", endpoint.signature_algo);
+
+    // List webhook endpoints
+    println!("\n--- Listing webhook endpoints ---");
+    let endpoints = client
+        .list_webhook_endpoints(Some(ListWebhookEndpointsRequest::new()))
+        .await?;
+    for endpoint in &endpoints.webhook_endpoints {
+        println!("  - {} ({})", endpoint.webhook_url, endpoint.signature_algo);
+    }
+
+    // Get the endpoint
+    println!("\n--- Getting specific webhook endpoint ---");
+    let get_request = GetWebhookEndpointRequest::new(endpoint.lago_id.to_string());
+    let endpoint = client
+        .get_webhook_endpoint(get_request)
+        .await?
+        .webhook_endpoint;
+    println!("Retrieved webhook endpoint: {}", endpoint.webhook_url);
+
+    // Switch it to HMAC signatures
+    println!("\n--- Updating webhook endpoint ---");
+    let update_input =
+        UpdateWebhookEndpointInput::new().with_signature_algo(WebhookSignatureAlgorithm::Hmac);
+    let update_request =
+        UpdateWebhookEndpointRequest::new(endpoint.lago_id.to_string(), update_input);
+    match client.update_webhook_endpoint(update_request).await {
+        Ok(response) => println!(
+            "Updated signature: {}",
+            response.webhook_endpoint.signature_algo
+        ),
+        Err(e) => println!("Failed to update webhook endpoint: {e}"),
+    }
+
+    // Fetch the public key used to sign JWT webhooks
+    println!("\n--- Fetching webhook public key ---");
+    let public_key = client
+        .get_webhook_public_key(GetWebhookPublicKeyRequest::new())
+        .await?
+        .webhook
+        .public_key;
+    println!("Public key: {}...", &public_key[..public_key.len().min(32)]);
+
+    let verifier = client.webhook_verifier().await?;
+    println!("Verifier ready for {} signatures", verifier.algorithm());
+
+    // Delete the endpoint
+    println!("\n--- Deleting webhook endpoint ---");
+    let delete_request = DeleteWebhookEndpointRequest::new(endpoint.lago_id.to_string());
+    match client.delete_webhook_endpoint(delete_request).await {
+        Ok(response) => println!(
+            "Deleted webhook endpoint: {}",
+            response.webhook_endpoint.webhook_url
+        ),
+        Err(e) => println!("Failed to delete webhook endpoint: {e}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,430 @@
+//! The [`LagoApi`] trait, implemented by [`LagoClient`].
+//!
+//! Applications that depend on `impl LagoApi` (or `Arc<dyn LagoApi>`) instead
+//! of `LagoClient` can substitute a fake in their own tests. With the `mock`
+//! feature enabled, a [`mockall`](https://docs.rs/mockall) generated
+//! `MockLagoApi` is available with one `expect_*` method per operation.
+//!
+//! The trait covers every API operation. The `*_stream` helpers are not part
+//! of it since they are built on top of the `list_*` operations of the client.
+
+use async_trait::async_trait;
+
+use lago_types::{
+    error::Result,
+    requests::{
+        activity_log::{GetActivityLogRequest, ListActivityLogsRequest},
+        add_on::{
+            CreateAddOnRequest, DeleteAddOnRequest, GetAddOnRequest, ListAddOnsRequest,
+            UpdateAddOnRequest,
+        },
+        api_log::{GetApiLogRequest, ListApiLogsRequest},
+        applied_coupon::{ApplyCouponRequest, ListAppliedCouponsRequest},
+        billable_metric::{
+            CreateBillableMetricRequest, GetBillableMetricRequest, ListBillableMetricsRequest,
+            UpdateBillableMetricRequest,
+        },
+        coupon::{
+            CreateCouponRequest, DeleteCouponRequest, GetCouponRequest, ListCouponsRequest,
+            UpdateCouponRequest,
+        },
+        credit_note::{
+            CreateCreditNoteRequest, DownloadCreditNoteRequest, EstimateCreditNoteRequest,
+            GetCreditNoteRequest, ListCreditNotesRequest, UpdateCreditNoteRequest,
+            VoidCreditNoteRequest,
+        },
+        customer::{
+            AttachCustomerTaxesRequest, CreateCustomerRequest, DeleteCustomerRequest,
+            DetachCustomerTaxesRequest, GenerateCustomerCheckoutUrlRequest,
+            GetCustomerPortalUrlRequest, GetCustomerRequest, ListCustomersRequest,
+            UpdateCustomerRequest,
+        },
+        customer_usage::GetCustomerCurrentUsageRequest,
+        event::{CreateEventRequest, CreateEventsBatchRequest, GetEventRequest, ListEventsRequest},
+        fee::{GetFeeRequest, ListFeesRequest},
+        invoice::{
+            CreateInvoiceRequest, DownloadInvoiceRequest, GetInvoiceRequest, InvoicePreviewRequest,
+            ListCustomerInvoicesRequest, ListInvoicesRequest, RefreshInvoiceRequest,
+            RetryInvoicePaymentRequest, RetryInvoiceRequest, UpdateInvoiceRequest,
+            VoidInvoiceRequest,
+        },
+        payment::{
+            CreatePaymentRequest, GetPaymentRequest, ListCustomerPaymentsRequest,
+            ListPaymentsRequest,
+        },
+        plan::{
+            CreatePlanRequest, DeletePlanRequest, GetPlanRequest, ListPlansRequest,
+            UpdatePlanRequest,
+        },
+        plan_charge::{
+            CreatePlanChargeFilterRequest, CreatePlanChargeRequest, CreatePlanFixedChargeRequest,
+            DeletePlanChargeFilterRequest, DeletePlanChargeRequest, DeletePlanFixedChargeRequest,
+            GetPlanChargeFilterRequest, GetPlanChargeRequest, GetPlanFixedChargeRequest,
+            ListPlanChargeFiltersRequest, ListPlanChargesRequest, ListPlanFixedChargesRequest,
+            UpdatePlanChargeFilterRequest, UpdatePlanChargeRequest, UpdatePlanFixedChargeRequest,
+        },
+        subscription::{
+            CreateSubscriptionRequest, DeleteSubscriptionRequest, GetSubscriptionRequest,
+            ListCustomerSubscriptionsRequest, ListSubscriptionsRequest, UpdateSubscriptionRequest,
+        },
+        subscription_charge::{
+            CreateSubscriptionChargeFilterRequest, DeleteSubscriptionChargeFilterRequest,
+            GetSubscriptionChargeFilterRequest, GetSubscriptionChargeRequest,
+            GetSubscriptionFixedChargeRequest, ListSubscriptionChargeFiltersRequest,
+            ListSubscriptionChargesRequest, ListSubscriptionFixedChargesRequest,
+            UpdateSubscriptionChargeFilterRequest, UpdateSubscriptionChargeRequest,
+            UpdateSubscriptionFixedChargeRequest,
+        },
+        tax::{
+            CreateTaxRequest, DeleteTaxRequest, GetTaxRequest, ListTaxesRequest, UpdateTaxRequest,
+        },
+        wallet::{
+            CreateWalletRequest, CreateWalletTransactionRequest, GetWalletRequest,
+            ListWalletTransactionsRequest, ListWalletsRequest, TerminateWalletRequest,
+            UpdateWalletRequest,
+        },
+        webhook_endpoint::{
+            CreateWebhookEndpointRequest, DeleteWebhookEndpointRequest, GetWebhookEndpointRequest,
+            GetWebhookPublicKeyRequest, ListWebhookEndpointsRequest, UpdateWebhookEndpointRequest,
+        },
+    },
+    responses::{
+        activity_log::{GetActivityLogResponse, ListActivityLogsResponse},
+        add_on::{
+            CreateAddOnResponse, DeleteAddOnResponse, GetAddOnResponse, ListAddOnsResponse,
+            UpdateAddOnResponse,
+        },
+        api_log::{GetApiLogResponse, ListApiLogsResponse},
+        applied_coupon::{ApplyCouponResponse, ListAppliedCouponsResponse},
+        billable_metric::{
+            CreateBillableMetricResponse, GetBillableMetricResponse, ListBillableMetricsResponse,
+            UpdateBillableMetricResponse,
+        },
+        coupon::{
+            CreateCouponResponse, DeleteCouponResponse, GetCouponResponse, ListCouponsResponse,
+            UpdateCouponResponse,
+        },
+        credit_note::{
+            CreateCreditNoteResponse, DownloadCreditNoteResponse, EstimateCreditNoteResponse,
+            GetCreditNoteResponse, ListCreditNotesResponse, UpdateCreditNoteResponse,
+            VoidCreditNoteResponse,
+        },
+        customer::{
+            CreateCustomerResponse, DeleteCustomerResponse, GenerateCustomerCheckoutUrlResponse,
+            GetCustomerPortalUrlResponse, GetCustomerResponse, ListCustomersResponse,
+            UpdateCustomerResponse, UpdateCustomerTaxesResponse,
+        },
+        customer_usage::GetCustomerCurrentUsageResponse,
+        event::{
+            CreateEventResponse, CreateEventsBatchResponse, GetEventResponse, ListEventsResponse,
+        },
+        fee::{GetFeeResponse, ListFeesResponse},
+        invoice::{
+            CreateInvoiceResponse, DownloadInvoiceResponse, GetInvoiceResponse,
+            InvoicePreviewResponse, ListInvoicesResponse, RefreshInvoiceResponse,
+            RetryInvoicePaymentResponse, RetryInvoiceResponse, UpdateInvoiceResponse,
+            VoidInvoiceResponse,
+        },
+        payment::{CreatePaymentResponse, GetPaymentResponse, ListPaymentsResponse},
+        plan::{
+            CreatePlanResponse, DeletePlanResponse, GetPlanResponse, ListPlansResponse,
+            UpdatePlanResponse,
+        },
+        plan_charge::{
+            CreatePlanChargeFilterResponse, CreatePlanChargeResponse,
+            CreatePlanFixedChargeResponse, DeletePlanChargeFilterResponse,
+            DeletePlanChargeResponse, DeletePlanFixedChargeResponse, GetPlanChargeFilterResponse,
+            GetPlanChargeResponse, GetPlanFixedChargeResponse, ListPlanChargeFiltersResponse,
+            ListPlanChargesResponse, ListPlanFixedChargesResponse, UpdatePlanChargeFilterResponse,
+            UpdatePlanChargeResponse, UpdatePlanFixedChargeResponse,
+        },
+        subscription::{
+            CreateSubscriptionResponse, DeleteSubscriptionResponse, GetSubscriptionResponse,
+            ListSubscriptionsResponse, UpdateSubscriptionResponse,
+        },
+        subscription_charge::{
+            CreateSubscriptionChargeFilterResponse, DeleteSubscriptionChargeFilterResponse,
+            GetSubscriptionChargeFilterResponse, GetSubscriptionChargeResponse,
+            GetSubscriptionFixedChargeResponse, ListSubscriptionChargeFiltersResponse,
+            ListSubscriptionChargesResponse, ListSubscriptionFixedChargesResponse,
+            UpdateSubscriptionChargeFilterResponse, UpdateSubscriptionChargeResponse,
+            UpdateSubscriptionFixedChargeResponse,
+        },
+        tax::{
+            CreateTaxResponse, DeleteTaxResponse, GetTaxResponse, ListTaxesResponse,
+            UpdateTaxResponse,
+        },
+        wallet::{
+            CreateWalletResponse, CreateWalletTransactionResponse, GetWalletResponse,
+            ListWalletTransactionsResponse, ListWalletsResponse, TerminateWalletResponse,
+            UpdateWalletResponse,
+        },
+        webhook_endpoint::{
+            CreateWebhookEndpointResponse, DeleteWebhookEndpointResponse,
+            GetWebhookEndpointResponse, GetWebhookPublicKeyResponse, ListWebhookEndpointsResponse,
+            UpdateWebhookEndpointResponse,
+        },
+    },
+};
+
+use crate::client::LagoClient;
+
+macro_rules! lago_api {
+    ($(
+        fn $name:ident($request:ident $(<$inner:ident>)?) -> $response:ident;
+    )*) => {
+        /// Every operation of the Lago API.
+        ///
+        /// Each method behaves like the [`LagoClient`] method of the same name.
+        ///
+        /// # Example
+        ///
+        /// ```no_run
+        /// use lago_client::{LagoApi, LagoClient};
+        /// use lago_types::requests::customer::GetCustomerRequest;
+        ///
+        /// async fn customer_name(api: &impl LagoApi, id: &str) -> Option<String> {
+        ///     let request = GetCustomerRequest::new(id.to_string());
+        ///     api.get_customer(request).await.ok()?.customer.name
+        /// }
+        ///
+        /// # async fn example() {
+        /// let client = LagoClient::from_env().unwrap();
+        /// let _name = customer_name(&client, "cust_123").await;
+        /// # }
+        /// ```
+        #[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+        #[async_trait]
+        pub trait LagoApi: Send + Sync {
+            $(
+                #[doc = concat!("See [`LagoClient::", stringify!($name), "`].")]
+                async fn $name(&self, request: $request $(<$inner>)?) -> Result<$response>;
+            )*
+        }
+
+        #[async_trait]
+        impl LagoApi for LagoClient {
+            $(
+                async fn $name(&self, request: $request $(<$inner>)?) -> Result<$response> {
+                    LagoClient::$name(self, request).await
+                }
+            )*
+        }
+    };
+}
+
+lago_api! {
+    // customer
+    fn list_customers(Option<ListCustomersRequest>) -> ListCustomersResponse;
+    fn get_customer(GetCustomerRequest) -> GetCustomerResponse;
+    fn create_customer(CreateCustomerRequest) -> CreateCustomerResponse;
+    fn update_customer(UpdateCustomerRequest) -> UpdateCustomerResponse;
+    fn delete_customer(DeleteCustomerRequest) -> DeleteCustomerResponse;
+    fn get_customer_portal_url(GetCustomerPortalUrlRequest) -> GetCustomerPortalUrlResponse;
+    fn generate_customer_checkout_url(GenerateCustomerCheckoutUrlRequest) -> GenerateCustomerCheckoutUrlResponse;
+    fn attach_customer_taxes(AttachCustomerTaxesRequest) -> UpdateCustomerTaxesResponse;
+    fn detach_customer_taxes(DetachCustomerTaxesRequest) -> UpdateCustomerTaxesResponse;
+
+    // customer usage
+    fn get_customer_current_usage(GetCustomerCurrentUsageRequest) -> GetCustomerCurrentUsageResponse;
+
+    // event
+    fn get_event(GetEventRequest) -> GetEventResponse;
+    fn create_event(CreateEventRequest) -> CreateEventResponse;
+    fn create_events_batch(CreateEventsBatchRequest) -> CreateEventsBatchResponse;
+    fn list_events(Option<ListEventsRequest>) -> ListEventsResponse;
+
+    // invoice
+    fn list_invoices(Option<ListInvoicesRequest>) -> ListInvoicesResponse;
+    fn get_invoice(GetInvoiceRequest) -> GetInvoiceResponse;
+    fn preview_invoice(InvoicePreviewRequest) -> InvoicePreviewResponse;
+    fn create_invoice(CreateInvoiceRequest) -> CreateInvoiceResponse;
+    fn update_invoice(UpdateInvoiceRequest) -> UpdateInvoiceResponse;
+    fn list_customer_invoices(ListCustomerInvoicesRequest) -> ListInvoicesResponse;
+    fn refresh_invoice(RefreshInvoiceRequest) -> RefreshInvoiceResponse;
+    fn download_invoice(DownloadInvoiceRequest) -> DownloadInvoiceResponse;
+    fn retry_invoice(RetryInvoiceRequest) -> RetryInvoiceResponse;
+    fn retry_invoice_payment(RetryInvoicePaymentRequest) -> RetryInvoicePaymentResponse;
+    fn void_invoice(VoidInvoiceRequest) -> VoidInvoiceResponse;
+
+    // payment
+    fn list_payments(Option<ListPaymentsRequest>) -> ListPaymentsResponse;
+    fn get_payment(GetPaymentRequest) -> GetPaymentResponse;
+    fn create_payment(CreatePaymentRequest) -> CreatePaymentResponse;
+    fn list_customer_payments(ListCustomerPaymentsRequest) -> ListPaymentsResponse;
+
+    // plan
+    fn list_plans(Option<ListPlansRequest>) -> ListPlansResponse;
+    fn get_plan(GetPlanRequest) -> GetPlanResponse;
+    fn create_plan(CreatePlanRequest) -> CreatePlanResponse;
+    fn update_plan(UpdatePlanRequest) -> UpdatePlanResponse;
+    fn delete_plan(DeletePlanRequest) -> DeletePlanResponse;
+
+    // plan charge
+    fn list_plan_charges(ListPlanChargesRequest) -> ListPlanChargesResponse;
+    fn get_plan_charge(GetPlanChargeRequest) -> GetPlanChargeResponse;
+    fn create_plan_charge(CreatePlanChargeRequest) -> CreatePlanChargeResponse;
+    fn update_plan_charge(UpdatePlanChargeRequest) -> UpdatePlanChargeResponse;
+    fn delete_plan_charge(DeletePlanChargeRequest) -> DeletePlanChargeResponse;
+    fn list_plan_fixed_charges(ListPlanFixedChargesRequest) -> ListPlanFixedChargesResponse;
+    fn get_plan_fixed_charge(GetPlanFixedChargeRequest) -> GetPlanFixedChargeResponse;
+    fn create_plan_fixed_charge(CreatePlanFixedChargeRequest) -> CreatePlanFixedChargeResponse;
+    fn update_plan_fixed_charge(UpdatePlanFixedChargeRequest) -> UpdatePlanFixedChargeResponse;
+    fn delete_plan_fixed_charge(DeletePlanFixedChargeRequest) -> DeletePlanFixedChargeResponse;
+    fn list_plan_charge_filters(ListPlanChargeFiltersRequest) -> ListPlanChargeFiltersResponse;
+    fn get_plan_charge_filter(GetPlanChargeFilterRequest) -> GetPlanChargeFilterResponse;
+    fn create_plan_charge_filter(CreatePlanChargeFilterRequest) -> CreatePlanChargeFilterResponse;
+    fn update_plan_charge_filter(UpdatePlanChargeFilterRequest) -> UpdatePlanChargeFilterResponse;
+    fn delete_plan_charge_filter(DeletePlanChargeFilterRequest) -> DeletePlanChargeFilterResponse;
+
+    // subscription
+    fn list_subscriptions(Option<ListSubscriptionsRequest>) -> ListSubscriptionsResponse;
+    fn get_subscription(GetSubscriptionRequest) -> GetSubscriptionResponse;
+    fn list_customer_subscriptions(ListCustomerSubscriptionsRequest) -> ListSubscriptionsResponse;
+    fn create_subscription(CreateSubscriptionRequest) -> CreateSubscriptionResponse;
+    fn update_subscription(UpdateSubscriptionRequest) -> UpdateSubscriptionResponse;
+    fn delete_subscription(DeleteSubscriptionRequest) -> DeleteSubscriptionResponse;
+
+    // subscription charge
+    fn list_subscription_charges(ListSubscriptionChargesRequest) -> ListSubscriptionChargesResponse;
+    fn get_subscription_charge(GetSubscriptionChargeRequest) -> GetSubscriptionChargeResponse;
+    fn update_subscription_charge(UpdateSubscriptionChargeRequest) -> UpdateSubscriptionChargeResponse;
+    fn list_subscription_fixed_charges(ListSubscriptionFixedChargesRequest) -> ListSubscriptionFixedChargesResponse;
+    fn get_subscription_fixed_charge(GetSubscriptionFixedChargeRequest) -> GetSubscriptionFixedChargeResponse;
+    fn update_subscription_fixed_charge(UpdateSubscriptionFixedChargeRequest) -> UpdateSubscriptionFixedChargeResponse;
+    fn list_subscription_charge_filters(ListSubscriptionChargeFiltersRequest) -> ListSubscriptionChargeFiltersResponse;
+    fn get_subscription_charge_filter(GetSubscriptionChargeFilterRequest) -> GetSubscriptionChargeFilterResponse;
+    fn create_subscription_charge_filter(CreateSubscriptionChargeFilterRequest) -> CreateSubscriptionChargeFilterResponse;
+    fn update_subscription_charge_filter(UpdateSubscriptionChargeFilterRequest) -> UpdateSubscriptionChargeFilterResponse;
+    fn delete_subscription_charge_filter(DeleteSubscriptionChargeFilterRequest) -> DeleteSubscriptionChargeFilterResponse;
+
+    // wallet
+    fn list_wallets(ListWalletsRequest) -> ListWalletsResponse;
+    fn get_wallet(GetWalletRequest) -> GetWalletResponse;
+    fn create_wallet(CreateWalletRequest) -> CreateWalletResponse;
+    fn update_wallet(UpdateWalletRequest) -> UpdateWalletResponse;
+    fn terminate_wallet(TerminateWalletRequest) -> TerminateWalletResponse;
+    fn create_wallet_transaction(CreateWalletTransactionRequest) -> CreateWalletTransactionResponse;
+    fn list_wallet_transactions(ListWalletTransactionsRequest) -> ListWalletTransactionsResponse;
+
+    // billable metric
+    fn list_billable_metrics(Option<ListBillableMetricsRequest>) -> ListBillableMetricsResponse;
+    fn get_billable_metric(GetBillableMetricRequest) -> GetBillableMetricResponse;
+    fn create_billable_metric(CreateBillableMetricRequest) -> CreateBillableMetricResponse;
+    fn update_billable_metric(UpdateBillableMetricRequest) -> UpdateBillableMetricResponse;
+
+    // coupon
+    fn list_coupons(Option<ListCouponsRequest>) -> ListCouponsResponse;
+    fn get_coupon(GetCouponRequest) -> GetCouponResponse;
+    fn create_coupon(CreateCouponRequest) -> CreateCouponResponse;
+    fn update_coupon(UpdateCouponRequest) -> UpdateCouponResponse;
+    fn delete_coupon(DeleteCouponRequest) -> DeleteCouponResponse;
+
+    // add-on
+    fn list_add_ons(Option<ListAddOnsRequest>) -> ListAddOnsResponse;
+    fn get_add_on(GetAddOnRequest) -> GetAddOnResponse;
+    fn create_add_on(CreateAddOnRequest) -> CreateAddOnResponse;
+    fn update_add_on(UpdateAddOnRequest) -> UpdateAddOnResponse;
+    fn delete_add_on(DeleteAddOnRequest) -> DeleteAddOnResponse;
+
+    // applied coupon
+    fn list_applied_coupons(Option<ListAppliedCouponsRequest>) -> ListAppliedCouponsResponse;
+    fn apply_coupon(ApplyCouponRequest) -> ApplyCouponResponse;
+
+    // credit note
+    fn list_credit_notes(Option<ListCreditNotesRequest>) -> ListCreditNotesResponse;
+    fn get_credit_note(GetCreditNoteRequest) -> GetCreditNoteResponse;
+    fn create_credit_note(CreateCreditNoteRequest) -> CreateCreditNoteResponse;
+    fn update_credit_note(UpdateCreditNoteRequest) -> UpdateCreditNoteResponse;
+    fn void_credit_note(VoidCreditNoteRequest) -> VoidCreditNoteResponse;
+    fn download_credit_note(DownloadCreditNoteRequest) -> DownloadCreditNoteResponse;
+    fn estimate_credit_note(EstimateCreditNoteRequest) -> EstimateCreditNoteResponse;
+
+    // tax
+    fn list_taxes(Option<ListTaxesRequest>) -> ListTaxesResponse;
+    fn get_tax(GetTaxRequest) -> GetTaxResponse;
+    fn create_tax(CreateTaxRequest) -> CreateTaxResponse;
+    fn update_tax(UpdateTaxRequest) -> UpdateTaxResponse;
+    fn delete_tax(DeleteTaxRequest) -> DeleteTaxResponse;
+
+    // fee
+    fn list_fees(Option<ListFeesRequest>) -> ListFeesResponse;
+    fn get_fee(GetFeeRequest) -> GetFeeResponse;
+
+    // activity log
+    fn list_activity_logs(Option<ListActivityLogsRequest>) -> ListActivityLogsResponse;
+    fn get_activity_log(GetActivityLogRequest) -> GetActivityLogResponse;
+
+    // api log
+    fn list_api_logs(Option<ListApiLogsRequest>) -> ListApiLogsResponse;
+    fn get_api_log(GetApiLogRequest) -> GetApiLogResponse;
+
+    // webhook endpoint
+    fn list_webhook_endpoints(Option<ListWebhookEndpointsRequest>) -> ListWebhookEndpointsResponse;
+    fn get_webhook_endpoint(GetWebhookEndpointRequest) -> GetWebhookEndpointResponse;
+    fn create_webhook_endpoint(CreateWebhookEndpointRequest) -> CreateWebhookEndpointResponse;
+    fn update_webhook_endpoint(UpdateWebhookEndpointRequest) -> UpdateWebhookEndpointResponse;
+    fn delete_webhook_endpoint(DeleteWebhookEndpointRequest) -> DeleteWebhookEndpointResponse;
+    fn get_webhook_public_key(GetWebhookPublicKeyRequest) -> GetWebhookPublicKeyResponse;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use lago_types::error::LagoError;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{Config, Credentials, InMemoryTransport, Region};
+
+    async fn customer_exists(api: &impl LagoApi, external_id: &str) -> Result<bool> {
+        let request = GetCustomerRequest::new(external_id.to_string());
+        match api.get_customer(request).await {
+            Ok(_) => Ok(true),
+            Err(LagoError::NotFound { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_lago_api_uses_expectations() {
+        let mut api = MockLagoApi::new();
+        api.expect_get_customer()
+            .withf(|request| request.external_id == "cust_123")
+            .times(1)
+            .returning(|_| {
+                Err(LagoError::NotFound {
+                    resource: "customer".to_string(),
+                })
+            });
+
+        assert!(!customer_exists(&api, "cust_123").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn lago_client_implements_lago_api() {
+        let transport = InMemoryTransport::new();
+        transport.push_json(
+            404,
+            &json!({"status": 404, "error": "Not Found", "code": "customer_not_found"}),
+        );
+        let client = LagoClient::new(
+            Config::builder()
+                .credentials(Credentials::new("test-api-key".to_string()))
+                .region(Region::Custom("http://lago.test".to_string()))
+                .transport(Arc::new(transport.clone()))
+                .build(),
+        );
+
+        let api: Arc<dyn LagoApi> = Arc::new(client);
+        let request = GetCustomerRequest::new("cust_123".to_string());
+        let result = api.get_customer(request).await;
+
+        assert!(matches!(result, Err(LagoError::NotFound { resource }) if resource == "customer"));
+        assert_eq!(
+            transport.last_request().unwrap().url,
+            "http://lago.test/customers/cust_123"
+        );
+    }
+}
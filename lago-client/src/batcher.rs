@@ -0,0 +1,532 @@
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{Instant, MissedTickBehavior, interval_at};
+
+use lago_types::error::{LagoError, Result};
+use lago_types::requests::event::{
+    CreateEventInput, CreateEventsBatchRequest, MAX_EVENTS_PER_BATCH,
+};
+
+use crate::client::LagoClient;
+use crate::telemetry::warning;
+
+/// Callback invoked with events that could not be ingested.
+///
+/// The events are passed along with the error returned once the client's
+/// retry policy was exhausted. Panics raised from the callback are caught and
+/// logged so they cannot stop the batcher.
+pub type FailedEventsCallback = Arc<dyn Fn(&[CreateEventInput], &LagoError) + Send + Sync>;
+
+/// Configuration settings for an [`EventBatcher`]
+#[derive(Clone)]
+pub struct EventBatcherConfig {
+    pub(crate) max_batch_size: usize,
+    pub(crate) flush_interval: Duration,
+    pub(crate) capacity: usize,
+    pub(crate) on_failure: Option<FailedEventsCallback>,
+}
+
+impl EventBatcherConfig {
+    /// Creates a new batcher configuration with default settings
+    ///
+    /// # Returns
+    /// A new `EventBatcherConfig` instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new batcher configuration builder
+    ///
+    /// # Returns
+    /// A new `EventBatcherConfigBuilder` instance
+    pub fn builder() -> EventBatcherConfigBuilder {
+        EventBatcherConfigBuilder::new()
+    }
+
+    /// Gets the number of buffered events that triggers a flush
+    pub fn max_batch_size(&self) -> usize {
+        self.max_batch_size
+    }
+
+    /// Gets the maximum time an event stays buffered before being flushed
+    pub fn flush_interval(&self) -> Duration {
+        self.flush_interval
+    }
+
+    /// Gets the capacity of the channel between senders and the batcher
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Default for EventBatcherConfig {
+    /// Creates a default batcher configuration
+    ///
+    /// Events are flushed by batches of 100 or every second, with room for
+    /// 10,000 pending events before `send` waits.
+    fn default() -> Self {
+        Self {
+            max_batch_size: MAX_EVENTS_PER_BATCH,
+            flush_interval: Duration::from_secs(1),
+            capacity: 10_000,
+            on_failure: None,
+        }
+    }
+}
+
+/// Builder for creating customized event batcher configurations
+#[derive(Default)]
+pub struct EventBatcherConfigBuilder {
+    config: EventBatcherConfig,
+}
+
+impl EventBatcherConfigBuilder {
+    /// Creates a new builder with the default settings
+    ///
+    /// # Returns
+    /// A new `EventBatcherConfigBuilder` instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of buffered events that triggers a flush
+    ///
+    /// The value is clamped between 1 and the API batch limit.
+    ///
+    /// # Arguments
+    /// * `max_batch_size` - The number of events per batch
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.config.max_batch_size = max_batch_size.clamp(1, MAX_EVENTS_PER_BATCH);
+        self
+    }
+
+    /// Sets the maximum time an event stays buffered before being flushed
+    ///
+    /// # Arguments
+    /// * `flush_interval` - The flush interval, at least 1 millisecond
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.config.flush_interval = flush_interval.max(Duration::from_millis(1));
+        self
+    }
+
+    /// Sets the number of events that can be pending before `send` waits
+    ///
+    /// # Arguments
+    /// * `capacity` - The capacity of the channel, at least 1
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.config.capacity = capacity.max(1);
+        self
+    }
+
+    /// Sets a callback for events that permanently failed
+    ///
+    /// # Arguments
+    /// * `callback` - The callback to invoke with the failed events and their error
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn on_failure(mut self, callback: FailedEventsCallback) -> Self {
+        self.config.on_failure = Some(callback);
+        self
+    }
+
+    /// Builds the final configuration instance
+    ///
+    /// # Returns
+    /// A new `EventBatcherConfig` instance with the specified settings
+    pub fn build(self) -> EventBatcherConfig {
+        self.config
+    }
+}
+
+enum Command {
+    Event(CreateEventInput),
+    Flush(oneshot::Sender<()>),
+    Shutdown(oneshot::Sender<()>),
+}
+
+/// Buffers usage events and sends them to Lago in the background
+///
+/// Events are queued in a bounded channel and sent through
+/// [`LagoClient::create_events_batch`] whenever `max_batch_size` events are
+/// buffered or `flush_interval` elapses, so the client's retry policy applies
+/// to every batch. When the channel is full, [`EventBatcher::send`] waits for
+/// room, which slows producers down instead of growing memory unbounded.
+///
+/// The handle is cheap to clone; all clones feed the same background task.
+///
+/// # Example
+/// ```no_run
+/// use lago_client::{EventBatcher, EventBatcherConfig, LagoClient};
+/// use lago_types::requests::event::CreateEventInput;
+///
+/// # async fn example() -> lago_types::error::Result<()> {
+/// let client = LagoClient::from_env()?;
+/// let batcher = EventBatcher::new(client, EventBatcherConfig::default());
+///
+/// batcher
+///     .send(CreateEventInput::for_subscription(
+///         "transaction_123".to_string(),
+///         "sub_123".to_string(),
+///         "api_calls".to_string(),
+///     ))
+///     .await?;
+///
+/// // Send the remaining events before the application exits
+/// batcher.shutdown().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct EventBatcher {
+    sender: mpsc::Sender<Command>,
+}
+
+impl EventBatcher {
+    /// Creates a new batcher and spawns its background task
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    /// * `client` - The client used to send the batches
+    /// * `config` - The batcher configuration
+    ///
+    /// # Returns
+    /// A new `EventBatcher` handle
+    pub fn new(client: LagoClient, config: EventBatcherConfig) -> Self {
+        let (sender, receiver) = mpsc::channel(config.capacity);
+        tokio::spawn(Worker::new(client, config).run(receiver));
+
+        Self { sender }
+    }
+
+    /// Queues an event, waiting for room if the channel is full
+    ///
+    /// # Arguments
+    /// * `event` - The event to send
+    ///
+    /// # Returns
+    /// `Ok(())` once the event is queued, or `LagoError::BatcherClosed` if the
+    /// batcher was shut down
+    pub async fn send(&self, event: CreateEventInput) -> Result<()> {
+        self.sender
+            .send(Command::Event(event))
+            .await
+            .map_err(|_| LagoError::BatcherClosed)
+    }
+
+    /// Queues an event without waiting
+    ///
+    /// # Arguments
+    /// * `event` - The event to send
+    ///
+    /// # Returns
+    /// `Ok(())` once the event is queued, `LagoError::BatcherFull` if the
+    /// channel is full, or `LagoError::BatcherClosed` if the batcher was shut down
+    pub fn try_send(&self, event: CreateEventInput) -> Result<()> {
+        self.sender
+            .try_send(Command::Event(event))
+            .map_err(|e| match e {
+                mpsc::error::TrySendError::Full(_) => LagoError::BatcherFull,
+                mpsc::error::TrySendError::Closed(_) => LagoError::BatcherClosed,
+            })
+    }
+
+    /// Sends every event queued so far
+    ///
+    /// # Returns
+    /// `Ok(())` once the events queued before this call were sent or reported
+    /// as failed, or `LagoError::BatcherClosed` if the batcher was shut down
+    pub async fn flush(&self) -> Result<()> {
+        let (ack, done) = oneshot::channel();
+        self.sender
+            .send(Command::Flush(ack))
+            .await
+            .map_err(|_| LagoError::BatcherClosed)?;
+        done.await.map_err(|_| LagoError::BatcherClosed)
+    }
+
+    /// Sends every outstanding event and stops the background task
+    ///
+    /// Events sent through any clone of the handle after this call are
+    /// rejected with `LagoError::BatcherClosed`.
+    ///
+    /// # Returns
+    /// `Ok(())` once the outstanding events were sent or reported as failed,
+    /// or `LagoError::BatcherClosed` if the batcher was already shut down
+    pub async fn shutdown(&self) -> Result<()> {
+        let (ack, done) = oneshot::channel();
+        self.sender
+            .send(Command::Shutdown(ack))
+            .await
+            .map_err(|_| LagoError::BatcherClosed)?;
+        done.await.map_err(|_| LagoError::BatcherClosed)
+    }
+}
+
+struct Worker {
+    client: LagoClient,
+    config: EventBatcherConfig,
+    buffer: Vec<CreateEventInput>,
+}
+
+impl Worker {
+    fn new(client: LagoClient, config: EventBatcherConfig) -> Self {
+        let buffer = Vec::with_capacity(config.max_batch_size);
+        Self {
+            client,
+            config,
+            buffer,
+        }
+    }
+
+    async fn run(mut self, mut receiver: mpsc::Receiver<Command>) {
+        let period = self.config.flush_interval;
+        let mut ticker = interval_at(Instant::now() + period, period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                command = receiver.recv() => match command {
+                    Some(Command::Event(event)) => {
+                        self.buffer.push(event);
+                        if self.buffer.len() >= self.config.max_batch_size {
+                            self.flush().await;
+                            ticker.reset();
+                        }
+                    }
+                    Some(Command::Flush(ack)) => {
+                        self.flush().await;
+                        ticker.reset();
+                        let _ = ack.send(());
+                    }
+                    Some(Command::Shutdown(ack)) => {
+                        receiver.close();
+                        self.drain(&mut receiver).await;
+                        let _ = ack.send(());
+                        return;
+                    }
+                    None => {
+                        self.flush().await;
+                        return;
+                    }
+                },
+                _ = ticker.tick() => self.flush().await,
+            }
+        }
+    }
+
+    /// Sends the events still queued in the closed channel, acknowledging
+    /// the flush and shutdown requests made in the meantime.
+    async fn drain(&mut self, receiver: &mut mpsc::Receiver<Command>) {
+        let mut acks = Vec::new();
+        while let Some(command) = receiver.recv().await {
+            match command {
+                Command::Event(event) => {
+                    self.buffer.push(event);
+                    if self.buffer.len() >= self.config.max_batch_size {
+                        self.flush().await;
+                    }
+                }
+                Command::Flush(ack) | Command::Shutdown(ack) => acks.push(ack),
+            }
+        }
+        self.flush().await;
+
+        for ack in acks {
+            let _ = ack.send(());
+        }
+    }
+
+    async fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let events = std::mem::take(&mut self.buffer);
+        let request = CreateEventsBatchRequest::new(events.clone())
+            .with_chunk_size(self.config.max_batch_size);
+
+        match self.client.create_events_batch(request).await {
+            Ok(response) => {
+                let mut offset = 0;
+                for chunk in response.chunks {
+                    let end = offset + chunk.transaction_ids.len();
+                    if let Some(error) = &chunk.error {
+                        self.report_failure(&events[offset..end], error);
+                    }
+                    offset = end;
+                }
+            }
+            Err(error) => self.report_failure(&events, &error),
+        }
+    }
+
+    /// Invokes the configured `on_failure` callback (if any), catching panics
+    /// so a buggy callback cannot stop the batcher.
+    fn report_failure(&self, events: &[CreateEventInput], error: &LagoError) {
+        let Some(callback) = &self.config.on_failure else {
+            return;
+        };
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| callback(events, error)));
+        if result.is_err() {
+            warning!("event batcher on_failure callback panicked; suppressing");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Credentials, Region, RetryConfig, RetryMode};
+    use mockito::Server;
+    use std::sync::Mutex;
+
+    fn create_test_client(base_url: &str) -> LagoClient {
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom(base_url.to_string()))
+            .retry_config(RetryConfig::builder().mode(RetryMode::Off).build())
+            .build();
+
+        LagoClient::new(config)
+    }
+
+    fn event(i: usize) -> CreateEventInput {
+        CreateEventInput::for_customer(
+            format!("tx_{i}"),
+            "customer_123".to_string(),
+            "api_calls".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_flushes_on_batch_size() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/events/batch")
+            .with_status(200)
+            .with_body(r#"{"events": []}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let config = EventBatcherConfig::builder()
+            .max_batch_size(2)
+            .flush_interval(Duration::from_secs(3600))
+            .build();
+        let batcher = EventBatcher::new(create_test_client(&server.url()), config);
+
+        for i in 0..4 {
+            batcher.send(event(i)).await.unwrap();
+        }
+        batcher.flush().await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_flushes_on_interval() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/events/batch")
+            .with_status(200)
+            .with_body(r#"{"events": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = EventBatcherConfig::builder()
+            .flush_interval(Duration::from_millis(50))
+            .build();
+        let batcher = EventBatcher::new(create_test_client(&server.url()), config);
+
+        batcher.send(event(0)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_zero_flush_interval_still_flushes() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/events/batch")
+            .with_status(200)
+            .with_body(r#"{"events": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = EventBatcherConfig::builder()
+            .flush_interval(Duration::ZERO)
+            .build();
+        let batcher = EventBatcher::new(create_test_client(&server.url()), config);
+
+        batcher.send(event(0)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_and_reports_failures() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/events/batch")
+            .with_status(422)
+            .with_body(r#"{"status": 422, "error": "Unprocessable Entity"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let failed = Arc::new(Mutex::new(Vec::new()));
+        let failed_clone = Arc::clone(&failed);
+        let config = EventBatcherConfig::builder()
+            .flush_interval(Duration::from_secs(3600))
+            .on_failure(Arc::new(move |events, error| {
+                assert!(matches!(error, LagoError::Validation { .. }));
+                let mut failed = failed_clone.lock().unwrap();
+                failed.extend(events.iter().map(|e| e.transaction_id.clone()));
+            }))
+            .build();
+        let batcher = EventBatcher::new(create_test_client(&server.url()), config);
+
+        batcher.send(event(0)).await.unwrap();
+        batcher.send(event(1)).await.unwrap();
+        batcher.shutdown().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(*failed.lock().unwrap(), vec!["tx_0", "tx_1"]);
+        assert!(matches!(
+            batcher.send(event(2)).await,
+            Err(LagoError::BatcherClosed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_try_send_reports_full_channel() {
+        // No worker consumes the channel, so it stays full after one event.
+        let (sender, _receiver) = mpsc::channel(1);
+        let batcher = EventBatcher { sender };
+
+        batcher.try_send(event(0)).unwrap();
+        assert!(matches!(
+            batcher.try_send(event(1)),
+            Err(LagoError::BatcherFull)
+        ));
+    }
+}
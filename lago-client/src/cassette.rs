@@ -0,0 +1,553 @@
+//! Record/replay of HTTP interactions for regression tests.
+//!
+//! A [`CassetteTransport`] sits in the request path as a [`Transport`]. In
+//! record mode it forwards every request to a real transport and keeps the
+//! request/response pair, writing them to a cassette file once recording is
+//! finished; in replay mode it answers from the cassette without touching the
+//! network. Since it works at the transport
+//! level, every `LagoClient` method can be recorded without endpoint-specific
+//! code.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use lago_client::{CassetteTransport, Config, Credentials, LagoClient, ReqwestTransport};
+//!
+//! # fn example() -> lago_types::error::Result<()> {
+//! // Record once against a real Lago account...
+//! let inner = Arc::new(ReqwestTransport::new(Duration::from_secs(30), "lago-rust-client"));
+//! let recorder = CassetteTransport::record("tests/cassettes/customers.json", inner);
+//! // ... run the calls to record, then write the cassette
+//! recorder.finish()?;
+//!
+//! // ...then replay deterministically afterwards.
+//! let player = CassetteTransport::replay("tests/cassettes/customers.json")?;
+//! let client = LagoClient::new(
+//!     Config::builder()
+//!         .credentials(Credentials::new("unused".to_string()))
+//!         .transport(Arc::new(player))
+//!         .build(),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use lago_types::error::{LagoError, Result};
+
+use crate::telemetry::warning;
+use crate::transport::{Transport, TransportRequest, TransportResponse};
+
+/// Value stored in place of redacted header values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers redacted from recorded requests and responses by default.
+pub const DEFAULT_REDACTED_HEADERS: &[&str] =
+    &["authorization", "x-api-key", "cookie", "set-cookie"];
+
+/// Whether a [`CassetteTransport`] records or replays interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Requests are sent through the inner transport and saved to the cassette.
+    Record,
+    /// Requests are answered from the cassette.
+    Replay,
+}
+
+/// The content of a cassette file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Recorded interactions, in the order they happened.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Loads a cassette from a JSON file
+    ///
+    /// # Arguments
+    /// * `path` - The cassette file
+    ///
+    /// # Returns
+    /// A `Result` containing the cassette, or an error if the file can't be read or parsed
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let content = fs::read(path)?;
+        Ok(serde_json::from_slice(&content)?)
+    }
+
+    /// Writes the cassette to a JSON file, creating parent directories
+    ///
+    /// # Arguments
+    /// * `path` - The cassette file
+    ///
+    /// # Returns
+    /// A `Result` indicating success or an I/O error
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request sent by the client.
+    pub request: RecordedRequest,
+    /// The response returned by the API.
+    pub response: RecordedResponse,
+}
+
+/// A request as stored in a cassette.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method.
+    pub method: String,
+    /// Full request URL, including the query string.
+    pub url: String,
+    /// Request headers, with credentials redacted.
+    pub headers: BTreeMap<String, String>,
+    /// Request body, if any.
+    pub body: Option<Value>,
+}
+
+impl RecordedRequest {
+    fn from_request(request: &TransportRequest, redacted_headers: &[String]) -> Self {
+        Self {
+            method: request.method.clone(),
+            url: request.url.clone(),
+            headers: headers_to_map(&request.headers, redacted_headers),
+            body: request.body.as_deref().map(body_to_value),
+        }
+    }
+
+    /// Whether `request` has the same method, path, query and body.
+    fn matches(&self, request: &RequestKey) -> bool {
+        let (path, query) = RequestKey::url_parts(&self.url);
+
+        self.method.eq_ignore_ascii_case(&request.method)
+            && path == request.path
+            && query == request.query
+            && self.body == request.body
+    }
+}
+
+/// A response as stored in a cassette.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers, with cookies redacted.
+    pub headers: BTreeMap<String, String>,
+    /// Response body: parsed JSON, or a string when the body is not JSON.
+    pub body: Option<Value>,
+}
+
+impl RecordedResponse {
+    fn from_response(response: &TransportResponse, redacted_headers: &[String]) -> Self {
+        Self {
+            status: response.status,
+            headers: headers_to_map(&response.headers, redacted_headers),
+            body: (!response.body.is_empty()).then(|| body_to_value(&response.body)),
+        }
+    }
+
+    fn to_response(&self) -> TransportResponse {
+        let body = match &self.body {
+            None => Vec::new(),
+            Some(Value::String(raw)) => raw.clone().into_bytes(),
+            Some(value) => serde_json::to_vec(value).unwrap_or_default(),
+        };
+
+        self.headers
+            .iter()
+            .fold(
+                TransportResponse::new(self.status),
+                |response, (name, value)| response.with_header(name, value),
+            )
+            .with_body(body)
+    }
+}
+
+/// The parts of a request compared in replay mode.
+struct RequestKey {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<Value>,
+}
+
+impl RequestKey {
+    fn from_request(request: &TransportRequest) -> Self {
+        let (path, query) = Self::url_parts(&request.url);
+
+        Self {
+            method: request.method.clone(),
+            path,
+            query,
+            body: request.body.as_deref().map(body_to_value),
+        }
+    }
+
+    /// Splits a URL into its path and its sorted query pairs, ignoring the host
+    /// so cassettes can be replayed against any region.
+    fn url_parts(url: &str) -> (String, Vec<(String, String)>) {
+        match Url::parse(url) {
+            Ok(url) => {
+                let mut query: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+                query.sort();
+                (url.path().to_string(), query)
+            }
+            Err(_) => (url.to_string(), Vec::new()),
+        }
+    }
+}
+
+/// A [`Transport`] recording interactions to, or replaying them from, a cassette file.
+///
+/// In replay mode each recorded interaction answers at most one request, so
+/// identical requests (pages of a list, retries, ...) get their responses in
+/// the recorded order. A request without a matching interaction fails with
+/// [`LagoError::Configuration`], which the client does not retry.
+///
+/// In record mode the cassette is written by [`CassetteTransport::finish`],
+/// or when the transport is dropped.
+pub struct CassetteTransport {
+    path: PathBuf,
+    inner: Option<Arc<dyn Transport>>,
+    redacted_headers: Vec<String>,
+    state: Mutex<CassetteState>,
+}
+
+struct CassetteState {
+    cassette: Cassette,
+    used: Vec<bool>,
+    /// Whether interactions were recorded since the cassette was last written.
+    unsaved: bool,
+}
+
+impl CassetteTransport {
+    /// Creates a transport recording every interaction to `path`
+    ///
+    /// The cassette starts empty. Interactions are kept in memory and written
+    /// to the file by [`CassetteTransport::finish`], or when the transport is
+    /// dropped. The headers of [`DEFAULT_REDACTED_HEADERS`] are redacted.
+    ///
+    /// # Arguments
+    /// * `path` - The cassette file to write
+    /// * `inner` - The transport requests are forwarded to
+    ///
+    /// # Returns
+    /// A new `CassetteTransport` in record mode
+    pub fn record(path: impl Into<PathBuf>, inner: Arc<dyn Transport>) -> Self {
+        Self {
+            path: path.into(),
+            inner: Some(inner),
+            redacted_headers: DEFAULT_REDACTED_HEADERS
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            state: Mutex::new(CassetteState {
+                cassette: Cassette::default(),
+                used: Vec::new(),
+                unsaved: false,
+            }),
+        }
+    }
+
+    /// Redacts another header from recorded requests and responses
+    ///
+    /// # Arguments
+    /// * `name` - The header name, case-insensitive
+    ///
+    /// # Returns
+    /// The transport instance for method chaining
+    pub fn redact_header(mut self, name: &str) -> Self {
+        self.redacted_headers.push(name.to_ascii_lowercase());
+        self
+    }
+
+    /// Writes the recorded interactions to the cassette file
+    ///
+    /// Does nothing in replay mode, or when nothing was recorded since the
+    /// last write.
+    ///
+    /// # Returns
+    /// A `Result` indicating success or an error if the cassette can't be written
+    pub fn finish(&self) -> Result<()> {
+        let cassette = {
+            let mut state = self.lock();
+            if !state.unsaved {
+                return Ok(());
+            }
+            state.unsaved = false;
+            state.cassette.clone()
+        };
+
+        cassette.save(&self.path)
+    }
+
+    /// Creates a transport answering requests from the cassette at `path`
+    ///
+    /// # Arguments
+    /// * `path` - The cassette file to read
+    ///
+    /// # Returns
+    /// A `Result` containing a `CassetteTransport` in replay mode, or an error
+    /// if the cassette can't be loaded
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+
+        Ok(Self {
+            path,
+            inner: None,
+            redacted_headers: Vec::new(),
+            state: Mutex::new(CassetteState {
+                used: vec![false; cassette.interactions.len()],
+                cassette,
+                unsaved: false,
+            }),
+        })
+    }
+
+    /// Returns whether the transport records or replays.
+    pub fn mode(&self) -> CassetteMode {
+        if self.inner.is_some() {
+            CassetteMode::Record
+        } else {
+            CassetteMode::Replay
+        }
+    }
+
+    /// Returns the path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the interactions recorded or loaded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.lock().cassette.interactions.clone()
+    }
+
+    /// Returns the number of recorded interactions not replayed yet.
+    ///
+    /// Assert it is zero at the end of a test to catch requests the code no
+    /// longer sends.
+    pub fn unused_interactions(&self) -> usize {
+        self.lock().used.iter().filter(|used| !**used).count()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CassetteState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn replay_request(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        let key = RequestKey::from_request(request);
+        let mut state = self.lock();
+        let CassetteState { cassette, used, .. } = &mut *state;
+
+        let index = cassette
+            .interactions
+            .iter()
+            .zip(used.iter())
+            .position(|(interaction, used)| !used && interaction.request.matches(&key))
+            .ok_or_else(|| {
+                LagoError::Configuration(format!(
+                    "Cassette {} has no unused interaction matching {} {}",
+                    self.path.display(),
+                    request.method,
+                    request.url
+                ))
+            })?;
+        used[index] = true;
+
+        Ok(cassette.interactions[index].response.to_response())
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let Some(inner) = &self.inner else {
+            return self.replay_request(&request);
+        };
+
+        let recorded = RecordedRequest::from_request(&request, &self.redacted_headers);
+        let response = inner.send(request).await?;
+
+        let mut state = self.lock();
+        state.cassette.interactions.push(Interaction {
+            request: recorded,
+            response: RecordedResponse::from_response(&response, &self.redacted_headers),
+        });
+        state.used.push(true);
+        state.unsaved = true;
+
+        Ok(response)
+    }
+}
+
+impl Drop for CassetteTransport {
+    fn drop(&mut self) {
+        if let Err(error) = self.finish() {
+            warning!("failed to write cassette {}: {error}", self.path.display());
+        }
+    }
+}
+
+fn headers_to_map(headers: &HeaderMap, redacted_headers: &[String]) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = if redacted_headers
+                .iter()
+                .any(|redacted| redacted == name.as_str())
+            {
+                REDACTED
+            } else {
+                value.to_str().ok()?
+            };
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn body_to_value(body: &[u8]) -> Value {
+    serde_json::from_slice(body)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(body).into_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::customer_json;
+    use crate::{Config, Credentials, InMemoryTransport, LagoClient, Region};
+    use lago_types::models::PaginationParams;
+    use lago_types::requests::customer::GetCustomerRequest;
+    use lago_types::requests::invoice::ListInvoicesRequest;
+    use serde_json::json;
+
+    fn create_client(transport: Arc<dyn Transport>) -> LagoClient {
+        LagoClient::new(
+            Config::builder()
+                .credentials(Credentials::new("secret-api-key".to_string()))
+                .region(Region::Custom("http://lago.test/api/v1".to_string()))
+                .transport(transport)
+                .build(),
+        )
+    }
+
+    #[tokio::test]
+    async fn records_and_replays_interactions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassettes/customer.json");
+
+        let inner = InMemoryTransport::new();
+        inner.push_json(200, &customer_json(&[]));
+        let recorder = Arc::new(CassetteTransport::record(&path, Arc::new(inner)));
+        let customer = create_client(recorder.clone())
+            .get_customer(GetCustomerRequest::new("cust_123".to_string()))
+            .await
+            .unwrap()
+            .customer;
+        assert_eq!(recorder.mode(), CassetteMode::Record);
+        assert!(!path.exists());
+        recorder.finish().unwrap();
+
+        let saved = Cassette::load(&path).unwrap();
+        assert_eq!(saved.interactions.len(), 1);
+        let request = &saved.interactions[0].request;
+        assert_eq!(request.headers["authorization"], REDACTED);
+        assert!(
+            !std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("secret-api-key")
+        );
+
+        let player = Arc::new(CassetteTransport::replay(&path).unwrap());
+        let replayed = create_client(player.clone())
+            .get_customer(GetCustomerRequest::new("cust_123".to_string()))
+            .await
+            .unwrap()
+            .customer;
+        assert_eq!(replayed.lago_id, customer.lago_id);
+        assert_eq!(player.unused_interactions(), 0);
+    }
+
+    #[tokio::test]
+    async fn matches_query_regardless_of_order_and_host() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("invoices.json");
+        Cassette {
+            interactions: vec![Interaction {
+                request: RecordedRequest {
+                    method: "GET".to_string(),
+                    url: "https://api.getlago.com/api/v1/invoices?per_page=10&page=2".to_string(),
+                    headers: BTreeMap::new(),
+                    body: None,
+                },
+                response: RecordedResponse {
+                    status: 200,
+                    headers: BTreeMap::new(),
+                    body: Some(json!({
+                        "invoices": [],
+                        "meta": {"current_page": 2, "total_pages": 2, "total_count": 10}
+                    })),
+                },
+            }],
+        }
+        .save(&path)
+        .unwrap();
+
+        let client = create_client(Arc::new(CassetteTransport::replay(&path).unwrap()));
+        let request = ListInvoicesRequest::new()
+            .with_pagination(PaginationParams::new().with_page(2).with_per_page(10));
+        let response = client.list_invoices(Some(request.clone())).await.unwrap();
+        assert_eq!(response.meta.current_page, 2);
+
+        let result = client.list_invoices(Some(request)).await;
+        assert!(
+            matches!(&result, Err(LagoError::Configuration(message)) if message.contains("GET http://lago.test/api/v1/invoices")),
+            "{result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_cassette_on_drop_with_headers_redacted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("customer.json");
+
+        let inner = InMemoryTransport::new();
+        inner.push_response(
+            TransportResponse::json(200, &customer_json(&[]))
+                .with_header("set-cookie", "session=1"),
+        );
+        let recorder =
+            CassetteTransport::record(&path, Arc::new(inner)).redact_header("User-Agent");
+        create_client(Arc::new(recorder))
+            .get_customer(GetCustomerRequest::new("cust_123".to_string()))
+            .await
+            .unwrap();
+
+        let saved = Cassette::load(&path).unwrap();
+        let interaction = &saved.interactions[0];
+        assert_eq!(interaction.request.headers["authorization"], REDACTED);
+        assert_eq!(interaction.request.headers["user-agent"], REDACTED);
+        assert_eq!(interaction.response.headers["set-cookie"], REDACTED);
+    }
+}
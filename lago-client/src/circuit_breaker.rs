@@ -0,0 +1,730 @@
+//! Circuit breaker around the Lago API.
+//!
+//! Set a [`CircuitBreakerConfig`] with [`crate::ConfigBuilder::circuit_breaker`]
+//! to stop calling the API while it is failing. The breaker watches the
+//! outcome of the last attempts; once the share of transient failures
+//! (requests without a response and 5xx statuses) crosses the threshold it
+//! opens, and requests fail immediately with [`LagoError::CircuitOpen`]
+//! instead of piling up retries. After `open_duration` a few probe requests
+//! are let through: the breaker closes if they all succeed and opens again
+//! otherwise.
+//!
+//! Client errors (4xx, including rate limited responses) come from a healthy
+//! API and are not counted.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use lago_types::error::{LagoError, Result};
+
+use crate::telemetry::warning;
+use crate::transport::TransportResponse;
+
+/// State of a circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// Requests go through and their outcome is recorded.
+    Closed,
+    /// Requests fail immediately with [`LagoError::CircuitOpen`].
+    Open,
+    /// A limited number of probe requests go through to test the API.
+    HalfOpen,
+}
+
+impl CircuitState {
+    /// Returns the state as a label, e.g. for logs and metrics.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Callback invoked with the previous and the new state each time the
+/// circuit breaker changes state.
+///
+/// Panics raised from the callback are caught and logged so they cannot break
+/// the underlying request flow.
+pub type CircuitStateCallback = Arc<dyn Fn(CircuitState, CircuitState) + Send + Sync>;
+
+/// Configuration settings for the circuit breaker
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lago_client::{CircuitBreakerConfig, Config};
+///
+/// # fn main() -> lago_types::error::Result<()> {
+/// let config = Config::builder()
+///     .circuit_breaker(
+///         CircuitBreakerConfig::builder()
+///             .failure_rate_threshold(0.5)
+///             .minimum_requests(20)
+///             .open_duration(Duration::from_secs(10))
+///             .build()?,
+///     )
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    pub(crate) failure_rate_threshold: f64,
+    pub(crate) minimum_requests: u32,
+    pub(crate) window_size: u32,
+    pub(crate) open_duration: Duration,
+    pub(crate) half_open_probes: u32,
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a new circuit breaker configuration with default settings
+    ///
+    /// # Returns
+    /// A new `CircuitBreakerConfig` instance with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new circuit breaker configuration builder
+    ///
+    /// # Returns
+    /// A new `CircuitBreakerConfigBuilder` instance
+    pub fn builder() -> CircuitBreakerConfigBuilder {
+        CircuitBreakerConfigBuilder::new()
+    }
+
+    /// Gets the share of failed attempts, in `[0.0, 1.0]`, that opens the breaker
+    ///
+    /// # Returns
+    /// The failure rate threshold
+    pub fn failure_rate_threshold(&self) -> f64 {
+        self.failure_rate_threshold
+    }
+
+    /// Gets the number of attempts recorded before the failure rate is considered
+    ///
+    /// # Returns
+    /// The minimum number of attempts
+    pub fn minimum_requests(&self) -> u32 {
+        self.minimum_requests
+    }
+
+    /// Gets the number of most recent attempts the failure rate is computed on
+    ///
+    /// # Returns
+    /// The size of the sliding window
+    pub fn window_size(&self) -> u32 {
+        self.window_size
+    }
+
+    /// Gets how long the breaker stays open before probing the API
+    ///
+    /// # Returns
+    /// The open duration
+    pub fn open_duration(&self) -> Duration {
+        self.open_duration
+    }
+
+    /// Gets the number of probe requests that must succeed to close the breaker
+    ///
+    /// # Returns
+    /// The number of half-open probes
+    pub fn half_open_probes(&self) -> u32 {
+        self.half_open_probes
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    /// Creates a default circuit breaker configuration
+    ///
+    /// The breaker opens when half of the last 20 attempts failed, after at
+    /// least 10 attempts, and probes the API with one request after 30 seconds.
+    fn default() -> Self {
+        Self {
+            failure_rate_threshold: 0.5,
+            minimum_requests: 10,
+            window_size: 20,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+/// Builder for creating customized circuit breaker configurations
+pub struct CircuitBreakerConfigBuilder {
+    config: CircuitBreakerConfig,
+}
+
+impl CircuitBreakerConfigBuilder {
+    /// Creates a new builder with default values
+    ///
+    /// # Returns
+    /// A new `CircuitBreakerConfigBuilder` instance
+    pub fn new() -> Self {
+        Self {
+            config: CircuitBreakerConfig::default(),
+        }
+    }
+
+    /// Sets the share of failed attempts that opens the breaker
+    ///
+    /// # Arguments
+    /// * `threshold` - The failure rate, greater than 0.0 and at most 1.0
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn failure_rate_threshold(mut self, threshold: f64) -> Self {
+        self.config.failure_rate_threshold = threshold;
+        self
+    }
+
+    /// Sets the number of attempts recorded before the failure rate is considered
+    ///
+    /// # Arguments
+    /// * `minimum_requests` - The minimum number of attempts, at least 1 and
+    ///   at most the window size
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn minimum_requests(mut self, minimum_requests: u32) -> Self {
+        self.config.minimum_requests = minimum_requests.max(1);
+        self
+    }
+
+    /// Sets the number of most recent attempts the failure rate is computed on
+    ///
+    /// # Arguments
+    /// * `window_size` - The size of the sliding window, at least 1
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn window_size(mut self, window_size: u32) -> Self {
+        self.config.window_size = window_size.max(1);
+        self
+    }
+
+    /// Sets how long the breaker stays open before probing the API
+    ///
+    /// # Arguments
+    /// * `open_duration` - The open duration
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn open_duration(mut self, open_duration: Duration) -> Self {
+        self.config.open_duration = open_duration;
+        self
+    }
+
+    /// Sets the number of probe requests that must succeed to close the breaker
+    ///
+    /// # Arguments
+    /// * `probes` - The number of half-open probes, at least 1
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn half_open_probes(mut self, probes: u32) -> Self {
+        self.config.half_open_probes = probes.max(1);
+        self
+    }
+
+    /// Builds the final circuit breaker configuration
+    ///
+    /// # Returns
+    /// A new `CircuitBreakerConfig` instance with the specified settings, or
+    /// [`LagoError::Configuration`] if the failure rate threshold isn't in
+    /// `(0.0, 1.0]` or `minimum_requests` exceeds `window_size`, as the
+    /// breaker would then open without failures or never open
+    pub fn build(self) -> Result<CircuitBreakerConfig> {
+        let threshold = self.config.failure_rate_threshold;
+        if !(threshold > 0.0 && threshold <= 1.0) {
+            return Err(LagoError::Configuration(format!(
+                "Circuit breaker failure rate threshold must be in (0, 1], got {threshold}"
+            )));
+        }
+        if self.config.minimum_requests > self.config.window_size {
+            return Err(LagoError::Configuration(format!(
+                "Circuit breaker minimum_requests ({}) can't exceed window_size ({})",
+                self.config.minimum_requests, self.config.window_size
+            )));
+        }
+
+        Ok(self.config)
+    }
+}
+
+impl Default for CircuitBreakerConfigBuilder {
+    /// Creates a default circuit breaker configuration builder
+    ///
+    /// This is equivalent to calling `CircuitBreakerConfigBuilder::new()`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A circuit breaker shared by all clones of a client.
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    on_state_change: Option<CircuitStateCallback>,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    /// Incremented on every state change, so attempts started in a previous
+    /// state are not counted in the current one.
+    generation: u64,
+    /// Outcomes of the last attempts while closed, `true` for a failure.
+    outcomes: VecDeque<bool>,
+    opened_at: Option<Instant>,
+    probes_in_flight: u32,
+    probe_successes: u32,
+}
+
+type Transition = Option<(CircuitState, CircuitState)>;
+
+/// An attempt let through by a [`CircuitBreaker`], counted when dropped.
+///
+/// A permit dropped without an outcome, e.g. when the call is cancelled,
+/// releases its probe slot without counting the attempt.
+pub(crate) struct CircuitPermit {
+    breaker: Arc<CircuitBreaker>,
+    generation: u64,
+    probe: bool,
+    failure: Option<bool>,
+}
+
+impl CircuitPermit {
+    /// Records the outcome of the attempt
+    ///
+    /// Requests that got no response and 5xx responses are failures, rate
+    /// limited responses and client errors are not counted and anything else
+    /// is a success.
+    pub(crate) fn record(mut self, result: &Result<TransportResponse>) {
+        self.failure = match result {
+            Ok(response) if (400..=499).contains(&response.status) => None,
+            Ok(response) => Some(response.status >= 500),
+            Err(LagoError::Http(_) | LagoError::Transport(_)) => Some(true),
+            Err(_) => None,
+        };
+    }
+}
+
+impl Drop for CircuitPermit {
+    fn drop(&mut self) {
+        self.breaker
+            .complete(self.generation, self.probe, self.failure, Instant::now());
+    }
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(
+        config: CircuitBreakerConfig,
+        on_state_change: Option<CircuitStateCallback>,
+    ) -> Self {
+        Self {
+            config,
+            on_state_change,
+            state: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                generation: 0,
+                outcomes: VecDeque::new(),
+                opened_at: None,
+                probes_in_flight: 0,
+                probe_successes: 0,
+            }),
+        }
+    }
+
+    /// Returns the current state of the breaker.
+    pub(crate) fn state(&self) -> CircuitState {
+        self.lock().state
+    }
+
+    /// Lets an attempt through, or fails with [`LagoError::CircuitOpen`]
+    ///
+    /// Once `open_duration` has elapsed, an open breaker turns half-open and
+    /// lets up to `half_open_probes` attempts through.
+    pub(crate) fn try_acquire(self: &Arc<Self>, now: Instant) -> Result<CircuitPermit> {
+        let mut transition = None;
+        let result = {
+            let mut state = self.lock();
+            if state.state == CircuitState::Open
+                && state
+                    .opened_at
+                    .is_none_or(|opened_at| now >= opened_at + self.config.open_duration)
+            {
+                transition = state.transition(CircuitState::HalfOpen, now);
+            }
+
+            match state.state {
+                CircuitState::Closed => Ok((state.generation, false)),
+                CircuitState::HalfOpen
+                    if state.probes_in_flight + state.probe_successes
+                        < self.config.half_open_probes =>
+                {
+                    state.probes_in_flight += 1;
+                    Ok((state.generation, true))
+                }
+                _ => Err(LagoError::CircuitOpen),
+            }
+        };
+
+        self.notify(transition);
+        result.map(|(generation, probe)| CircuitPermit {
+            breaker: self.clone(),
+            generation,
+            probe,
+            failure: None,
+        })
+    }
+
+    /// Counts the outcome of an attempt, `Some(true)` for a failure.
+    fn complete(&self, generation: u64, probe: bool, failure: Option<bool>, now: Instant) {
+        let transition = {
+            let mut state = self.lock();
+            if state.generation != generation {
+                return;
+            }
+
+            if probe {
+                state.probes_in_flight = state.probes_in_flight.saturating_sub(1);
+                match failure {
+                    Some(true) => state.transition(CircuitState::Open, now),
+                    Some(false) => {
+                        state.probe_successes += 1;
+                        (state.probe_successes >= self.config.half_open_probes)
+                            .then(|| state.transition(CircuitState::Closed, now))
+                            .flatten()
+                    }
+                    None => None,
+                }
+            } else if let Some(failure) = failure {
+                state.outcomes.push_back(failure);
+                while state.outcomes.len() > self.config.window_size as usize {
+                    state.outcomes.pop_front();
+                }
+
+                let total = state.outcomes.len();
+                let failures = state.outcomes.iter().filter(|failure| **failure).count();
+                (total >= self.config.minimum_requests as usize
+                    && failures as f64 >= self.config.failure_rate_threshold * total as f64)
+                    .then(|| state.transition(CircuitState::Open, now))
+                    .flatten()
+            } else {
+                None
+            }
+        };
+
+        self.notify(transition);
+    }
+
+    /// Invokes the `on_circuit_state_change` callback (if any), catching
+    /// panics so a buggy observer cannot break the request flow.
+    fn notify(&self, transition: Transition) {
+        let Some((from, to)) = transition else {
+            return;
+        };
+        #[cfg(feature = "tracing")]
+        tracing::info!(from = %from, to = %to, "circuit breaker is {to}");
+
+        let Some(callback) = &self.on_state_change else {
+            return;
+        };
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| callback(from, to)));
+        if result.is_err() {
+            warning!("on_circuit_state_change callback panicked; suppressing");
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BreakerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl BreakerState {
+    fn transition(&mut self, to: CircuitState, now: Instant) -> Transition {
+        let from = std::mem::replace(&mut self.state, to);
+        self.generation += 1;
+        self.outcomes.clear();
+        self.probes_in_flight = 0;
+        self.probe_successes = 0;
+        self.opened_at = (to == CircuitState::Open).then_some(now);
+        (from != to).then_some((from, to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Credentials, InMemoryTransport, LagoClient, Region, RetryConfig};
+    use serde_json::{Value, json};
+
+    fn breaker(config: CircuitBreakerConfig) -> Arc<CircuitBreaker> {
+        Arc::new(CircuitBreaker::new(config, None))
+    }
+
+    fn attempt(breaker: &Arc<CircuitBreaker>, status: u16) {
+        breaker
+            .try_acquire(Instant::now())
+            .unwrap()
+            .record(&Ok(TransportResponse::new(status)));
+    }
+
+    #[test]
+    fn rejects_configurations_that_never_or_always_open() {
+        for threshold in [0.0, -0.5, 1.5, f64::NAN] {
+            let result = CircuitBreakerConfig::builder()
+                .failure_rate_threshold(threshold)
+                .build();
+            assert!(matches!(result, Err(LagoError::Configuration(_))));
+        }
+
+        let result = CircuitBreakerConfig::builder()
+            .minimum_requests(30)
+            .window_size(20)
+            .build();
+        assert!(matches!(result, Err(LagoError::Configuration(_))));
+
+        assert!(
+            CircuitBreakerConfig::builder()
+                .failure_rate_threshold(1.0)
+                .minimum_requests(20)
+                .window_size(20)
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn opens_once_failure_rate_crosses_threshold() {
+        let breaker = breaker(
+            CircuitBreakerConfig::builder()
+                .failure_rate_threshold(0.5)
+                .minimum_requests(4)
+                .window_size(4)
+                .build()
+                .unwrap(),
+        );
+
+        for status in [200, 503, 429, 422] {
+            attempt(&breaker, status);
+        }
+        // The 429 and the 422 are not counted: 1 failure out of 2 attempts.
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        attempt(&breaker, 200);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        attempt(&breaker, 500);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            breaker.try_acquire(Instant::now()),
+            Err(LagoError::CircuitOpen)
+        ));
+    }
+
+    #[test]
+    fn sliding_window_forgets_old_failures() {
+        let breaker = breaker(
+            CircuitBreakerConfig::builder()
+                .failure_rate_threshold(0.5)
+                .minimum_requests(5)
+                .window_size(5)
+                .build()
+                .unwrap(),
+        );
+
+        breaker
+            .try_acquire(Instant::now())
+            .unwrap()
+            .record(&Err(LagoError::Transport("connection reset".to_string())));
+        for status in [502, 200, 200, 200, 502] {
+            attempt(&breaker, status);
+        }
+        // 3 failures out of 6 attempts, but only 2 out of the last 5.
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        attempt(&breaker, 502);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        attempt(&breaker, 502);
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn half_open_probes_close_or_reopen_the_circuit() {
+        let breaker = breaker(
+            CircuitBreakerConfig::builder()
+                .minimum_requests(1)
+                .open_duration(Duration::from_secs(30))
+                .half_open_probes(2)
+                .build()
+                .unwrap(),
+        );
+        attempt(&breaker, 503);
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let later = Instant::now() + Duration::from_secs(30);
+        let probe = breaker.try_acquire(later).unwrap();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        let second = breaker.try_acquire(later).unwrap();
+        assert!(matches!(
+            breaker.try_acquire(later),
+            Err(LagoError::CircuitOpen)
+        ));
+
+        probe.record(&Ok(TransportResponse::new(200)));
+        second.record(&Ok(TransportResponse::new(500)));
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let later = Instant::now() + Duration::from_secs(30);
+        breaker
+            .try_acquire(later)
+            .unwrap()
+            .record(&Ok(TransportResponse::new(200)));
+        breaker
+            .try_acquire(later)
+            .unwrap()
+            .record(&Ok(TransportResponse::new(200)));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn dropped_probe_releases_its_slot() {
+        let breaker = breaker(
+            CircuitBreakerConfig::builder()
+                .minimum_requests(1)
+                .build()
+                .unwrap(),
+        );
+        attempt(&breaker, 503);
+
+        let later = Instant::now() + Duration::from_secs(30);
+        drop(breaker.try_acquire(later).unwrap());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker
+            .try_acquire(later)
+            .unwrap()
+            .record(&Ok(TransportResponse::new(200)));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn attempts_started_before_a_state_change_are_ignored() {
+        let breaker = breaker(
+            CircuitBreakerConfig::builder()
+                .minimum_requests(1)
+                .build()
+                .unwrap(),
+        );
+        let slow = breaker.try_acquire(Instant::now()).unwrap();
+        attempt(&breaker, 503);
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        slow.record(&Ok(TransportResponse::new(200)));
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn state_change_callback_panics_are_suppressed() {
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let seen = transitions.clone();
+        let callback: CircuitStateCallback = Arc::new(move |from, to| {
+            seen.lock().unwrap().push((from, to));
+            panic!("observer bug");
+        });
+        let breaker = Arc::new(CircuitBreaker::new(
+            CircuitBreakerConfig::builder()
+                .minimum_requests(1)
+                .build()
+                .unwrap(),
+            Some(callback),
+        ));
+
+        attempt(&breaker, 503);
+        let later = Instant::now() + Duration::from_secs(30);
+        breaker
+            .try_acquire(later)
+            .unwrap()
+            .record(&Ok(TransportResponse::new(200)));
+
+        assert_eq!(
+            *transitions.lock().unwrap(),
+            [
+                (CircuitState::Closed, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Closed),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_fails_fast_while_circuit_is_open() {
+        let transport = InMemoryTransport::new();
+        transport.push_response(TransportResponse::new(503));
+        transport.push_response(TransportResponse::new(503));
+        transport.push_json(200, &json!({}));
+
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let seen = transitions.clone();
+        let client = LagoClient::new(
+            Config::builder()
+                .credentials(Credentials::new("test-api-key".to_string()))
+                .region(Region::Custom("http://lago.test/api/v1".to_string()))
+                .retry_config(
+                    RetryConfig::builder()
+                        .max_attempts(3)
+                        .initial_delay(Duration::from_millis(1))
+                        .build(),
+                )
+                .transport(Arc::new(transport.clone()))
+                .circuit_breaker(
+                    CircuitBreakerConfig::builder()
+                        .minimum_requests(2)
+                        .open_duration(Duration::from_millis(20))
+                        .build()
+                        .unwrap(),
+                )
+                .on_circuit_state_change(Arc::new(move |from, to| {
+                    seen.lock().unwrap().push((from, to))
+                }))
+                .build(),
+        );
+        let clone = client.clone();
+
+        let url = "http://lago.test/api/v1/customers";
+        // The breaker opens after the second 503 and stops the retries.
+        let result: Result<Value> = client
+            .make_request("list_customers", "GET", url, None::<&()>)
+            .await;
+        assert!(matches!(result, Err(LagoError::CircuitOpen)));
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(clone.circuit_state(), Some(CircuitState::Open));
+
+        let result: Result<Value> = clone
+            .make_request("list_customers", "GET", url, None::<&()>)
+            .await;
+        assert!(matches!(result, Err(LagoError::CircuitOpen)));
+        assert_eq!(transport.requests().len(), 2);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let _: Value = clone
+            .make_request("list_customers", "GET", url, None::<&()>)
+            .await
+            .unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+        assert_eq!(transitions.lock().unwrap().len(), 3);
+    }
+}
@@ -1,12 +1,20 @@
-use reqwest::{Client as HttpClient, Response};
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue, USER_AGENT};
 use serde::de::DeserializeOwned;
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
+use uuid::Uuid;
 
 use lago_types::error::{LagoError, Result};
 
+use crate::circuit_breaker::{CircuitBreaker, CircuitState};
+use crate::metrics::{RequestMetrics, RetryReason};
+use crate::retry::{RateLimiter, RetryContext};
+#[cfg(feature = "tracing")]
+use crate::telemetry::TraceContext;
+use crate::telemetry::{RequestTrace, warning};
+use crate::transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
 use crate::{Config, RetryMode};
 
 /// Information about rate limit headers from the API response.
@@ -66,6 +74,9 @@ impl std::fmt::Display for RateLimitInfo {
 /// so they cannot break the underlying request flow.
 pub type RateLimitInfoCallback = Arc<dyn Fn(&RateLimitInfo) + Send + Sync>;
 
+/// Header carrying the idempotency key of unsafe requests.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
 /// The main client for interacting with the Lago API
 ///
 /// This client handles HTTP requests, authentication, retries, and error handling
@@ -73,30 +84,74 @@ pub type RateLimitInfoCallback = Arc<dyn Fn(&RateLimitInfo) + Send + Sync>;
 #[derive(Clone)]
 pub struct LagoClient {
     pub(crate) config: Config,
-    http_client: HttpClient,
+    transport: Arc<dyn Transport>,
+    rate_limiter: Arc<RateLimiter>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    #[cfg(feature = "tracing")]
+    pub(crate) trace_context: Option<TraceContext>,
 }
 
 impl LagoClient {
     /// Creates a new Lago client with the provided configuration
     ///
+    /// Requests are sent through the transport set with
+    /// [`crate::ConfigBuilder::transport`], or a [`ReqwestTransport`] honoring
+    /// the configured timeout and user agent.
+    ///
     /// # Arguments
     /// * `config` - The configuration settings for the client
     ///
     /// # Returns
     /// A new instance of `LagoClient`
     pub fn new(config: Config) -> Self {
-        let http_client = HttpClient::builder()
-            .timeout(config.timeout())
-            .user_agent(config.user_agent())
-            .build()
-            .expect("Failed to create HTTP client");
+        let transport = config.transport.clone().unwrap_or_else(|| {
+            Arc::new(ReqwestTransport::new(config.timeout(), config.user_agent()))
+        });
+        let circuit_breaker = config.circuit_breaker().map(|breaker| {
+            Arc::new(CircuitBreaker::new(
+                breaker.clone(),
+                config.on_circuit_state_change().cloned(),
+            ))
+        });
 
         Self {
             config,
-            http_client,
+            transport,
+            rate_limiter: Arc::default(),
+            circuit_breaker,
+            #[cfg(feature = "tracing")]
+            trace_context: None,
+        }
+    }
+
+    /// Returns a client whose requests continue the given W3C trace
+    ///
+    /// Every request made through the returned client sends a `traceparent`
+    /// header with the trace id of `context`, so the calls can be correlated
+    /// with the incoming request or job that triggered them. Without it, each
+    /// call starts a new trace.
+    ///
+    /// # Arguments
+    /// * `context` - The trace context, e.g. parsed from an incoming `traceparent` header
+    ///
+    /// # Returns
+    /// A copy of the client using the provided trace context
+    #[cfg(feature = "tracing")]
+    pub fn with_trace_context(&self, context: TraceContext) -> Self {
+        Self {
+            trace_context: Some(context),
+            ..self.clone()
         }
     }
 
+    /// Returns the state of the circuit breaker shared by the clones of this client
+    ///
+    /// # Returns
+    /// The current state, or `None` when no circuit breaker is configured
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.state())
+    }
+
     /// Creates a new Lago client using default configuration from environment variables
     ///
     /// This method will use default settings and attempt to load credentials
@@ -116,9 +171,19 @@ impl LagoClient {
     ///
     /// When a rate limit error (429) is encountered, the client will use the
     /// `x-ratelimit-reset` header value as the wait time if available, falling back
-    /// to exponential backoff otherwise.
+    /// to exponential backoff otherwise. In [`RetryMode::Adaptive`], requests also
+    /// wait for a token of the rate limiter shared by all clones of the client.
+    ///
+    /// POST requests carry a generated `Idempotency-Key` header that is the same
+    /// for every attempt of the call. When no key is available, they are only retried on
+    /// rate limit errors, unless `retry_without_idempotency_key` is enabled.
+    ///
+    /// With a circuit breaker configured, every attempt is let through by the
+    /// breaker first, and the call fails with [`LagoError::CircuitOpen`] while
+    /// it is open.
     ///
     /// # Arguments
+    /// * `operation` - The name of the API operation, e.g. `get_customer`
     /// * `method` - The HTTP method (GET, POST, PUT, DELETE)
     /// * `url` - The full URL to make the request to
     /// * `body` - Optional request body that will be serialized as JSON
@@ -127,6 +192,7 @@ impl LagoClient {
     /// A `Result` containing the deserialized response or an error
     pub(crate) async fn make_request<T, B>(
         &self,
+        operation: &'static str,
         method: &str,
         url: &str,
         body: Option<&B>,
@@ -135,66 +201,228 @@ impl LagoClient {
         T: DeserializeOwned,
         B: serde::Serialize,
     {
-        let credentials = self.config.credentials()?;
-        let mut attempt = 0;
-        loop {
-            let _start_time = Instant::now();
-
-            let mut request_builder = match method {
-                "GET" => self.http_client.get(url),
-                "POST" => self.http_client.post(url),
-                "PUT" => self.http_client.put(url),
-                "DELETE" => self.http_client.delete(url),
-                _ => {
-                    return Err(LagoError::Configuration(format!(
-                        "Unsupported method: {method}"
-                    )));
-                }
-            };
+        self.make_request_with_key(operation, method, url, body, None)
+            .await
+    }
 
-            request_builder = request_builder.bearer_auth(credentials.api_key());
+    /// Makes a request with the given idempotency key
+    ///
+    /// Same as [`LagoClient::make_request`], for calls whose request carries
+    /// its own key. POST requests without one get a generated key, and the key
+    /// is ignored for other methods.
+    pub(crate) async fn make_request_with_key<T, B>(
+        &self,
+        operation: &'static str,
+        method: &str,
+        url: &str,
+        body: Option<&B>,
+        idempotency_key: Option<String>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: serde::Serialize,
+    {
+        let credentials = self.config.credentials()?;
+        if !matches!(method, "GET" | "POST" | "PUT" | "DELETE") {
+            return Err(LagoError::Configuration(format!(
+                "Unsupported method: {method}"
+            )));
+        }
+        let idempotency_key = if method == "POST" {
+            idempotency_key.or_else(|| self.generate_idempotency_key())
+        } else {
+            None
+        };
+        let can_retry = idempotency_key.is_some()
+            || method != "POST"
+            || self.config.retry_config().retry_without_idempotency_key;
+
+        let mut headers = HeaderMap::new();
+        let authorization = HeaderValue::from_str(&format!("Bearer {}", credentials.api_key()))
+            .map_err(|_| LagoError::Configuration("Invalid API key".to_string()))?;
+        headers.insert(AUTHORIZATION, authorization);
+        if let Ok(user_agent) = HeaderValue::from_str(self.config.user_agent()) {
+            headers.insert(USER_AGENT, user_agent);
+        }
+        if let Some(key) = &idempotency_key {
+            let key = HeaderValue::from_str(key)
+                .map_err(|_| LagoError::InvalidInput(format!("Invalid idempotency key: {key}")))?;
+            headers.insert(IDEMPOTENCY_KEY_HEADER, key);
+        }
 
-            if let Some(body) = body {
-                request_builder = request_builder.json(body);
+        let body = match body {
+            Some(body) => {
+                headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                Some(serde_json::to_vec(body)?)
             }
+            None => None,
+        };
 
-            let response = match request_builder.send().await {
-                Ok(response) => response,
-                Err(e) => {
-                    if attempt >= self.config.retry_config().max_attempts {
-                        return Err(LagoError::Http(e));
-                    }
-
-                    attempt += 1;
-                    let delay = self.config.retry_config().delay_for_attempt(attempt);
-                    sleep(delay).await;
-                    continue;
+        let trace = RequestTrace::start(self, operation, method, url);
+        let first_attempt_at = Instant::now();
+        let mut previous_delay = None;
+        let mut attempt = 0;
+        let attempts = async {
+            loop {
+                let attempt_trace = trace.attempt(attempt);
+                let mut request = TransportRequest {
+                    method: method.to_string(),
+                    url: url.to_string(),
+                    headers: headers.clone(),
+                    body: body.clone(),
+                };
+                attempt_trace.inject(&mut request.headers);
+
+                let circuit = match &self.circuit_breaker {
+                    Some(breaker) => match breaker.try_acquire(Instant::now()) {
+                        Ok(circuit) => Some(circuit),
+                        Err(e) => {
+                            attempt_trace.record_error(&e);
+                            trace.finish(None, attempt);
+                            return Err(e);
+                        }
+                    },
+                    None => None,
+                };
+                let permit = match self.config.retry_config().mode {
+                    RetryMode::Adaptive => Some(
+                        self.rate_limiter
+                            .acquire(self.config.retry_config().max_delay)
+                            .await,
+                    ),
+                    _ => None,
+                };
+                let started_at = Instant::now();
+                let result = attempt_trace.instrument(self.transport.send(request)).await;
+                self.record_attempt(&RequestMetrics {
+                    operation,
+                    method,
+                    status: result.as_ref().ok().map(|response| response.status),
+                    latency: started_at.elapsed(),
+                    attempt,
+                });
+                if let Some(circuit) = circuit {
+                    circuit.record(&result);
                 }
-            };
-
-            // Parse rate limit headers before consuming the response. Used
-            // both to time 429 retries and to feed the on_rate_limit_info
-            // callback after a successful response.
-            let rate_limit_info = self.parse_rate_limit_info(&response, method, url);
 
-            match self.handle_response(response).await {
-                Ok(result) => {
-                    if let Some(info) = &rate_limit_info {
-                        self.emit_rate_limit_info(info);
+                let response = match result {
+                    Ok(response) => response,
+                    Err(e @ (LagoError::Http(_) | LagoError::Transport(_))) => {
+                        attempt_trace.record_error(&e);
+                        let context = RetryContext {
+                            error: &e,
+                            method,
+                            attempt: attempt + 1,
+                            headers: None,
+                            elapsed: first_attempt_at.elapsed(),
+                            previous_delay,
+                        };
+                        let Some(delay) = can_retry
+                            .then(|| self.config.retry_policy().retry_delay(&context))
+                            .flatten()
+                        else {
+                            trace.finish(None, attempt + 1);
+                            return Err(e);
+                        };
+
+                        attempt += 1;
+                        previous_delay = Some(delay);
+                        attempt_trace.record_retry_delay(delay);
+                        self.record_retry(operation, RetryReason::Http, delay);
+                        drop(permit);
+                        sleep(delay).await;
+                        continue;
                     }
-                    return Ok(result);
-                }
-                Err(e) => {
-                    if !self.should_retry(&e, attempt) {
+                    Err(e) => {
+                        attempt_trace.record_error(&e);
+                        trace.finish(None, attempt + 1);
                         return Err(e);
                     }
+                };
+
+                // Parse rate limit headers before consuming the response. Used
+                // both to time 429 retries and to feed the on_rate_limit_info
+                // callback after a successful response.
+                let rate_limit_info = self.parse_rate_limit_info(&response.headers, method, url);
+                let status = response.status;
+                let response_headers = response.headers.clone();
+                attempt_trace.record_status(status);
+                attempt_trace.record_rate_limit(rate_limit_info.as_ref());
+                if permit.is_some()
+                    && let Some(info) = &rate_limit_info
+                {
+                    self.rate_limiter.update(info, Instant::now());
+                }
+                if let Some(recorder) = self.config.metrics_recorder()
+                    && let Some(usage_pct) = rate_limit_info.as_ref().and_then(|i| i.usage_pct())
+                {
+                    recorder.record_rate_limit_usage(usage_pct);
+                }
 
-                    attempt += 1;
-                    let delay = self.get_retry_delay(rate_limit_info.as_ref(), &e, attempt);
-                    sleep(delay).await;
-                    continue;
+                match self.handle_response(response) {
+                    Ok(result) => {
+                        trace.finish(Some(status), attempt + 1);
+                        if let Some(info) = &rate_limit_info {
+                            self.emit_rate_limit_info(info);
+                        }
+                        return Ok(result);
+                    }
+                    Err(e) => {
+                        // A rate limited request was not applied, so it is safe to resend.
+                        let retry_allowed = can_retry || matches!(e, LagoError::RateLimit);
+                        let context = RetryContext {
+                            error: &e,
+                            method,
+                            attempt: attempt + 1,
+                            headers: Some(&response_headers),
+                            elapsed: first_attempt_at.elapsed(),
+                            previous_delay,
+                        };
+                        let Some(delay) = retry_allowed
+                            .then(|| self.config.retry_policy().retry_delay(&context))
+                            .flatten()
+                        else {
+                            trace.finish(Some(status), attempt + 1);
+                            return Err(e);
+                        };
+
+                        attempt += 1;
+                        previous_delay = Some(delay);
+                        attempt_trace.record_retry_delay(delay);
+                        let reason = match e.status() {
+                            Some(429) => RetryReason::RateLimit,
+                            Some(500..=599) => RetryReason::ServerError,
+                            _ => RetryReason::Other,
+                        };
+                        self.record_retry(operation, reason, delay);
+                        drop(permit);
+                        sleep(delay).await;
+                        continue;
+                    }
                 }
             }
+        };
+        trace.instrument(attempts).await
+    }
+
+    /// Generates an idempotency key when `idempotency_keys` is enabled
+    fn generate_idempotency_key(&self) -> Option<String> {
+        self.config
+            .idempotency_keys()
+            .then(|| Uuid::new_v4().to_string())
+    }
+
+    /// Reports an attempt to the configured metrics recorder, if any.
+    fn record_attempt(&self, metrics: &RequestMetrics<'_>) {
+        if let Some(recorder) = self.config.metrics_recorder() {
+            recorder.record_request(metrics);
+        }
+    }
+
+    /// Reports a retry and its backoff delay to the configured metrics recorder, if any.
+    fn record_retry(&self, operation: &str, reason: RetryReason, delay: Duration) {
+        if let Some(recorder) = self.config.metrics_recorder() {
+            recorder.record_retry(operation, reason, delay);
         }
     }
 
@@ -208,36 +436,10 @@ impl LagoClient {
 
         let result = std::panic::catch_unwind(AssertUnwindSafe(|| callback(info)));
         if result.is_err() {
-            eprintln!("lago: on_rate_limit_info callback panicked; suppressing");
+            warning!("on_rate_limit_info callback panicked; suppressing");
         }
     }
 
-    /// Determines the appropriate delay before the next retry attempt
-    ///
-    /// For rate limit errors (429), uses the `x-ratelimit-reset` header value
-    /// if available, otherwise falls back to exponential backoff. For other errors,
-    /// always uses exponential backoff.
-    /// Maximum delay before a retry attempt (20 seconds).
-    const MAX_RETRY_DELAY: Duration = Duration::from_secs(20);
-
-    fn get_retry_delay(
-        &self,
-        rate_limit_info: Option<&RateLimitInfo>,
-        error: &LagoError,
-        attempt: u32,
-    ) -> Duration {
-        let delay = if let LagoError::RateLimit = error
-            && let Some(info) = rate_limit_info
-            && let Some(reset_secs) = info.reset
-        {
-            Duration::from_secs(reset_secs)
-        } else {
-            self.config.retry_config().delay_for_attempt(attempt)
-        };
-
-        delay.min(Self::MAX_RETRY_DELAY)
-    }
-
     /// Extracts rate limit information from response headers.
     ///
     /// Returns `None` when no `x-ratelimit-*` headers are present (for
@@ -245,24 +447,21 @@ impl LagoClient {
     /// so callers can skip emission entirely when there's nothing to report.
     fn parse_rate_limit_info(
         &self,
-        response: &Response,
+        headers: &HeaderMap,
         method: &str,
         url: &str,
     ) -> Option<RateLimitInfo> {
-        let limit = response
-            .headers()
+        let limit = headers
             .get("x-ratelimit-limit")
             .and_then(|h| h.to_str().ok())
             .and_then(|s| s.parse::<u32>().ok());
 
-        let remaining = response
-            .headers()
+        let remaining = headers
             .get("x-ratelimit-remaining")
             .and_then(|h| h.to_str().ok())
             .and_then(|s| s.parse::<u32>().ok());
 
-        let reset = response
-            .headers()
+        let reset = headers
             .get("x-ratelimit-reset")
             .and_then(|h| h.to_str().ok())
             .and_then(|s| s.parse::<u64>().ok());
@@ -284,52 +483,17 @@ impl LagoClient {
     ///
     /// This method handles different HTTP status codes and converts them to appropriate
     /// error types for the client to handle.
-    async fn handle_response<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        let status = response.status();
-
-        if status.is_success() {
-            let text = response.text().await.map_err(LagoError::Http)?;
+    fn handle_response<T: DeserializeOwned>(&self, response: TransportResponse) -> Result<T> {
+        if response.is_success() {
             // Handle empty responses (e.g., 200 OK with no body)
-            if text.is_empty() {
+            if response.body.is_empty() {
                 return serde_json::from_str("{}").map_err(LagoError::Serialization);
             }
-            serde_json::from_str(&text).map_err(LagoError::Serialization)
+            serde_json::from_slice(&response.body).map_err(LagoError::Serialization)
         } else {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            match status.as_u16() {
-                401 => Err(LagoError::Unauthorized),
-                404 => Err(LagoError::Api {
-                    status: status.as_u16(),
-                    message: error_text,
-                }),
-                429 => Err(LagoError::RateLimit),
-                _ => Err(LagoError::Api {
-                    status: status.as_u16(),
-                    message: error_text,
-                }),
-            }
-        }
-    }
-
-    /// Determines whether a request should be retried based on the error type and attempt count
-    fn should_retry(&self, error: &LagoError, attempt: u32) -> bool {
-        if attempt >= self.config.retry_config().max_attempts {
-            return false;
-        }
-
-        if self.config.retry_config().mode == RetryMode::Off {
-            return false;
-        }
+            let error_text = String::from_utf8_lossy(&response.body);
 
-        match error {
-            LagoError::Http(_) => true,
-            LagoError::RateLimit => true,
-            LagoError::Api { status, .. } => *status >= 500,
-            _ => false,
+            Err(LagoError::from_response(response.status, &error_text))
         }
     }
 }
@@ -416,7 +580,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_ok());
 
@@ -454,7 +619,9 @@ mod tests {
             name: "New Item".to_string(),
         };
 
-        let result: Result<TestResponse> = client.make_request("POST", &url, Some(&request)).await;
+        let result: Result<TestResponse> = client
+            .make_request("test", "POST", &url, Some(&request))
+            .await;
 
         assert!(result.is_ok());
 
@@ -479,7 +646,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let _result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let _result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         mock.assert_async().await;
     }
@@ -490,7 +658,9 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("PATCH", &url, None::<&()>).await;
+        let result: Result<TestResponse> = client
+            .make_request("test", "PATCH", &url, None::<&()>)
+            .await;
 
         assert!(result.is_err());
 
@@ -515,7 +685,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
 
@@ -540,13 +711,18 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
 
+        // Not a Lago error payload, so the response text is kept.
         match result.unwrap_err() {
-            LagoError::Api { status, message } => {
-                assert_eq!(status, 404);
+            LagoError::Api {
+                status: 404,
+                code: None,
+                message,
+            } => {
                 assert_eq!(message, "Not Found");
             }
             _ => panic!("Expected Api Error"),
@@ -571,7 +747,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
 
@@ -596,16 +773,17 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
 
         match result.unwrap_err() {
-            LagoError::Api { status, message } => {
+            LagoError::ServerError { status, message } => {
                 assert_eq!(status, 500);
                 assert_eq!(message, "Internal Server Error");
             }
-            _ => panic!("Expected Api Error"),
+            _ => panic!("Expected ServerError"),
         }
 
         mock.assert_async().await;
@@ -624,7 +802,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
 
@@ -650,7 +829,8 @@ mod tests {
         let client = create_retry_client(&server.url(), 3);
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
         mock.assert_async().await;
@@ -679,7 +859,8 @@ mod tests {
         let client = create_retry_client(&server.url(), 5);
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_ok());
 
@@ -692,51 +873,157 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_no_retry_on_client_error() {
+    async fn test_idempotency_key_is_stable_across_retries() {
+        let mut server = Server::new_async().await;
+        let keys = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = keys.clone();
+        let mock = server
+            .mock("POST", "/test")
+            .match_request(move |request| {
+                let key = request
+                    .header(IDEMPOTENCY_KEY_HEADER)
+                    .first()
+                    .map(|value| value.to_str().unwrap_or_default().to_string());
+                recorded.lock().unwrap().push(key);
+                true
+            })
+            .with_status(500)
+            .with_body("Server Error")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = create_retry_client(&server.url(), 2);
+        let url = format!("{}/test", server.url());
+
+        let result: Result<TestResponse> =
+            client.make_request("test", "POST", &url, None::<&()>).await;
+
+        assert!(result.is_err());
+        mock.assert_async().await;
+
+        let keys = keys.lock().unwrap();
+        assert_eq!(keys.len(), 3);
+        assert!(keys[0].is_some());
+        assert!(keys.iter().all(|key| key == &keys[0]));
+    }
+
+    #[tokio::test]
+    async fn test_custom_idempotency_key() {
         let mut server = Server::new_async().await;
         let mock = server
+            .mock("POST", "/test")
+            .match_header(IDEMPOTENCY_KEY_HEADER, "payment-42")
+            .with_status(200)
+            .with_body(json!({"id": "123", "name": "Success"}).to_string())
+            .create_async()
+            .await;
+        let get_mock = server
             .mock("GET", "/test")
-            .with_status(400)
-            .with_body("Bad Request")
+            .match_header(IDEMPOTENCY_KEY_HEADER, mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(json!({"id": "123", "name": "Success"}).to_string())
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let url = format!("{}/test", server.url());
+        let key = Some("payment-42".to_string());
+
+        let result: Result<TestResponse> = client
+            .make_request_with_key("test", "POST", &url, None::<&()>, key.clone())
+            .await;
+        assert!(result.is_ok());
+        let result: Result<TestResponse> = client
+            .make_request_with_key("test", "GET", &url, None::<&()>, key)
+            .await;
+        assert!(result.is_ok());
+
+        mock.assert_async().await;
+        get_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_without_idempotency_key_is_not_retried() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/test")
+            .match_header(IDEMPOTENCY_KEY_HEADER, mockito::Matcher::Missing)
+            .with_status(500)
+            .with_body("Server Error")
             .expect(1)
             .create_async()
             .await;
 
-        let client = create_retry_client(&server.url(), 3);
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom(server.url()))
+            .retry_config(RetryConfig::builder().max_attempts(3).build())
+            .idempotency_keys(false)
+            .build();
+        let client = LagoClient::new(config);
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "POST", &url, None::<&()>).await;
 
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(LagoError::ServerError { status: 500, .. })
+        ));
         mock.assert_async().await;
     }
 
     #[tokio::test]
-    async fn test_should_retry_logic() {
-        let client = create_retry_client("http://localhost:8080", 3);
+    async fn test_post_without_idempotency_key_retried_when_allowed() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/test")
+            .with_status(500)
+            .with_body("Server Error")
+            .expect(3)
+            .create_async()
+            .await;
 
-        let rate_limit_error = LagoError::RateLimit;
-        assert!(client.should_retry(&rate_limit_error, 1));
+        let retry_config = RetryConfig::builder()
+            .max_attempts(2)
+            .retry_without_idempotency_key(true)
+            .build();
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom(server.url()))
+            .retry_config(retry_config)
+            .idempotency_keys(false)
+            .build();
+        let client = LagoClient::new(config);
+        let url = format!("{}/test", server.url());
 
-        let server_error = LagoError::Api {
-            status: 500,
-            message: "Server Error".to_string(),
-        };
-        assert!(client.should_retry(&server_error, 1));
+        let result: Result<TestResponse> =
+            client.make_request("test", "POST", &url, None::<&()>).await;
 
-        let client_error = LagoError::Api {
-            status: 400,
-            message: "Bad Request".to_string(),
-        };
-        assert!(!client.should_retry(&client_error, 1));
+        assert!(result.is_err());
+        mock.assert_async().await;
+    }
 
-        assert!(!client.should_retry(&server_error, 3));
+    #[tokio::test]
+    async fn test_no_retry_on_client_error() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/test")
+            .with_status(400)
+            .with_body("Bad Request")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = create_retry_client(&server.url(), 3);
+        let url = format!("{}/test", server.url());
 
-        let auth_error = LagoError::Unauthorized;
-        assert!(!client.should_retry(&auth_error, 1));
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
-        let client_no_retry = create_test_client("http://localhost:8080");
-        assert!(!client_no_retry.should_retry(&server_error, 1));
+        assert!(result.is_err());
+        mock.assert_async().await;
     }
 
     #[tokio::test]
@@ -751,7 +1038,8 @@ mod tests {
         let client = LagoClient::new(config);
         let url = "http://10.255.255.1:80/test";
 
-        let result: Result<TestResponse> = client.make_request("GET", url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", url, None::<&()>).await;
 
         assert!(result.is_err());
 
@@ -778,7 +1066,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<EmptyResponse> = client.make_request("POST", &url, None::<&()>).await;
+        let result: Result<EmptyResponse> =
+            client.make_request("test", "POST", &url, None::<&()>).await;
 
         assert!(result.is_ok());
         mock.assert_async().await;
@@ -801,7 +1090,8 @@ mod tests {
         let url = format!("{}/test", server.url());
 
         // Verify the error is a RateLimit error
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             LagoError::RateLimit => {}
@@ -822,7 +1112,8 @@ mod tests {
         let client = create_test_client(&server.url());
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -833,82 +1124,6 @@ mod tests {
         mock.assert_async().await;
     }
 
-    #[tokio::test]
-    async fn test_get_retry_delay_uses_rate_limit_reset() {
-        let client = create_retry_client("http://localhost:8080", 3);
-
-        let info = RateLimitInfo {
-            limit: Some(100),
-            remaining: Some(0),
-            reset: Some(10),
-            ..Default::default()
-        };
-
-        let delay = client.get_retry_delay(Some(&info), &LagoError::RateLimit, 1);
-        assert_eq!(
-            delay,
-            Duration::from_secs(10),
-            "Should use reset time from rate limit header"
-        );
-    }
-
-    #[tokio::test]
-    async fn test_get_retry_delay_caps_at_max() {
-        let client = create_retry_client("http://localhost:8080", 3);
-
-        let info = RateLimitInfo {
-            limit: Some(100),
-            remaining: Some(0),
-            reset: Some(120),
-            ..Default::default()
-        };
-
-        let delay = client.get_retry_delay(Some(&info), &LagoError::RateLimit, 1);
-        assert_eq!(
-            delay,
-            Duration::from_secs(20),
-            "Should cap retry delay at 20 seconds"
-        );
-    }
-
-    #[tokio::test]
-    async fn test_get_retry_delay_falls_back_to_exponential_backoff() {
-        let client = create_retry_client("http://localhost:8080", 3);
-
-        let info = RateLimitInfo {
-            limit: Some(100),
-            remaining: Some(0),
-            reset: None,
-            ..Default::default()
-        };
-
-        let delay = client.get_retry_delay(Some(&info), &LagoError::RateLimit, 1);
-        // With initial_delay of 100ms and multiplier of 2.0, attempt 1 should give 200ms
-        assert_eq!(
-            delay,
-            Duration::from_millis(200),
-            "Should fall back to exponential backoff when reset header is missing"
-        );
-    }
-
-    #[tokio::test]
-    async fn test_get_retry_delay_for_non_rate_limit_errors() {
-        let client = create_retry_client("http://localhost:8080", 3);
-
-        let server_error = LagoError::Api {
-            status: 500,
-            message: "Server Error".to_string(),
-        };
-
-        let delay = client.get_retry_delay(None, &server_error, 2);
-        // With initial_delay of 100ms and multiplier of 2.0, attempt 2 should give 400ms
-        assert_eq!(
-            delay,
-            Duration::from_millis(400),
-            "Should use exponential backoff for non-rate-limit errors"
-        );
-    }
-
     // ------------------------------------------------------------------
     // RateLimitInfo + on_rate_limit_info observability
     // ------------------------------------------------------------------
@@ -999,7 +1214,7 @@ mod tests {
         let client = create_observed_client(&server.url(), callback);
         let url = format!("{}/test", server.url());
 
-        let _: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let _: Result<TestResponse> = client.make_request("test", "GET", &url, None::<&()>).await;
 
         // Snapshot under the lock then drop the guard before awaiting again
         // (clippy::await_holding_lock).
@@ -1036,7 +1251,7 @@ mod tests {
         let client = create_observed_client(&server.url(), callback);
         let url = format!("{}/test", server.url());
 
-        let _: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let _: Result<TestResponse> = client.make_request("test", "GET", &url, None::<&()>).await;
 
         assert_eq!(*counter.lock().unwrap(), 0);
         mock.assert_async().await;
@@ -1062,9 +1277,200 @@ mod tests {
         let client = create_observed_client(&server.url(), callback);
         let url = format!("{}/test", server.url());
 
-        let result: Result<TestResponse> = client.make_request("GET", &url, None::<&()>).await;
+        let result: Result<TestResponse> =
+            client.make_request("test", "GET", &url, None::<&()>).await;
         assert!(result.is_ok(), "callback panic must not break the request");
 
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn test_adaptive_mode_paces_requests_across_clones() {
+        use crate::InMemoryTransport;
+
+        let transport = InMemoryTransport::new();
+        transport.push_response(
+            TransportResponse::json(200, &json!({"id": "1", "name": "first"}))
+                .with_header("x-ratelimit-limit", "1")
+                .with_header("x-ratelimit-remaining", "0")
+                .with_header("x-ratelimit-reset", "1"),
+        );
+        transport.push_json(200, &json!({"id": "2", "name": "second"}));
+
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom("http://lago.test".to_string()))
+            .retry_config(RetryConfig::builder().mode(RetryMode::Adaptive).build())
+            .transport(Arc::new(transport.clone()))
+            .build();
+        let client = LagoClient::new(config);
+        let clone = client.clone();
+
+        let url = "http://lago.test/test";
+        let _: TestResponse = client
+            .make_request("test", "GET", url, None::<&()>)
+            .await
+            .unwrap();
+        let started_at = Instant::now();
+        let _: TestResponse = clone
+            .make_request("test", "GET", url, None::<&()>)
+            .await
+            .unwrap();
+
+        assert!(started_at.elapsed() >= Duration::from_millis(900));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_tracing_spans_and_traceparent() {
+        use crate::InMemoryTransport;
+        use lago_types::requests::customer::GetCustomerRequest;
+        use std::sync::Mutex;
+        use tracing_subscriber::fmt::format::FmtSpan;
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let transport = InMemoryTransport::new();
+        transport.push_response(TransportResponse::new(503));
+        transport.push_response(
+            TransportResponse::json(200, &crate::transport::customer_json(&[]))
+                .with_header("x-ratelimit-remaining", "99"),
+        );
+        let retry_config = RetryConfig::builder()
+            .max_attempts(1)
+            .initial_delay(Duration::from_millis(1))
+            .build();
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom("http://lago.test/api/v1".to_string()))
+            .retry_config(retry_config)
+            .transport(Arc::new(transport.clone()))
+            .build();
+        let context =
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+        let client = LagoClient::new(config).with_trace_context(context.clone());
+
+        client
+            .get_customer(GetCustomerRequest::new("cust_123".to_string()))
+            .await
+            .unwrap();
+
+        let traceparents: Vec<TraceContext> = transport
+            .requests()
+            .iter()
+            .map(|request| TraceContext::parse(request.header("traceparent").unwrap()).unwrap())
+            .collect();
+        assert_eq!(traceparents.len(), 2);
+        assert!(
+            traceparents
+                .iter()
+                .all(|t| t.trace_id() == context.trace_id())
+        );
+        assert_ne!(traceparents[0].parent_id(), traceparents[1].parent_id());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("get_customer"), "{output}");
+        assert!(output.contains("lago.resource_id=cust_123"), "{output}");
+        assert!(
+            output.contains("url.path=\"/customers/cust_123\""),
+            "{output}"
+        );
+        assert!(output.contains("http.response.status_code=503"), "{output}");
+        assert!(output.contains("retry_delay_ms="), "{output}");
+        assert!(output.contains("ratelimit.remaining=99"), "{output}");
+        assert!(output.contains("attempts=2"), "{output}");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_attempts_run_in_spans_and_continue_current_trace() {
+        use crate::InMemoryTransport;
+        use async_trait::async_trait;
+        use std::sync::Mutex;
+        use tracing::Instrument;
+
+        /// Records the span each request is sent in.
+        struct SpanRecordingTransport {
+            inner: InMemoryTransport,
+            spans: Mutex<Vec<&'static str>>,
+        }
+
+        #[async_trait]
+        impl Transport for SpanRecordingTransport {
+            async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+                let span = tracing::Span::current().metadata().map(|m| m.name());
+                self.spans.lock().unwrap().push(span.unwrap_or_default());
+                self.inner.send(request).await
+            }
+        }
+
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry());
+        let inner = InMemoryTransport::new();
+        for _ in 0..3 {
+            inner.push_response(TransportResponse::json(
+                200,
+                &json!({"id": "123", "name": "Success"}),
+            ));
+        }
+        let transport = Arc::new(SpanRecordingTransport {
+            inner: inner.clone(),
+            spans: Mutex::default(),
+        });
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom("http://lago.test/api/v1".to_string()))
+            .transport(transport.clone())
+            .build();
+        let client = LagoClient::new(config);
+        let url = "http://lago.test/api/v1/test";
+
+        let job = tracing::info_span!("job");
+        for _ in 0..2 {
+            let _: TestResponse = client
+                .make_request("test", "GET", url, None::<&()>)
+                .instrument(job.clone())
+                .await
+                .unwrap();
+        }
+        let context =
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+        let incoming = tracing::info_span!("incoming_request");
+        context.attach(&incoming);
+        let _: TestResponse = client
+            .make_request("test", "GET", url, None::<&()>)
+            .instrument(incoming)
+            .await
+            .unwrap();
+
+        assert_eq!(*transport.spans.lock().unwrap(), vec!["lago.attempt"; 3]);
+        let traceparents: Vec<TraceContext> = inner
+            .requests()
+            .iter()
+            .map(|request| TraceContext::parse(request.header("traceparent").unwrap()).unwrap())
+            .collect();
+        assert_eq!(traceparents[0].trace_id(), traceparents[1].trace_id());
+        assert_eq!(traceparents[2].trace_id(), context.trace_id());
+    }
 }
@@ -2,12 +2,15 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use super::{
+    circuit_breaker::{CircuitBreakerConfig, CircuitStateCallback},
     client::RateLimitInfoCallback,
     credentials::{
         Credentials, CredentialsProvider, EnvironmentCredentialsProvider, StaticCredentialsProvider,
     },
+    metrics::MetricsRecorder,
     region::{EnvironmentRegionProvider, Region, RegionProvider, StaticRegionProvider},
-    retry::RetryConfig,
+    retry::{RetryConfig, RetryPolicy},
+    transport::Transport,
 };
 
 /// Configuration settings for the Lago client
@@ -22,6 +25,12 @@ pub struct Config {
     pub(crate) retry_config: RetryConfig,
     pub(crate) user_agent: String,
     pub(crate) on_rate_limit_info: Option<RateLimitInfoCallback>,
+    pub(crate) idempotency_keys: bool,
+    pub(crate) transport: Option<Arc<dyn Transport>>,
+    pub(crate) metrics_recorder: Option<Arc<dyn MetricsRecorder>>,
+    pub(crate) retry_policy: Option<Arc<dyn RetryPolicy>>,
+    pub(crate) circuit_breaker: Option<CircuitBreakerConfig>,
+    pub(crate) on_circuit_state_change: Option<CircuitStateCallback>,
 }
 
 impl Config {
@@ -65,6 +74,17 @@ impl Config {
         &self.retry_config
     }
 
+    /// Gets the policy deciding whether and when failed requests are retried
+    ///
+    /// # Returns
+    /// The policy set with [`ConfigBuilder::retry_policy`], or the retry configuration
+    pub fn retry_policy(&self) -> &dyn RetryPolicy {
+        match &self.retry_policy {
+            Some(policy) => policy.as_ref(),
+            None => &self.retry_config,
+        }
+    }
+
     /// Gets the configured user agent string
     ///
     /// # Returns
@@ -80,6 +100,38 @@ impl Config {
     pub fn on_rate_limit_info(&self) -> Option<&RateLimitInfoCallback> {
         self.on_rate_limit_info.as_ref()
     }
+
+    /// Gets the circuit breaker settings, if the circuit breaker is enabled
+    ///
+    /// # Returns
+    /// The circuit breaker configuration, or `None` when requests are never short-circuited
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreakerConfig> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Gets the configured `on_circuit_state_change` callback, if any.
+    ///
+    /// The callback is invoked each time the circuit breaker changes state.
+    /// See [`crate::CircuitStateCallback`].
+    pub fn on_circuit_state_change(&self) -> Option<&CircuitStateCallback> {
+        self.on_circuit_state_change.as_ref()
+    }
+
+    /// Whether an idempotency key is generated for unsafe requests (POST)
+    ///
+    /// # Returns
+    /// `true` if the client attaches a generated `Idempotency-Key` header
+    pub fn idempotency_keys(&self) -> bool {
+        self.idempotency_keys
+    }
+
+    /// Gets the configured metrics recorder, if any.
+    ///
+    /// The recorder receives request, retry and rate limit metrics from every
+    /// request. See [`crate::MetricsRecorder`].
+    pub fn metrics_recorder(&self) -> Option<&Arc<dyn MetricsRecorder>> {
+        self.metrics_recorder.as_ref()
+    }
 }
 
 impl Default for Config {
@@ -95,6 +147,12 @@ impl Default for Config {
             retry_config: RetryConfig::default(),
             user_agent: format!("lago-rust-client/{}", env!("CARGO_PKG_VERSION")),
             on_rate_limit_info: None,
+            idempotency_keys: true,
+            transport: None,
+            metrics_recorder: None,
+            retry_policy: None,
+            circuit_breaker: None,
+            on_circuit_state_change: None,
         }
     }
 }
@@ -110,6 +168,12 @@ pub struct ConfigBuilder {
     retry_config: Option<RetryConfig>,
     user_agent: Option<String>,
     on_rate_limit_info: Option<RateLimitInfoCallback>,
+    idempotency_keys: Option<bool>,
+    transport: Option<Arc<dyn Transport>>,
+    metrics_recorder: Option<Arc<dyn MetricsRecorder>>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    on_circuit_state_change: Option<CircuitStateCallback>,
 }
 
 impl ConfigBuilder {
@@ -125,6 +189,12 @@ impl ConfigBuilder {
             retry_config: None,
             user_agent: None,
             on_rate_limit_info: None,
+            idempotency_keys: None,
+            transport: None,
+            metrics_recorder: None,
+            retry_policy: None,
+            circuit_breaker: None,
+            on_circuit_state_change: None,
         }
     }
 
@@ -200,6 +270,21 @@ impl ConfigBuilder {
         self
     }
 
+    /// Sets a custom retry policy
+    ///
+    /// The policy replaces the retry rules of the retry configuration, whose
+    /// `max_delay` still bounds the pacing of [`crate::RetryMode::Adaptive`].
+    ///
+    /// # Arguments
+    /// * `policy` - The policy deciding whether and when failed requests are retried
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn retry_policy(mut self, policy: Arc<dyn RetryPolicy>) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
     /// Sets the user agent string for HTTP requests
     ///
     /// # Arguments
@@ -228,6 +313,89 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enables the circuit breaker
+    ///
+    /// Once the failure rate of the last attempts crosses the threshold,
+    /// requests fail immediately with `LagoError::CircuitOpen` until probe
+    /// requests succeed again. The breaker is shared by all clones of the
+    /// client. See [`crate::CircuitBreakerConfig`].
+    ///
+    /// # Arguments
+    /// * `config` - The circuit breaker settings
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Sets a callback invoked each time the circuit breaker changes state.
+    ///
+    /// Use this to alert on an open circuit or export its state as a metric.
+    /// See [`crate::CircuitStateCallback`].
+    ///
+    /// # Arguments
+    /// * `callback` - The callback to invoke with the previous and the new state
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn on_circuit_state_change(mut self, callback: CircuitStateCallback) -> Self {
+        self.on_circuit_state_change = Some(callback);
+        self
+    }
+
+    /// Enables or disables generated idempotency keys for unsafe requests
+    ///
+    /// When enabled (the default), every POST request gets an `Idempotency-Key`
+    /// header that stays the same across all retry attempts of the call, so the
+    /// server can discard duplicates of an attempt it already applied. Requests
+    /// like `CreatePaymentRequest` can also carry their own key, set with
+    /// their `with_idempotency_key` method.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to generate idempotency keys
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn idempotency_keys(mut self, enabled: bool) -> Self {
+        self.idempotency_keys = Some(enabled);
+        self
+    }
+
+    /// Sets the transport used to send HTTP requests
+    ///
+    /// Use it to route requests through your own HTTP stack, or an
+    /// [`crate::InMemoryTransport`] in tests. The configured timeout and user
+    /// agent only apply to the default `reqwest` transport; the user agent is
+    /// still sent as a request header.
+    ///
+    /// # Arguments
+    /// * `transport` - The transport to use
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets a recorder receiving metrics from every request
+    ///
+    /// The recorder is told about each attempt (operation, method, status,
+    /// latency), each retry (reason and backoff delay) and the rate limit
+    /// usage. Use an [`crate::InMemoryMetricsRecorder`] in tests.
+    ///
+    /// # Arguments
+    /// * `recorder` - The metrics recorder to use
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn metrics_recorder(mut self, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        self.metrics_recorder = Some(recorder);
+        self
+    }
+
     /// Builds the final configuration instance
     ///
     /// Any unset values will use the defaults from `Config::default()`.
@@ -248,6 +416,14 @@ impl ConfigBuilder {
             retry_config: self.retry_config.unwrap_or(default_config.retry_config),
             user_agent: self.user_agent.unwrap_or(default_config.user_agent),
             on_rate_limit_info: self.on_rate_limit_info,
+            idempotency_keys: self
+                .idempotency_keys
+                .unwrap_or(default_config.idempotency_keys),
+            transport: self.transport,
+            metrics_recorder: self.metrics_recorder,
+            retry_policy: self.retry_policy,
+            circuit_breaker: self.circuit_breaker,
+            on_circuit_state_change: self.on_circuit_state_change,
         }
     }
 }
@@ -1,14 +1,35 @@
+pub mod api;
+pub mod batcher;
+pub mod cassette;
+pub mod circuit_breaker;
 pub mod client;
 pub mod config;
 pub mod credentials;
+pub mod metrics;
 pub mod observability;
+pub mod pagination;
 pub mod queries;
 pub mod region;
 pub mod retry;
+pub mod spool;
+pub mod telemetry;
+pub mod transport;
+pub mod webhooks;
 
+pub use api::*;
+pub use batcher::*;
+pub use cassette::*;
+pub use circuit_breaker::*;
 pub use client::*;
 pub use config::*;
 pub use credentials::*;
+pub use metrics::*;
+pub use pagination::*;
 pub use queries::*;
 pub use region::*;
 pub use retry::*;
+pub use spool::*;
+#[cfg(feature = "tracing")]
+pub use telemetry::*;
+pub use transport::*;
+pub use webhooks::*;
@@ -0,0 +1,322 @@
+//! Metrics hooks for the requests made by [`LagoClient`](crate::LagoClient).
+//!
+//! Set a [`MetricsRecorder`] with [`crate::ConfigBuilder::metrics_recorder`]
+//! to export request counts, latencies, retries and rate limit usage to your
+//! metrics backend. [`InMemoryMetricsRecorder`] keeps everything in memory,
+//! which is handy in tests.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Why a request was retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RetryReason {
+    /// The request failed before a response was received (connection, timeout, ...).
+    Http,
+    /// The API answered `429 Too Many Requests`.
+    RateLimit,
+    /// The API answered with a 5xx status.
+    ServerError,
+    /// The retry policy retried another error, e.g. a 409 conflict.
+    Other,
+}
+
+impl RetryReason {
+    /// Returns the reason as a metric label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetryReason::Http => "http",
+            RetryReason::RateLimit => "rate_limit",
+            RetryReason::ServerError => "server_error",
+            RetryReason::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for RetryReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single HTTP attempt made by the client.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics<'a> {
+    /// Name of the API operation, e.g. `get_customer`.
+    pub operation: &'a str,
+    /// HTTP method of the request.
+    pub method: &'a str,
+    /// Response status, or `None` when no response was received.
+    pub status: Option<u16>,
+    /// Time spent waiting for the response.
+    pub latency: Duration,
+    /// Attempt number, starting at 0.
+    pub attempt: u32,
+}
+
+/// Receives metrics from every request made by the client.
+///
+/// All methods have empty default implementations, so a recorder only
+/// implements the metrics it exports. They are called inline in the request
+/// path and should not block.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use lago_client::{MetricsRecorder, RequestMetrics};
+///
+/// #[derive(Default)]
+/// struct ErrorCounter(AtomicU64);
+///
+/// impl MetricsRecorder for ErrorCounter {
+///     fn record_request(&self, request: &RequestMetrics<'_>) {
+///         if request.status.is_none_or(|status| status >= 400) {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Called after each attempt, with its status and latency.
+    fn record_request(&self, _request: &RequestMetrics<'_>) {}
+
+    /// Called before sleeping ahead of a retry.
+    ///
+    /// # Arguments
+    /// * `operation` - Name of the API operation
+    /// * `reason` - Why the attempt is retried
+    /// * `delay` - Time the client will sleep before the next attempt
+    fn record_retry(&self, _operation: &str, _reason: RetryReason, _delay: Duration) {}
+
+    /// Called with the rate limit usage, in `[0.0, 1.0]`, of every response
+    /// carrying rate limit headers.
+    fn record_rate_limit_usage(&self, _usage_pct: f64) {}
+}
+
+/// Metrics collected by an [`InMemoryMetricsRecorder`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Number of attempts by operation, method and status (`None` when no response was received).
+    pub requests: BTreeMap<(String, String, Option<u16>), u64>,
+    /// Latency of every attempt, by operation.
+    pub latencies: BTreeMap<String, Vec<Duration>>,
+    /// Number of retries by reason.
+    pub retries: BTreeMap<RetryReason, u64>,
+    /// Total time spent sleeping before retries.
+    pub backoff: Duration,
+    /// Last rate limit usage seen, in `[0.0, 1.0]`.
+    pub rate_limit_usage_pct: Option<f64>,
+}
+
+impl MetricsSnapshot {
+    /// Returns the number of attempts of `operation` that got `status`.
+    pub fn request_count(&self, operation: &str, status: Option<u16>) -> u64 {
+        self.requests
+            .iter()
+            .filter(|((op, _, s), _)| op == operation && *s == status)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Returns the number of retries for `reason`.
+    pub fn retry_count(&self, reason: RetryReason) -> u64 {
+        self.retries.get(&reason).copied().unwrap_or_default()
+    }
+}
+
+/// A [`MetricsRecorder`] keeping metrics in memory.
+///
+/// Clones share the same metrics, so a clone can be handed to the client and
+/// inspected afterwards.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use lago_client::{Config, InMemoryMetricsRecorder, LagoClient};
+///
+/// let metrics = InMemoryMetricsRecorder::new();
+/// let client = LagoClient::new(
+///     Config::builder()
+///         .metrics_recorder(Arc::new(metrics.clone()))
+///         .build(),
+/// );
+///
+/// // ... make some calls
+/// let snapshot = metrics.snapshot();
+/// println!("{} retries", snapshot.retries.values().sum::<u64>());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMetricsRecorder {
+    metrics: Arc<Mutex<MetricsSnapshot>>,
+}
+
+impl InMemoryMetricsRecorder {
+    /// Creates an empty recorder
+    ///
+    /// # Returns
+    /// A new `InMemoryMetricsRecorder`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of the metrics recorded so far.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.lock().clone()
+    }
+
+    /// Clears all recorded metrics.
+    pub fn reset(&self) {
+        *self.lock() = MetricsSnapshot::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MetricsSnapshot> {
+        self.metrics.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl MetricsRecorder for InMemoryMetricsRecorder {
+    fn record_request(&self, request: &RequestMetrics<'_>) {
+        let mut metrics = self.lock();
+        *metrics
+            .requests
+            .entry((
+                request.operation.to_string(),
+                request.method.to_string(),
+                request.status,
+            ))
+            .or_default() += 1;
+        metrics
+            .latencies
+            .entry(request.operation.to_string())
+            .or_default()
+            .push(request.latency);
+    }
+
+    fn record_retry(&self, _operation: &str, reason: RetryReason, delay: Duration) {
+        let mut metrics = self.lock();
+        *metrics.retries.entry(reason).or_default() += 1;
+        metrics.backoff += delay;
+    }
+
+    fn record_rate_limit_usage(&self, usage_pct: f64) {
+        self.lock().rate_limit_usage_pct = Some(usage_pct);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retry::{RetryContext, RetryPolicy};
+    use crate::{
+        Config, Credentials, InMemoryTransport, LagoClient, Region, RetryConfig, TransportResponse,
+    };
+    use lago_types::error::LagoError;
+    use serde_json::{Value, json};
+
+    #[tokio::test]
+    async fn records_attempts_retries_and_rate_limit_usage() {
+        let transport = InMemoryTransport::new();
+        transport.push_error(LagoError::Transport("connection reset".to_string()));
+        transport.push_response(
+            TransportResponse::new(429)
+                .with_header("x-ratelimit-limit", "100")
+                .with_header("x-ratelimit-remaining", "0")
+                .with_header("x-ratelimit-reset", "0"),
+        );
+        transport.push_response(TransportResponse::new(503));
+        transport.push_response(
+            TransportResponse::json(200, &json!({})).with_header("x-ratelimit-remaining", "75"),
+        );
+        transport.push_response(
+            TransportResponse::json(200, &json!({}))
+                .with_header("x-ratelimit-limit", "100")
+                .with_header("x-ratelimit-remaining", "75"),
+        );
+
+        let metrics = InMemoryMetricsRecorder::new();
+        let client = LagoClient::new(
+            Config::builder()
+                .credentials(Credentials::new("test-api-key".to_string()))
+                .region(Region::Custom("http://lago.test/api/v1".to_string()))
+                .retry_config(
+                    RetryConfig::builder()
+                        .max_attempts(3)
+                        .initial_delay(Duration::from_millis(1))
+                        .build(),
+                )
+                .transport(Arc::new(transport))
+                .metrics_recorder(Arc::new(metrics.clone()))
+                .build(),
+        );
+
+        let url = "http://lago.test/api/v1/customers";
+        let _: Value = client
+            .make_request("list_customers", "GET", url, None::<&()>)
+            .await
+            .unwrap();
+        let _: Value = client
+            .make_request("list_customers", "GET", url, None::<&()>)
+            .await
+            .unwrap();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.request_count("list_customers", None), 1);
+        assert_eq!(snapshot.request_count("list_customers", Some(429)), 1);
+        assert_eq!(snapshot.request_count("list_customers", Some(503)), 1);
+        assert_eq!(snapshot.request_count("list_customers", Some(200)), 2);
+        assert_eq!(snapshot.latencies["list_customers"].len(), 5);
+        assert_eq!(snapshot.retry_count(RetryReason::Http), 1);
+        assert_eq!(snapshot.retry_count(RetryReason::RateLimit), 1);
+        assert_eq!(snapshot.retry_count(RetryReason::ServerError), 1);
+        assert!(snapshot.backoff > Duration::ZERO);
+        assert_eq!(snapshot.rate_limit_usage_pct, Some(0.25));
+
+        metrics.reset();
+        assert_eq!(metrics.snapshot(), MetricsSnapshot::default());
+    }
+
+    #[tokio::test]
+    async fn labels_retries_of_client_errors_as_other() {
+        struct RetryConflicts;
+
+        impl RetryPolicy for RetryConflicts {
+            fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+                (context.error.status() == Some(409) && context.attempt < 2)
+                    .then_some(Duration::from_millis(1))
+            }
+        }
+
+        let transport = InMemoryTransport::new();
+        transport.push_json(409, &json!({"status": 409, "error": "Conflict"}));
+        transport.push_json(200, &json!({}));
+
+        let metrics = InMemoryMetricsRecorder::new();
+        let client = LagoClient::new(
+            Config::builder()
+                .credentials(Credentials::new("test-api-key".to_string()))
+                .region(Region::Custom("http://lago.test/api/v1".to_string()))
+                .retry_policy(Arc::new(RetryConflicts))
+                .transport(Arc::new(transport))
+                .metrics_recorder(Arc::new(metrics.clone()))
+                .build(),
+        );
+
+        let _: Value = client
+            .make_request(
+                "update_customer",
+                "PUT",
+                "http://lago.test/api/v1/customers",
+                None::<&()>,
+            )
+            .await
+            .unwrap();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.retry_count(RetryReason::Other), 1);
+        assert_eq!(snapshot.retry_count(RetryReason::ServerError), 0);
+    }
+}
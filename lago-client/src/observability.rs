@@ -12,8 +12,11 @@ use crate::client::{RateLimitInfo, RateLimitInfoCallback};
 /// emits a warning.
 pub const DEFAULT_RATE_LIMIT_THRESHOLDS: &[f64] = &[0.80, 0.90, 0.95];
 
-/// Returns a [`RateLimitInfoCallback`] that logs a warning to stderr each time
-/// rate limit usage crosses one of the configured thresholds.
+/// Returns a [`RateLimitInfoCallback`] that logs a warning each time rate limit
+/// usage crosses one of the configured thresholds.
+///
+/// With the `tracing` feature the warning is a `tracing` event carrying the
+/// rate limit as fields; otherwise it is printed to stderr.
 ///
 /// Pass `None` for `thresholds` to use [`DEFAULT_RATE_LIMIT_THRESHOLDS`].
 ///
@@ -41,6 +44,18 @@ pub fn logging_rate_limit_observer(thresholds: Option<&[f64]>) -> RateLimitInfoC
         };
 
         if sorted.iter().any(|threshold| pct >= *threshold) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                usage_pct = pct * 100.0,
+                limit = info.limit,
+                remaining = info.remaining,
+                reset = info.reset,
+                http.request.method = %info.method,
+                url.full = %info.url,
+                "rate limit at {:.0}%",
+                pct * 100.0,
+            );
+            #[cfg(not(feature = "tracing"))]
             eprintln!(
                 "lago: rate limit at {:.0}% (limit={:?}, remaining={:?}, reset={:?}s, {} {})",
                 pct * 100.0,
@@ -0,0 +1,555 @@
+//! Auto-paginating streams over the `list_*` endpoints.
+//!
+//! Every list operation on [`LagoClient`] has a `*_stream` counterpart that
+//! returns a [`ListStream`]. The stream requests one page at a time through
+//! the regular request path (so retries and rate limit handling apply to
+//! every page), yields the items one by one and follows `meta.next_page`
+//! until the last page is reached or a configured cap is hit.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::Stream;
+use lago_types::{
+    error::Result,
+    models::{
+        ActivityLogObject, AddOn, ApiLogObject, AppliedCoupon, BillableMetric,
+        ChargeFilterResponse, Coupon, CreditNote, Customer, Event, Fee, FixedCharge, Invoice,
+        PaginationMeta, PaginationParams, Payment, Plan, PlanCharge, Subscription, Tax, Wallet,
+        WalletTransaction, WebhookEndpoint,
+    },
+    requests::{
+        activity_log::ListActivityLogsRequest,
+        add_on::ListAddOnsRequest,
+        api_log::ListApiLogsRequest,
+        applied_coupon::ListAppliedCouponsRequest,
+        billable_metric::ListBillableMetricsRequest,
+        coupon::ListCouponsRequest,
+        credit_note::ListCreditNotesRequest,
+        customer::ListCustomersRequest,
+        event::ListEventsRequest,
+        fee::ListFeesRequest,
+        invoice::{ListCustomerInvoicesRequest, ListInvoicesRequest},
+        payment::{ListCustomerPaymentsRequest, ListPaymentsRequest},
+        plan::ListPlansRequest,
+        plan_charge::{
+            ListPlanChargeFiltersRequest, ListPlanChargesRequest, ListPlanFixedChargesRequest,
+        },
+        subscription::{ListCustomerSubscriptionsRequest, ListSubscriptionsRequest},
+        subscription_charge::{
+            ListSubscriptionChargeFiltersRequest, ListSubscriptionChargesRequest,
+            ListSubscriptionFixedChargesRequest,
+        },
+        tax::ListTaxesRequest,
+        wallet::{ListWalletTransactionsRequest, ListWalletsRequest},
+        webhook_endpoint::ListWebhookEndpointsRequest,
+    },
+    responses::{
+        activity_log::ListActivityLogsResponse,
+        add_on::ListAddOnsResponse,
+        api_log::ListApiLogsResponse,
+        applied_coupon::ListAppliedCouponsResponse,
+        billable_metric::ListBillableMetricsResponse,
+        coupon::ListCouponsResponse,
+        credit_note::ListCreditNotesResponse,
+        customer::ListCustomersResponse,
+        event::ListEventsResponse,
+        fee::ListFeesResponse,
+        invoice::ListInvoicesResponse,
+        payment::ListPaymentsResponse,
+        plan::ListPlansResponse,
+        plan_charge::{
+            ListPlanChargeFiltersResponse, ListPlanChargesResponse, ListPlanFixedChargesResponse,
+        },
+        subscription::ListSubscriptionsResponse,
+        subscription_charge::{
+            ListSubscriptionChargeFiltersResponse, ListSubscriptionChargesResponse,
+            ListSubscriptionFixedChargesResponse,
+        },
+        tax::ListTaxesResponse,
+        wallet::{ListWalletTransactionsResponse, ListWalletsResponse},
+        webhook_endpoint::ListWebhookEndpointsResponse,
+    },
+};
+
+use crate::client::LagoClient;
+
+/// A list request that carries pagination parameters.
+///
+/// Implemented for every `List*Request` so a [`ListStream`] can move the
+/// request from one page to the next.
+pub trait PaginatedRequest: Clone + Send + 'static {
+    /// Returns the pagination parameters of the request.
+    fn pagination(&self) -> &PaginationParams;
+
+    /// Sets the page to request.
+    fn set_page(&mut self, page: i32);
+}
+
+/// A list response made of a page of items and pagination metadata.
+///
+/// Implemented for every `List*Response`.
+pub trait PaginatedResponse: Send + 'static {
+    /// The type of the items contained in a page.
+    type Item: Send + 'static;
+
+    /// Returns the pagination metadata of the page.
+    fn meta(&self) -> &PaginationMeta;
+
+    /// Consumes the response and returns the items of the page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+macro_rules! impl_paginated_request {
+    ($($request:ty),* $(,)?) => {
+        $(
+            impl PaginatedRequest for $request {
+                fn pagination(&self) -> &PaginationParams {
+                    &self.pagination
+                }
+
+                fn set_page(&mut self, page: i32) {
+                    self.pagination.page = Some(page);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_paginated_response {
+    ($($response:ty => $field:ident: $item:ty),* $(,)?) => {
+        $(
+            impl PaginatedResponse for $response {
+                type Item = $item;
+
+                fn meta(&self) -> &PaginationMeta {
+                    &self.meta
+                }
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.$field
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated_request!(
+    ListActivityLogsRequest,
+    ListAddOnsRequest,
+    ListApiLogsRequest,
+    ListAppliedCouponsRequest,
+    ListBillableMetricsRequest,
+    ListCouponsRequest,
+    ListCreditNotesRequest,
+    ListCustomersRequest,
+    ListEventsRequest,
+    ListFeesRequest,
+    ListInvoicesRequest,
+    ListCustomerInvoicesRequest,
+    ListPaymentsRequest,
+    ListCustomerPaymentsRequest,
+    ListPlansRequest,
+    ListPlanChargesRequest,
+    ListPlanFixedChargesRequest,
+    ListPlanChargeFiltersRequest,
+    ListSubscriptionsRequest,
+    ListCustomerSubscriptionsRequest,
+    ListSubscriptionChargesRequest,
+    ListSubscriptionFixedChargesRequest,
+    ListSubscriptionChargeFiltersRequest,
+    ListTaxesRequest,
+    ListWalletsRequest,
+    ListWalletTransactionsRequest,
+    ListWebhookEndpointsRequest,
+);
+
+impl_paginated_response!(
+    ListActivityLogsResponse => activity_logs: ActivityLogObject,
+    ListAddOnsResponse => add_ons: AddOn,
+    ListApiLogsResponse => api_logs: ApiLogObject,
+    ListAppliedCouponsResponse => applied_coupons: AppliedCoupon,
+    ListBillableMetricsResponse => billable_metrics: BillableMetric,
+    ListCouponsResponse => coupons: Coupon,
+    ListCreditNotesResponse => credit_notes: CreditNote,
+    ListCustomersResponse => customers: Customer,
+    ListEventsResponse => events: Event,
+    ListFeesResponse => fees: Fee,
+    ListInvoicesResponse => invoices: Invoice,
+    ListPaymentsResponse => payments: Payment,
+    ListPlansResponse => plans: Plan,
+    ListPlanChargesResponse => charges: PlanCharge,
+    ListPlanFixedChargesResponse => fixed_charges: FixedCharge,
+    ListPlanChargeFiltersResponse => filters: ChargeFilterResponse,
+    ListSubscriptionsResponse => subscriptions: Subscription,
+    ListSubscriptionChargesResponse => charges: PlanCharge,
+    ListSubscriptionFixedChargesResponse => fixed_charges: FixedCharge,
+    ListSubscriptionChargeFiltersResponse => filters: ChargeFilterResponse,
+    ListTaxesResponse => taxes: Tax,
+    ListWalletsResponse => wallets: Wallet,
+    ListWalletTransactionsResponse => wallet_transactions: WalletTransaction,
+    ListWebhookEndpointsResponse => webhook_endpoints: WebhookEndpoint,
+);
+
+/// A boxed future resolving to one page of a list endpoint.
+pub type PageFuture<P> = Pin<Box<dyn Future<Output = Result<P>> + Send>>;
+
+/// Function fetching a single page for a given request.
+pub type PageFetcher<R, P> = fn(LagoClient, R) -> PageFuture<P>;
+
+/// A [`Stream`] over every item of a paginated list endpoint.
+///
+/// Pages are fetched lazily, one at a time, starting from the page set on
+/// the request (or the first page). The stream ends after the last page,
+/// after `max_items` items or after `max_pages` pages, whichever comes
+/// first. An error fetching a page is yielded once and ends the stream.
+///
+/// # Example
+/// ```no_run
+/// use futures::TryStreamExt;
+/// use lago_client::LagoClient;
+///
+/// # async fn example() -> lago_types::error::Result<()> {
+/// let client = LagoClient::from_env()?;
+/// let invoices: Vec<_> = client
+///     .list_invoices_stream(None)
+///     .max_items(500)
+///     .try_collect()
+///     .await?;
+/// println!("Fetched {} invoices", invoices.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ListStream<R, P>
+where
+    R: PaginatedRequest,
+    P: PaginatedResponse,
+{
+    client: LagoClient,
+    request: R,
+    fetcher: PageFetcher<R, P>,
+    buffer: VecDeque<P::Item>,
+    in_flight: Option<PageFuture<P>>,
+    next_page: Option<i32>,
+    pages_fetched: usize,
+    items_yielded: usize,
+    max_items: Option<usize>,
+    max_pages: Option<usize>,
+}
+
+impl<R, P> ListStream<R, P>
+where
+    R: PaginatedRequest,
+    P: PaginatedResponse,
+{
+    /// Creates a new stream starting at the page set on `request`.
+    ///
+    /// # Arguments
+    /// * `client` - The client used to fetch every page
+    /// * `request` - The list request, including filters and pagination
+    /// * `fetcher` - Function fetching a single page for a request
+    ///
+    /// # Returns
+    /// A new `ListStream` instance
+    pub fn new(client: LagoClient, request: R, fetcher: PageFetcher<R, P>) -> Self {
+        let first_page = request.pagination().page.unwrap_or(1);
+
+        Self {
+            client,
+            request,
+            fetcher,
+            buffer: VecDeque::new(),
+            in_flight: None,
+            next_page: Some(first_page),
+            pages_fetched: 0,
+            items_yielded: 0,
+            max_items: None,
+            max_pages: None,
+        }
+    }
+
+    /// Stops the stream after yielding `max_items` items.
+    ///
+    /// # Arguments
+    /// * `max_items` - The maximum number of items to yield
+    ///
+    /// # Returns
+    /// The stream instance for method chaining
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Stops the stream after fetching `max_pages` pages.
+    ///
+    /// # Arguments
+    /// * `max_pages` - The maximum number of pages to fetch
+    ///
+    /// # Returns
+    /// The stream instance for method chaining
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Returns the number of pages fetched so far.
+    pub fn pages_fetched(&self) -> usize {
+        self.pages_fetched
+    }
+
+    fn items_exhausted(&self) -> bool {
+        self.max_items
+            .is_some_and(|max_items| self.items_yielded >= max_items)
+    }
+
+    fn pages_exhausted(&self) -> bool {
+        self.max_pages
+            .is_some_and(|max_pages| self.pages_fetched >= max_pages)
+    }
+}
+
+impl<R, P> Unpin for ListStream<R, P>
+where
+    R: PaginatedRequest,
+    P: PaginatedResponse,
+{
+}
+
+impl<R, P> Stream for ListStream<R, P>
+where
+    R: PaginatedRequest,
+    P: PaginatedResponse,
+{
+    type Item = Result<P::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.items_exhausted() {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                this.items_yielded += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if let Some(in_flight) = this.in_flight.as_mut() {
+                let result = match in_flight.as_mut().poll(cx) {
+                    Poll::Ready(result) => result,
+                    Poll::Pending => return Poll::Pending,
+                };
+                this.in_flight = None;
+
+                match result {
+                    Ok(response) => {
+                        this.pages_fetched += 1;
+                        this.next_page = response.meta().next_page;
+                        this.buffer.extend(response.into_items());
+                        continue;
+                    }
+                    Err(e) => {
+                        this.next_page = None;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+
+            let Some(page) = this.next_page.take() else {
+                return Poll::Ready(None);
+            };
+
+            if this.pages_exhausted() {
+                return Poll::Ready(None);
+            }
+
+            let mut request = this.request.clone();
+            request.set_page(page);
+            this.in_flight = Some((this.fetcher)(this.client.clone(), request));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Credentials, Region};
+    use futures::TryStreamExt;
+    use mockito::{Matcher, Server};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, Default)]
+    struct ListThingsRequest {
+        pagination: PaginationParams,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct ListThingsResponse {
+        things: Vec<String>,
+        meta: PaginationMeta,
+    }
+
+    impl_paginated_request!(ListThingsRequest);
+    impl_paginated_response!(ListThingsResponse => things: String);
+
+    fn fetch_things(
+        client: LagoClient,
+        request: ListThingsRequest,
+    ) -> PageFuture<ListThingsResponse> {
+        Box::pin(async move {
+            let region = client.config.region()?;
+            let url = format!(
+                "{}/things?page={}",
+                region.endpoint(),
+                request.pagination.page.unwrap_or(1)
+            );
+            client
+                .make_request("list_things", "GET", &url, None::<&()>)
+                .await
+        })
+    }
+
+    fn create_test_client(base_url: &str) -> LagoClient {
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom(base_url.to_string()))
+            .build();
+
+        LagoClient::new(config)
+    }
+
+    fn page_body(page: i32, total_pages: i32, things: &[&str]) -> String {
+        let next_page = if page < total_pages {
+            Some(page + 1)
+        } else {
+            None
+        };
+        json!({
+            "things": things,
+            "meta": {
+                "current_page": page,
+                "next_page": next_page,
+                "prev_page": if page > 1 { Some(page - 1) } else { None },
+                "total_pages": total_pages,
+                "total_count": 5,
+            }
+        })
+        .to_string()
+    }
+
+    async fn mock_pages(server: &mut mockito::ServerGuard) -> Vec<mockito::Mock> {
+        let pages: [&[&str]; 3] = [&["a", "b"], &["c", "d"], &["e"]];
+        let mut mocks = Vec::new();
+        for (index, things) in pages.iter().enumerate() {
+            let page = index as i32 + 1;
+            mocks.push(
+                server
+                    .mock("GET", "/things")
+                    .match_query(Matcher::UrlEncoded("page".into(), page.to_string()))
+                    .with_status(200)
+                    .with_body(page_body(page, 3, things))
+                    .create_async()
+                    .await,
+            );
+        }
+        mocks
+    }
+
+    #[tokio::test]
+    async fn test_stream_follows_next_page() {
+        let mut server = Server::new_async().await;
+        let mocks = mock_pages(&mut server).await;
+
+        let client = create_test_client(&server.url());
+        let things: Vec<String> =
+            ListStream::new(client, ListThingsRequest::default(), fetch_things)
+                .try_collect()
+                .await
+                .unwrap();
+
+        assert_eq!(things, vec!["a", "b", "c", "d", "e"]);
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_starts_at_requested_page() {
+        let mut server = Server::new_async().await;
+        let _mocks = mock_pages(&mut server).await;
+
+        let client = create_test_client(&server.url());
+        let request = ListThingsRequest {
+            pagination: PaginationParams::new().with_page(2),
+        };
+        let things: Vec<String> = ListStream::new(client, request, fetch_things)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(things, vec!["c", "d", "e"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_stops_at_max_items() {
+        let mut server = Server::new_async().await;
+        let _mocks = mock_pages(&mut server).await;
+
+        let client = create_test_client(&server.url());
+        let mut stream =
+            ListStream::new(client, ListThingsRequest::default(), fetch_things).max_items(3);
+        let mut things = Vec::new();
+        while let Some(thing) = stream.try_next().await.unwrap() {
+            things.push(thing);
+        }
+
+        assert_eq!(things, vec!["a", "b", "c"]);
+        assert_eq!(stream.pages_fetched(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stream_stops_at_max_pages() {
+        let mut server = Server::new_async().await;
+        let _mocks = mock_pages(&mut server).await;
+
+        let client = create_test_client(&server.url());
+        let things: Vec<String> =
+            ListStream::new(client, ListThingsRequest::default(), fetch_things)
+                .max_pages(1)
+                .try_collect()
+                .await
+                .unwrap();
+
+        assert_eq!(things, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_yields_error_and_ends() {
+        let mut server = Server::new_async().await;
+        let _first = server
+            .mock("GET", "/things")
+            .match_query(Matcher::UrlEncoded("page".into(), "1".into()))
+            .with_status(200)
+            .with_body(page_body(1, 2, &["a"]))
+            .create_async()
+            .await;
+        let _second = server
+            .mock("GET", "/things")
+            .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+            .with_status(400)
+            .with_body("Bad Request")
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let mut stream = ListStream::new(client, ListThingsRequest::default(), fetch_things);
+
+        assert_eq!(stream.try_next().await.unwrap(), Some("a".to_string()));
+        assert!(stream.try_next().await.is_err());
+        assert!(stream.try_next().await.unwrap().is_none());
+    }
+}
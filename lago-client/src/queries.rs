@@ -1,4 +1,5 @@
 pub mod activity_log;
+pub mod add_on;
 pub mod api_log;
 pub mod applied_coupon;
 pub mod billable_metric;
@@ -14,3 +15,6 @@ pub mod plan;
 pub mod plan_charge;
 pub mod subscription;
 pub mod subscription_charge;
+pub mod tax;
+pub mod wallet;
+pub mod webhook_endpoint;
@@ -6,6 +6,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// Activity log-related operations for the Lago client
 impl LagoClient {
@@ -16,6 +17,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of activity logs or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_activity_logs(
         &self,
         request: Option<ListActivityLogsRequest>,
@@ -36,7 +38,32 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_activity_logs", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all activity logs, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_activity_logs`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the activity logs list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each activity log or an error
+    pub fn list_activity_logs_stream(
+        &self,
+        request: Option<ListActivityLogsRequest>,
+    ) -> ListStream<ListActivityLogsRequest, ListActivityLogsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| {
+                Box::pin(async move { client.list_activity_logs(Some(request)).await })
+            },
+        )
     }
 
     /// Retrieves a specific activity log by its activity ID
@@ -46,6 +73,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the activity log data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.activity_id))
+    )]
     pub async fn get_activity_log(
         &self,
         request: GetActivityLogRequest,
@@ -56,6 +87,7 @@ impl LagoClient {
             region.endpoint(),
             request.activity_id
         );
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_activity_log", "GET", &url, None::<&()>)
+            .await
     }
 }
@@ -0,0 +1,155 @@
+use lago_types::{
+    error::{LagoError, Result},
+    requests::add_on::{
+        CreateAddOnRequest, DeleteAddOnRequest, GetAddOnRequest, ListAddOnsRequest,
+        UpdateAddOnRequest,
+    },
+    responses::add_on::{
+        CreateAddOnResponse, DeleteAddOnResponse, GetAddOnResponse, ListAddOnsResponse,
+        UpdateAddOnResponse,
+    },
+};
+use serde::Serialize;
+use url::Url;
+
+use crate::client::LagoClient;
+use crate::pagination::ListStream;
+
+/// Internal struct for serializing update request body (without the code field)
+#[derive(Serialize)]
+struct UpdateAddOnBody {
+    add_on: lago_types::requests::add_on::UpdateAddOnInput,
+}
+
+impl LagoClient {
+    /// Retrieves a list of add-ons with optional filtering parameters
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the add-on list
+    ///
+    /// # Returns
+    /// A `Result` containing the list of add-ons or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn list_add_ons(
+        &self,
+        request: Option<ListAddOnsRequest>,
+    ) -> Result<ListAddOnsResponse> {
+        let request = request.unwrap_or_default();
+        let region = self.config.region()?;
+        let mut url = Url::parse(&format!("{}/add_ons", region.endpoint()))
+            .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
+
+        let query_params = request.to_query_params();
+
+        if !query_params.is_empty() {
+            let query_string = query_params
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            url.set_query(Some(&query_string));
+        }
+
+        self.make_request("list_add_ons", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all add-ons, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_add_ons`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the add-ons list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each add-on or an error
+    pub fn list_add_ons_stream(
+        &self,
+        request: Option<ListAddOnsRequest>,
+    ) -> ListStream<ListAddOnsRequest, ListAddOnsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_add_ons(Some(request)).await }),
+        )
+    }
+
+    /// Retrieves an add-on by its code
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the add-on code to retrieve
+    ///
+    /// # Returns
+    /// A `Result` containing the add-on data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
+    pub async fn get_add_on(&self, request: GetAddOnRequest) -> Result<GetAddOnResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/add_ons/{}", region.endpoint(), request.code);
+
+        self.make_request("get_add_on", "GET", &url, None::<&()>)
+            .await
+    }
+
+    /// Creates a new add-on
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the add-on data to create
+    ///
+    /// # Returns
+    /// A `Result` containing the created add-on data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_add_on(&self, request: CreateAddOnRequest) -> Result<CreateAddOnResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/add_ons", region.endpoint());
+
+        self.make_request("create_add_on", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Updates an existing add-on
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the add-on code and update data
+    ///
+    /// # Returns
+    /// A `Result` containing the updated add-on data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
+    pub async fn update_add_on(&self, request: UpdateAddOnRequest) -> Result<UpdateAddOnResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/add_ons/{}", region.endpoint(), request.code);
+
+        let body = UpdateAddOnBody {
+            add_on: request.add_on,
+        };
+
+        self.make_request("update_add_on", "PUT", &url, Some(&body))
+            .await
+    }
+
+    /// Deletes an add-on by its code
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the add-on code to delete
+    ///
+    /// # Returns
+    /// A `Result` containing the deleted add-on data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
+    pub async fn delete_add_on(&self, request: DeleteAddOnRequest) -> Result<DeleteAddOnResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/add_ons/{}", region.endpoint(), request.code);
+
+        self.make_request("delete_add_on", "DELETE", &url, None::<&()>)
+            .await
+    }
+}
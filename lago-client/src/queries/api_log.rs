@@ -6,6 +6,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// API log-related operations for the Lago client
 impl LagoClient {
@@ -16,6 +17,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of API logs or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_api_logs(
         &self,
         request: Option<ListApiLogsRequest>,
@@ -36,7 +38,30 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_api_logs", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all API logs, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_api_logs`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the api logs list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each API log or an error
+    pub fn list_api_logs_stream(
+        &self,
+        request: Option<ListApiLogsRequest>,
+    ) -> ListStream<ListApiLogsRequest, ListApiLogsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_api_logs(Some(request)).await }),
+        )
     }
 
     /// Retrieves a specific API log by its request ID
@@ -46,9 +71,14 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the API log data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.request_id))
+    )]
     pub async fn get_api_log(&self, request: GetApiLogRequest) -> Result<GetApiLogResponse> {
         let region = self.config.region()?;
         let url = format!("{}/api_logs/{}", region.endpoint(), request.request_id);
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_api_log", "GET", &url, None::<&()>)
+            .await
     }
 }
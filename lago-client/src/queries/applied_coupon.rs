@@ -6,6 +6,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 impl LagoClient {
     /// Retrieves a list of applied coupons with optional filtering parameters
@@ -15,6 +16,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of applied coupons or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_applied_coupons(
         &self,
         request: Option<ListAppliedCouponsRequest>,
@@ -35,7 +37,32 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_applied_coupons", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all applied coupons, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_applied_coupons`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the applied coupons list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each applied coupon or an error
+    pub fn list_applied_coupons_stream(
+        &self,
+        request: Option<ListAppliedCouponsRequest>,
+    ) -> ListStream<ListAppliedCouponsRequest, ListAppliedCouponsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| {
+                Box::pin(async move { client.list_applied_coupons(Some(request)).await })
+            },
+        )
     }
 
     /// Applies a coupon to a customer
@@ -45,10 +72,19 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the applied coupon data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn apply_coupon(&self, request: ApplyCouponRequest) -> Result<ApplyCouponResponse> {
         let region = self.config.region()?;
         let url = format!("{}/applied_coupons", region.endpoint());
 
-        self.make_request("POST", &url, Some(&request)).await
+        let idempotency_key = request.idempotency_key.clone();
+        self.make_request_with_key(
+            "apply_coupon",
+            "POST",
+            &url,
+            Some(&request),
+            idempotency_key,
+        )
+        .await
     }
 }
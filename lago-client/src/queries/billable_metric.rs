@@ -12,6 +12,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 impl LagoClient {
     /// Retrieves a list of billable metrics with optional filtering parameters
@@ -21,6 +22,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of billable metrics or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_billable_metrics(
         &self,
         request: Option<ListBillableMetricsRequest>,
@@ -41,7 +43,32 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_billable_metrics", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all billable metrics, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_billable_metrics`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the billable metrics list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each billable metric or an error
+    pub fn list_billable_metrics_stream(
+        &self,
+        request: Option<ListBillableMetricsRequest>,
+    ) -> ListStream<ListBillableMetricsRequest, ListBillableMetricsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| {
+                Box::pin(async move { client.list_billable_metrics(Some(request)).await })
+            },
+        )
     }
 
     /// Retrieves a specific billable metric by its code
@@ -51,6 +78,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the billable metric data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn get_billable_metric(
         &self,
         request: GetBillableMetricRequest,
@@ -58,7 +89,8 @@ impl LagoClient {
         let region = self.config.region()?;
         let url = format!("{}/billable_metrics/{}", region.endpoint(), request.code);
 
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_billable_metric", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Creates a new billable metric
@@ -68,6 +100,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created billable metric data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_billable_metric(
         &self,
         request: CreateBillableMetricRequest,
@@ -75,7 +108,8 @@ impl LagoClient {
         let region = self.config.region()?;
         let url = format!("{}/billable_metrics", region.endpoint());
 
-        self.make_request("POST", &url, Some(&request)).await
+        self.make_request("create_billable_metric", "POST", &url, Some(&request))
+            .await
     }
 
     /// Updates an existing billable metric by its code
@@ -85,6 +119,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the updated billable metric data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn update_billable_metric(
         &self,
         request: UpdateBillableMetricRequest,
@@ -97,6 +135,12 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request(
+            "update_billable_metric",
+            "PUT",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 }
@@ -13,6 +13,7 @@ use serde::Serialize;
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// Internal struct for serializing update request body (without the code field)
 #[derive(Serialize)]
@@ -28,6 +29,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of coupons or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_coupons(
         &self,
         request: Option<ListCouponsRequest>,
@@ -48,7 +50,30 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_coupons", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all coupons, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_coupons`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the coupons list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each coupon or an error
+    pub fn list_coupons_stream(
+        &self,
+        request: Option<ListCouponsRequest>,
+    ) -> ListStream<ListCouponsRequest, ListCouponsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_coupons(Some(request)).await }),
+        )
     }
 
     /// Retrieves a coupon by its code
@@ -58,11 +83,16 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the coupon data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn get_coupon(&self, request: GetCouponRequest) -> Result<GetCouponResponse> {
         let region = self.config.region()?;
         let url = format!("{}/coupons/{}", region.endpoint(), request.code);
 
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_coupon", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Creates a new coupon
@@ -72,6 +102,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created coupon data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_coupon(
         &self,
         request: CreateCouponRequest,
@@ -79,7 +110,8 @@ impl LagoClient {
         let region = self.config.region()?;
         let url = format!("{}/coupons", region.endpoint());
 
-        self.make_request("POST", &url, Some(&request)).await
+        self.make_request("create_coupon", "POST", &url, Some(&request))
+            .await
     }
 
     /// Updates an existing coupon
@@ -89,6 +121,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the updated coupon data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn update_coupon(
         &self,
         request: UpdateCouponRequest,
@@ -100,7 +136,8 @@ impl LagoClient {
             coupon: request.coupon,
         };
 
-        self.make_request("PUT", &url, Some(&body)).await
+        self.make_request("update_coupon", "PUT", &url, Some(&body))
+            .await
     }
 
     /// Deletes a coupon by its code
@@ -110,6 +147,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the deleted coupon data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn delete_coupon(
         &self,
         request: DeleteCouponRequest,
@@ -117,6 +158,7 @@ impl LagoClient {
         let region = self.config.region()?;
         let url = format!("{}/coupons/{}", region.endpoint(), request.code);
 
-        self.make_request("DELETE", &url, None::<&()>).await
+        self.make_request("delete_coupon", "DELETE", &url, None::<&()>)
+            .await
     }
 }
@@ -1,17 +1,20 @@
 use lago_types::{
     error::{LagoError, Result},
     requests::credit_note::{
-        CreateCreditNoteRequest, GetCreditNoteRequest, ListCreditNotesRequest,
-        UpdateCreditNoteRequest,
+        CreateCreditNoteRequest, DownloadCreditNoteRequest, EstimateCreditNoteRequest,
+        GetCreditNoteRequest, ListCreditNotesRequest, UpdateCreditNoteRequest,
+        VoidCreditNoteRequest,
     },
     responses::credit_note::{
-        CreateCreditNoteResponse, GetCreditNoteResponse, ListCreditNotesResponse,
-        UpdateCreditNoteResponse,
+        CreateCreditNoteResponse, DownloadCreditNoteResponse, EstimateCreditNoteResponse,
+        GetCreditNoteResponse, ListCreditNotesResponse, UpdateCreditNoteResponse,
+        VoidCreditNoteResponse,
     },
 };
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// Credit note-related operations for the Lago client
 impl LagoClient {
@@ -38,6 +41,7 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_credit_notes(
         &self,
         request: Option<ListCreditNotesRequest>,
@@ -58,7 +62,32 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_credit_notes", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all credit notes, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_credit_notes`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the credit notes list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each credit note or an error
+    pub fn list_credit_notes_stream(
+        &self,
+        request: Option<ListCreditNotesRequest>,
+    ) -> ListStream<ListCreditNotesRequest, ListCreditNotesResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| {
+                Box::pin(async move { client.list_credit_notes(Some(request)).await })
+            },
+        )
     }
 
     /// Retrieves a specific credit note by its Lago ID
@@ -82,13 +111,18 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn get_credit_note(
         &self,
         request: GetCreditNoteRequest,
     ) -> Result<GetCreditNoteResponse> {
         let region = self.config.region()?;
         let url = format!("{}/credit_notes/{}", region.endpoint(), request.lago_id);
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_credit_note", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Creates a new credit note
@@ -130,13 +164,22 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_credit_note(
         &self,
         request: CreateCreditNoteRequest,
     ) -> Result<CreateCreditNoteResponse> {
         let region = self.config.region()?;
         let url = format!("{}/credit_notes", region.endpoint());
-        self.make_request("POST", &url, Some(&request)).await
+        let idempotency_key = request.idempotency_key.clone();
+        self.make_request_with_key(
+            "create_credit_note",
+            "POST",
+            &url,
+            Some(&request),
+            idempotency_key,
+        )
+        .await
     }
 
     /// Updates an existing credit note
@@ -169,12 +212,118 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn update_credit_note(
         &self,
         request: UpdateCreditNoteRequest,
     ) -> Result<UpdateCreditNoteResponse> {
         let region = self.config.region()?;
         let url = format!("{}/credit_notes/{}", region.endpoint(), request.lago_id);
-        self.make_request("PUT", &url, Some(&request)).await
+        self.make_request("update_credit_note", "PUT", &url, Some(&request))
+            .await
+    }
+
+    /// Voids a credit note
+    ///
+    /// The remaining credit balance of the credit note is set to zero, so it
+    /// can no longer be applied to future invoices.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the Lago ID of the credit note to void
+    ///
+    /// # Returns
+    /// A `Result` containing the voided credit note or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn void_credit_note(
+        &self,
+        request: VoidCreditNoteRequest,
+    ) -> Result<VoidCreditNoteResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/credit_notes/{}/void",
+            region.endpoint(),
+            request.lago_id
+        );
+        self.make_request("void_credit_note", "PUT", &url, None::<&()>)
+            .await
+    }
+
+    /// Downloads a credit note PDF
+    ///
+    /// Triggers the generation of the PDF if needed. The `file_url` of the
+    /// returned credit note points to the PDF once it is generated.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the Lago ID of the credit note to download
+    ///
+    /// # Returns
+    /// A `Result` containing the credit note or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn download_credit_note(
+        &self,
+        request: DownloadCreditNoteRequest,
+    ) -> Result<DownloadCreditNoteResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/credit_notes/{}/download",
+            region.endpoint(),
+            request.lago_id
+        );
+        self.make_request("download_credit_note", "POST", &url, None::<&()>)
+            .await
+    }
+
+    /// Estimates a credit note before creating it
+    ///
+    /// Returns the amounts that can be credited or refunded for the given
+    /// fee items, and the taxes that apply, without creating anything.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the invoice and the fee items to estimate
+    ///
+    /// # Returns
+    /// A `Result` containing the estimated credit note or an error
+    ///
+    /// # Example
+    /// ```no_run
+    /// use lago_client::LagoClient;
+    /// use lago_types::requests::credit_note::{
+    ///     CreateCreditNoteItemInput, EstimateCreditNoteInput, EstimateCreditNoteRequest,
+    /// };
+    ///
+    /// # async fn example() -> lago_types::error::Result<()> {
+    /// let client = LagoClient::from_env()?;
+    /// let items = vec![
+    ///     CreateCreditNoteItemInput::new("fee_lago_id".to_string(), 1000),
+    /// ];
+    /// let input = EstimateCreditNoteInput::new("invoice_lago_id".to_string(), items);
+    ///
+    /// let response = client.estimate_credit_note(EstimateCreditNoteRequest::new(input)).await?;
+    /// let estimate = response.estimated_credit_note;
+    /// println!(
+    ///     "Up to {} refundable, {} in taxes",
+    ///     estimate.max_refundable_amount_cents, estimate.taxes_amount_cents
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn estimate_credit_note(
+        &self,
+        request: EstimateCreditNoteRequest,
+    ) -> Result<EstimateCreditNoteResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/credit_notes/estimate", region.endpoint());
+        self.make_request("estimate_credit_note", "POST", &url, Some(&request))
+            .await
     }
 }
@@ -1,11 +1,35 @@
 use lago_types::{
     error::{LagoError, Result},
-    requests::customer::{CreateCustomerRequest, GetCustomerRequest, ListCustomersRequest},
-    responses::customer::{CreateCustomerResponse, GetCustomerResponse, ListCustomersResponse},
+    requests::customer::{
+        AttachCustomerTaxesRequest, CreateCustomerRequest, DeleteCustomerRequest,
+        DetachCustomerTaxesRequest, GenerateCustomerCheckoutUrlRequest,
+        GetCustomerPortalUrlRequest, GetCustomerRequest, ListCustomersRequest, UpdateCustomerInput,
+        UpdateCustomerRequest,
+    },
+    responses::customer::{
+        CreateCustomerResponse, DeleteCustomerResponse, GenerateCustomerCheckoutUrlResponse,
+        GetCustomerPortalUrlResponse, GetCustomerResponse, ListCustomersResponse,
+        UpdateCustomerResponse, UpdateCustomerTaxesResponse,
+    },
 };
+use serde::Serialize;
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
+
+/// Internal struct for serializing the upsert body updating a customer
+#[derive(Serialize)]
+struct UpdateCustomerBody {
+    customer: UpdateCustomerFields,
+}
+
+#[derive(Serialize)]
+struct UpdateCustomerFields {
+    external_id: String,
+    #[serde(flatten)]
+    input: UpdateCustomerInput,
+}
 
 impl LagoClient {
     /// Retrieves a list of customers with optional filtering parameters
@@ -15,6 +39,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of customers or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_customers(
         &self,
         request: Option<ListCustomersRequest>,
@@ -35,7 +60,30 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_customers", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all customers, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_customers`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the customers list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each customer or an error
+    pub fn list_customers_stream(
+        &self,
+        request: Option<ListCustomersRequest>,
+    ) -> ListStream<ListCustomersRequest, ListCustomersResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_customers(Some(request)).await }),
+        )
     }
 
     /// Retrieves a specific customer by their external ID
@@ -45,11 +93,16 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the customer data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
     pub async fn get_customer(&self, request: GetCustomerRequest) -> Result<GetCustomerResponse> {
         let region = self.config.region()?;
         let url = format!("{}/customers/{}", region.endpoint(), request.external_id);
 
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_customer", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Creates a new customer
@@ -59,6 +112,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created customer data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_customer(
         &self,
         request: CreateCustomerRequest,
@@ -66,6 +120,354 @@ impl LagoClient {
         let region = self.config.region()?;
         let url = format!("{}/customers", region.endpoint());
 
-        self.make_request("POST", &url, Some(&request)).await
+        self.make_request("create_customer", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Updates an existing customer
+    ///
+    /// Lago has no dedicated update endpoint: the customer is upserted on its
+    /// external ID with only the fields set in the request, so the other ones
+    /// are left untouched.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID and update data
+    ///
+    /// # Returns
+    /// A `Result` containing the updated customer data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn update_customer(
+        &self,
+        request: UpdateCustomerRequest,
+    ) -> Result<UpdateCustomerResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/customers", region.endpoint());
+
+        let body = UpdateCustomerBody {
+            customer: UpdateCustomerFields {
+                external_id: request.external_id,
+                input: request.customer,
+            },
+        };
+
+        self.make_request("update_customer", "POST", &url, Some(&body))
+            .await
+    }
+
+    /// Deletes a customer by their external ID
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the external ID of the customer to delete
+    ///
+    /// # Returns
+    /// A `Result` containing the deleted customer data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
+    pub async fn delete_customer(
+        &self,
+        request: DeleteCustomerRequest,
+    ) -> Result<DeleteCustomerResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/customers/{}", region.endpoint(), request.external_id);
+
+        self.make_request("delete_customer", "DELETE", &url, None::<&()>)
+            .await
+    }
+
+    /// Retrieves the URL of the customer portal
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID
+    ///
+    /// # Returns
+    /// A `Result` containing the portal URL or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
+    pub async fn get_customer_portal_url(
+        &self,
+        request: GetCustomerPortalUrlRequest,
+    ) -> Result<GetCustomerPortalUrlResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/customers/{}/portal_url",
+            region.endpoint(),
+            request.external_id
+        );
+
+        self.make_request("get_customer_portal_url", "GET", &url, None::<&()>)
+            .await
+    }
+
+    /// Generates a checkout URL to collect the payment method of a customer
+    ///
+    /// The URL is generated by the payment provider set in the billing
+    /// configuration of the customer.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID
+    ///
+    /// # Returns
+    /// A `Result` containing the checkout URL or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
+    pub async fn generate_customer_checkout_url(
+        &self,
+        request: GenerateCustomerCheckoutUrlRequest,
+    ) -> Result<GenerateCustomerCheckoutUrlResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/customers/{}/checkout_url",
+            region.endpoint(),
+            request.external_id
+        );
+
+        self.make_request("generate_customer_checkout_url", "POST", &url, None::<&()>)
+            .await
+    }
+
+    /// Applies taxes to a customer
+    ///
+    /// The customer is fetched, then upserted with its current tax codes
+    /// followed by the new ones, so the taxes already applied are kept.
+    ///
+    /// The API only replaces the whole list of tax codes, so this is not
+    /// atomic: a concurrent change of the customer's taxes made between both
+    /// requests is overwritten. Serialize the tax changes of a customer, or use
+    /// [`LagoClient::update_customer`] with the complete list of tax codes.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID and the tax codes to apply
+    ///
+    /// # Returns
+    /// A `Result` containing the updated customer or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_customer_id))
+    )]
+    pub async fn attach_customer_taxes(
+        &self,
+        request: AttachCustomerTaxesRequest,
+    ) -> Result<UpdateCustomerTaxesResponse> {
+        let mut tax_codes = self
+            .customer_tax_codes("attach_customer_taxes", &request.external_customer_id)
+            .await?;
+        for code in request.tax_codes {
+            if !tax_codes.contains(&code) {
+                tax_codes.push(code);
+            }
+        }
+
+        self.set_customer_tax_codes(
+            "attach_customer_taxes",
+            request.external_customer_id,
+            tax_codes,
+        )
+        .await
+    }
+
+    /// Removes taxes from a customer
+    ///
+    /// The customer is fetched, then upserted with its current tax codes
+    /// minus the removed ones. Like [`LagoClient::attach_customer_taxes`], this
+    /// is not atomic.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID and the tax codes to remove
+    ///
+    /// # Returns
+    /// A `Result` containing the updated customer or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_customer_id))
+    )]
+    pub async fn detach_customer_taxes(
+        &self,
+        request: DetachCustomerTaxesRequest,
+    ) -> Result<UpdateCustomerTaxesResponse> {
+        let mut tax_codes = self
+            .customer_tax_codes("detach_customer_taxes", &request.external_customer_id)
+            .await?;
+        tax_codes.retain(|code| !request.tax_codes.contains(code));
+
+        self.set_customer_tax_codes(
+            "detach_customer_taxes",
+            request.external_customer_id,
+            tax_codes,
+        )
+        .await
+    }
+
+    /// Returns the codes of the taxes applied to a customer.
+    async fn customer_tax_codes(
+        &self,
+        operation: &'static str,
+        external_id: &str,
+    ) -> Result<Vec<String>> {
+        let region = self.config.region()?;
+        let url = format!("{}/customers/{}", region.endpoint(), external_id);
+
+        let response: GetCustomerResponse = self
+            .make_request(operation, "GET", &url, None::<&()>)
+            .await?;
+        Ok(response
+            .customer
+            .taxes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|tax| tax.code)
+            .collect())
+    }
+
+    /// Upserts a customer with only its tax codes, leaving the other fields untouched.
+    async fn set_customer_tax_codes(
+        &self,
+        operation: &'static str,
+        external_id: String,
+        tax_codes: Vec<String>,
+    ) -> Result<UpdateCustomerTaxesResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/customers", region.endpoint());
+
+        let body = UpdateCustomerBody {
+            customer: UpdateCustomerFields {
+                external_id,
+                input: UpdateCustomerInput::new().with_tax_codes(tax_codes),
+            },
+        };
+
+        self.make_request(operation, "POST", &url, Some(&body))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::transport::customer_json;
+    use crate::{Config, Credentials, InMemoryTransport, Region, TransportResponse};
+
+    fn client(transport: &InMemoryTransport) -> LagoClient {
+        LagoClient::new(
+            Config::builder()
+                .credentials(Credentials::new("test-api-key".to_string()))
+                .region(Region::Custom("http://lago.test/api/v1".to_string()))
+                .transport(Arc::new(transport.clone()))
+                .build(),
+        )
+    }
+
+    #[tokio::test]
+    async fn attach_and_detach_customer_taxes_keep_other_taxes() {
+        let transport = InMemoryTransport::new();
+        transport.push_response(TransportResponse::json(200, &customer_json(&["vat_20"])));
+        transport.push_response(TransportResponse::json(
+            200,
+            &customer_json(&["vat_20", "gst_5"]),
+        ));
+        transport.push_response(TransportResponse::json(
+            200,
+            &customer_json(&["vat_20", "gst_5"]),
+        ));
+        transport.push_response(TransportResponse::json(200, &customer_json(&["gst_5"])));
+        let client = client(&transport);
+
+        let request = AttachCustomerTaxesRequest::new(
+            "cust_123".to_string(),
+            vec!["gst_5".to_string(), "vat_20".to_string()],
+        );
+        let customer = client
+            .attach_customer_taxes(request)
+            .await
+            .unwrap()
+            .customer;
+        assert_eq!(customer.taxes.unwrap().len(), 2);
+
+        let request =
+            DetachCustomerTaxesRequest::new("cust_123".to_string(), vec!["vat_20".to_string()]);
+        client.detach_customer_taxes(request).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].url,
+            "http://lago.test/api/v1/customers/cust_123"
+        );
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].url, "http://lago.test/api/v1/customers");
+        assert_eq!(
+            requests[1].json_body().unwrap(),
+            json!({"customer": {"external_id": "cust_123", "tax_codes": ["vat_20", "gst_5"]}})
+        );
+        assert_eq!(
+            requests[3].json_body().unwrap(),
+            json!({"customer": {"external_id": "cust_123", "tax_codes": ["gst_5"]}})
+        );
+    }
+
+    #[tokio::test]
+    async fn update_customer_sends_only_set_fields() {
+        let transport = InMemoryTransport::new();
+        transport.push_response(TransportResponse::json(200, &customer_json(&[])));
+        let client = client(&transport);
+
+        let input = UpdateCustomerInput::new().with_email("billing@acme.test".to_string());
+        client
+            .update_customer(UpdateCustomerRequest::new("cust_123".to_string(), input))
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url, "http://lago.test/api/v1/customers");
+        assert_eq!(
+            requests[0].json_body().unwrap(),
+            json!({"customer": {"external_id": "cust_123", "email": "billing@acme.test"}})
+        );
+    }
+
+    #[tokio::test]
+    async fn generates_customer_checkout_url() {
+        let transport = InMemoryTransport::new();
+        transport.push_response(TransportResponse::json(
+            200,
+            &json!({
+                "customer": {
+                    "lago_customer_id": "1a901a90-1a90-1a90-1a90-1a901a901a90",
+                    "external_customer_id": "cust_123",
+                    "payment_provider": "stripe",
+                    "checkout_url": "https://checkout.stripe.test/c/pay/cs_123"
+                }
+            }),
+        ));
+        let client = client(&transport);
+
+        let checkout = client
+            .generate_customer_checkout_url(GenerateCustomerCheckoutUrlRequest::new(
+                "cust_123".to_string(),
+            ))
+            .await
+            .unwrap()
+            .customer;
+
+        assert_eq!(
+            checkout.checkout_url,
+            "https://checkout.stripe.test/c/pay/cs_123"
+        );
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(
+            request.url,
+            "http://lago.test/api/v1/customers/cust_123/checkout_url"
+        );
     }
 }
@@ -18,6 +18,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the customer usage data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_customer_id))
+    )]
     pub async fn get_customer_current_usage(
         &self,
         request: GetCustomerCurrentUsageRequest,
@@ -41,6 +45,12 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "get_customer_current_usage",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 }
@@ -1,11 +1,32 @@
 use lago_types::{
     error::{LagoError, Result},
-    requests::event::{CreateEventRequest, GetEventRequest, ListEventsRequest},
-    responses::event::{CreateEventResponse, GetEventResponse, ListEventsResponse},
+    requests::event::{
+        CreateEventInput, CreateEventRequest, CreateEventsBatchRequest, GetEventRequest,
+        ListEventsRequest,
+    },
+    responses::event::{
+        CreateEventResponse, CreateEventsBatchResponse, EventCreated, EventsBatchChunk,
+        GetEventResponse, ListEventsResponse,
+    },
 };
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
+
+/// Internal struct for serializing one chunk of a batch request
+#[derive(Serialize)]
+struct EventsBatchBody<'a> {
+    events: &'a [CreateEventInput],
+}
+
+/// Internal struct for deserializing the acknowledgment of one chunk
+#[derive(Deserialize)]
+struct EventsBatchChunkResponse {
+    #[serde(default)]
+    events: Vec<EventCreated>,
+}
 
 impl LagoClient {
     /// Retrieves a specific event by its transaction ID
@@ -29,13 +50,18 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.transaction_id))
+    )]
     pub async fn get_event(&self, request: GetEventRequest) -> Result<GetEventResponse> {
         let region = self.config.region()?;
         // URL encode the transaction_id to handle special characters
         let encoded_transaction_id = urlencoding::encode(&request.transaction_id).into_owned();
         let url = format!("{}/events/{}", region.endpoint(), encoded_transaction_id);
 
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_event", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Creates a new usage event
@@ -70,11 +96,106 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_event(&self, request: CreateEventRequest) -> Result<CreateEventResponse> {
         let region = self.config.region()?;
         let url = format!("{}/events", region.endpoint());
 
-        self.make_request("POST", &url, Some(&request)).await
+        self.make_request("create_event", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Creates usage events in batches
+    ///
+    /// The events are split into chunks of at most
+    /// [`MAX_EVENTS_PER_BATCH`](lago_types::requests::event::MAX_EVENTS_PER_BATCH)
+    /// events, sent one after the other to `POST /events/batch`. Each chunk goes
+    /// through the configured retry policy. A rejected chunk doesn't stop the
+    /// following ones: its error and transaction IDs are reported in the response.
+    /// Errors that no chunk can get past (invalid configuration or API key,
+    /// forbidden access, missing endpoint) stop the batch and are returned instead.
+    ///
+    /// With a key set by [`CreateEventsBatchRequest::with_idempotency_key`],
+    /// chunk `n` is sent with the key `{key}-{n}`, so that retrying the whole batch with the same
+    /// key only replays the chunks that were already ingested.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the events to create
+    ///
+    /// # Returns
+    /// A `Result` containing the outcome of each chunk, or an error if the
+    /// client can't send any of them
+    ///
+    /// # Example
+    /// ```no_run
+    /// use lago_client::LagoClient;
+    /// use lago_types::requests::event::{CreateEventInput, CreateEventsBatchRequest};
+    ///
+    /// # async fn example() -> lago_types::error::Result<()> {
+    /// let client = LagoClient::from_env()?;
+    /// let events = (0..250)
+    ///     .map(|i| {
+    ///         CreateEventInput::for_subscription(
+    ///             format!("transaction_{i}"),
+    ///             "sub_123".to_string(),
+    ///             "api_calls".to_string(),
+    ///         )
+    ///     })
+    ///     .collect();
+    ///
+    /// let response = client
+    ///     .create_events_batch(CreateEventsBatchRequest::new(events))
+    ///     .await?;
+    /// for transaction_id in response.failed_transaction_ids() {
+    ///     println!("Failed to ingest {transaction_id}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_events_batch(
+        &self,
+        request: CreateEventsBatchRequest,
+    ) -> Result<CreateEventsBatchResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/events/batch", region.endpoint());
+
+        let idempotency_key = request.idempotency_key.clone();
+        let mut chunks = Vec::new();
+        for (index, events) in request.chunks().enumerate() {
+            let transaction_ids = events
+                .iter()
+                .map(|event| event.transaction_id.clone())
+                .collect();
+            let body = EventsBatchBody { events };
+            let chunk_key = idempotency_key.as_ref().map(|key| format!("{key}-{index}"));
+
+            let chunk = match self
+                .make_request_with_key::<EventsBatchChunkResponse, _>(
+                    "create_events_batch",
+                    "POST",
+                    &url,
+                    Some(&body),
+                    chunk_key,
+                )
+                .await
+            {
+                Ok(response) => EventsBatchChunk {
+                    transaction_ids,
+                    events: response.events,
+                    error: None,
+                },
+                Err(error) if aborts_batch(&error) => return Err(error),
+                Err(error) => EventsBatchChunk {
+                    transaction_ids,
+                    events: Vec::new(),
+                    error: Some(error),
+                },
+            };
+            chunks.push(chunk);
+        }
+
+        Ok(CreateEventsBatchResponse { chunks })
     }
 
     /// Retrieves a list of events with optional filtering parameters
@@ -115,6 +236,7 @@ impl LagoClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_events(
         &self,
         request: Option<ListEventsRequest>,
@@ -135,6 +257,235 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_events", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all events, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_events`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the events list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each event or an error
+    pub fn list_events_stream(
+        &self,
+        request: Option<ListEventsRequest>,
+    ) -> ListStream<ListEventsRequest, ListEventsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_events(Some(request)).await }),
+        )
+    }
+}
+
+/// Whether a chunk error would fail every following chunk too.
+fn aborts_batch(error: &LagoError) -> bool {
+    matches!(
+        error,
+        LagoError::Configuration(_)
+            | LagoError::InvalidInput(_)
+            | LagoError::Unauthorized
+            | LagoError::Forbidden { .. }
+            | LagoError::NotFound { .. }
+            | LagoError::Api {
+                status: 401 | 403 | 404,
+                ..
+            }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Credentials, Region, RetryConfig, RetryMode};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn create_test_client(base_url: &str) -> LagoClient {
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom(base_url.to_string()))
+            .retry_config(RetryConfig::builder().mode(RetryMode::Off).build())
+            .build();
+
+        LagoClient::new(config)
+    }
+
+    fn events(count: usize) -> Vec<CreateEventInput> {
+        (0..count)
+            .map(|i| {
+                CreateEventInput::for_customer(
+                    format!("tx_{i}"),
+                    "customer_123".to_string(),
+                    "api_calls".to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn acknowledgment(ids: std::ops::Range<usize>) -> String {
+        let events: Vec<_> = ids
+            .map(|i| json!({"transaction_id": format!("tx_{i}"), "code": "api_calls"}))
+            .collect();
+        json!({ "events": events }).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_create_events_batch_splits_into_chunks() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("POST", "/events/batch")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_0"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(0..100))
+            .create_async()
+            .await;
+        let second = server
+            .mock("POST", "/events/batch")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_100"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(100..150))
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let response = client
+            .create_events_batch(CreateEventsBatchRequest::new(events(150)))
+            .await
+            .unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert!(response.is_success());
+        assert_eq!(response.chunks.len(), 2);
+        assert_eq!(response.chunks[0].transaction_ids.len(), 100);
+        assert_eq!(response.chunks[1].transaction_ids.len(), 50);
+        assert_eq!(response.events().count(), 150);
+    }
+
+    #[tokio::test]
+    async fn test_create_events_batch_reports_failed_chunks() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/events/batch")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_0"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(0..2))
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/events/batch")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_2"}]}),
+            ))
+            .with_status(422)
+            .with_body(
+                r#"{"status":422,"error":"Unprocessable Entity","code":"validation_errors"}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/events/batch")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_4"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(4..5))
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let request = CreateEventsBatchRequest::new(events(5)).with_chunk_size(2);
+        let response = client.create_events_batch(request).await.unwrap();
+
+        assert!(!response.is_success());
+        assert_eq!(response.chunks.len(), 3);
+        assert!(matches!(
+            response.chunks[1].error,
+            Some(LagoError::Validation { .. })
+        ));
+        assert_eq!(response.failed_transaction_ids(), vec!["tx_2", "tx_3"]);
+        assert_eq!(response.events().count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_create_events_batch_derives_a_key_per_chunk() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("POST", "/events/batch")
+            .match_header("Idempotency-Key", "batch-42-0")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_0"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(0..2))
+            .create_async()
+            .await;
+        let second = server
+            .mock("POST", "/events/batch")
+            .match_header("Idempotency-Key", "batch-42-1")
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_2"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(2..3))
+            .create_async()
+            .await;
+        let next_call = server
+            .mock("POST", "/events/batch")
+            .match_header("Idempotency-Key", Matcher::Regex("^[0-9a-f-]{36}$".into()))
+            .match_body(Matcher::PartialJson(
+                json!({"events": [{"transaction_id": "tx_0"}]}),
+            ))
+            .with_status(200)
+            .with_body(acknowledgment(0..1))
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let request = CreateEventsBatchRequest::new(events(3))
+            .with_chunk_size(2)
+            .with_idempotency_key("batch-42".to_string());
+        let response = client.create_events_batch(request).await.unwrap();
+        client
+            .create_events_batch(CreateEventsBatchRequest::new(events(1)))
+            .await
+            .unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        next_call.assert_async().await;
+        assert!(response.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_create_events_batch_stops_on_unauthorized() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/events/batch")
+            .with_status(401)
+            .with_body(r#"{"status":401,"error":"Unauthorized"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let request = CreateEventsBatchRequest::new(events(5)).with_chunk_size(2);
+        let result = client.create_events_batch(request).await;
+
+        assert!(matches!(result, Err(LagoError::Unauthorized)));
+        mock.assert_async().await;
     }
 }
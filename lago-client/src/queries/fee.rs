@@ -6,6 +6,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// Fee-related operations for the Lago client.
 impl LagoClient {
@@ -21,6 +22,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of fees and pagination metadata, or an error.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_fees(&self, request: Option<ListFeesRequest>) -> Result<ListFeesResponse> {
         let request = request.unwrap_or_default();
         let region = self.config.region()?;
@@ -38,7 +40,30 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_fees", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all fees, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_fees`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the fees list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each fee or an error
+    pub fn list_fees_stream(
+        &self,
+        request: Option<ListFeesRequest>,
+    ) -> ListStream<ListFeesRequest, ListFeesResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_fees(Some(request)).await }),
+        )
     }
 
     /// Retrieves a specific fee by its Lago ID (UUID).
@@ -48,11 +73,16 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the fee data or an error.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.fee_id))
+    )]
     pub async fn get_fee(&self, request: GetFeeRequest) -> Result<GetFeeResponse> {
         let region = self.config.region()?;
         let url = Url::parse(&format!("{}/fees/{}", region.endpoint(), request.fee_id))
             .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_fee", "GET", url.as_str(), None::<&()>)
+            .await
     }
 }
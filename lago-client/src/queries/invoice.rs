@@ -14,6 +14,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// Invoice-related operations for the Lago client
 impl LagoClient {
@@ -24,6 +25,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of invoices or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_invoices(
         &self,
         request: Option<ListInvoicesRequest>,
@@ -44,7 +46,30 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_invoices", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all invoices, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_invoices`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the invoices list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each invoice or an error
+    pub fn list_invoices_stream(
+        &self,
+        request: Option<ListInvoicesRequest>,
+    ) -> ListStream<ListInvoicesRequest, ListInvoicesResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_invoices(Some(request)).await }),
+        )
     }
 
     /// Retrieves a specific invoice by its ID
@@ -54,10 +79,15 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the invoice data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.invoice_id))
+    )]
     pub async fn get_invoice(&self, request: GetInvoiceRequest) -> Result<GetInvoiceResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices/{}", region.endpoint(), request.invoice_id);
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_invoice", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Previews an invoice without creating it
@@ -70,13 +100,15 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the previewed invoice or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn preview_invoice(
         &self,
         request: InvoicePreviewRequest,
     ) -> Result<InvoicePreviewResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices/preview", region.endpoint());
-        self.make_request("POST", &url, Some(&request)).await
+        self.make_request("preview_invoice", "POST", &url, Some(&request))
+            .await
     }
 
     /// Creates a one-off invoice for a customer
@@ -89,13 +121,22 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created invoice or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_invoice(
         &self,
         request: CreateInvoiceRequest,
     ) -> Result<CreateInvoiceResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices", region.endpoint());
-        self.make_request("POST", &url, Some(&request)).await
+        let idempotency_key = request.idempotency_key.clone();
+        self.make_request_with_key(
+            "create_invoice",
+            "POST",
+            &url,
+            Some(&request),
+            idempotency_key,
+        )
+        .await
     }
 
     /// Updates an existing invoice
@@ -108,13 +149,18 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the updated invoice or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn update_invoice(
         &self,
         request: UpdateInvoiceRequest,
     ) -> Result<UpdateInvoiceResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices/{}", region.endpoint(), request.lago_id);
-        self.make_request("PUT", &url, Some(&request)).await
+        self.make_request("update_invoice", "PUT", &url, Some(&request))
+            .await
     }
 
     /// Retrieves a list of invoices for a specific customer
@@ -124,6 +170,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of invoices or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_customer_id))
+    )]
     pub async fn list_customer_invoices(
         &self,
         request: ListCustomerInvoicesRequest,
@@ -147,7 +197,28 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_customer_invoices", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all customer invoices, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_customer_invoices`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each customer invoice or an error
+    pub fn list_customer_invoices_stream(
+        &self,
+        request: ListCustomerInvoicesRequest,
+    ) -> ListStream<ListCustomerInvoicesRequest, ListInvoicesResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_customer_invoices(request).await })
+        })
     }
 
     /// Refreshes a draft invoice
@@ -160,13 +231,18 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the refreshed invoice or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn refresh_invoice(
         &self,
         request: RefreshInvoiceRequest,
     ) -> Result<RefreshInvoiceResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices/{}/refresh", region.endpoint(), request.lago_id);
-        self.make_request("PUT", &url, None::<&()>).await
+        self.make_request("refresh_invoice", "PUT", &url, None::<&()>)
+            .await
     }
 
     /// Downloads an invoice PDF
@@ -180,6 +256,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the invoice with file_url or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn download_invoice(
         &self,
         request: DownloadInvoiceRequest,
@@ -190,7 +270,8 @@ impl LagoClient {
             region.endpoint(),
             request.lago_id
         );
-        self.make_request("POST", &url, None::<&()>).await
+        self.make_request("download_invoice", "POST", &url, None::<&()>)
+            .await
     }
 
     /// Retries a failed invoice finalization
@@ -203,13 +284,18 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the retried invoice or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn retry_invoice(
         &self,
         request: RetryInvoiceRequest,
     ) -> Result<RetryInvoiceResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices/{}/retry", region.endpoint(), request.lago_id);
-        self.make_request("POST", &url, None::<&()>).await
+        self.make_request("retry_invoice", "POST", &url, None::<&()>)
+            .await
     }
 
     /// Retries a failed invoice payment
@@ -223,6 +309,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the invoice or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn retry_invoice_payment(
         &self,
         request: RetryInvoicePaymentRequest,
@@ -233,7 +323,8 @@ impl LagoClient {
             region.endpoint(),
             request.lago_id
         );
-        self.make_request("POST", &url, None::<&()>).await
+        self.make_request("retry_invoice_payment", "POST", &url, None::<&()>)
+            .await
     }
 
     /// Voids a finalized invoice
@@ -246,9 +337,14 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the voided invoice or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn void_invoice(&self, request: VoidInvoiceRequest) -> Result<VoidInvoiceResponse> {
         let region = self.config.region()?;
         let url = format!("{}/invoices/{}/void", region.endpoint(), request.lago_id);
-        self.make_request("POST", &url, None::<&()>).await
+        self.make_request("void_invoice", "POST", &url, None::<&()>)
+            .await
     }
 }
@@ -8,6 +8,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 /// Payment-related operations for the Lago client
 impl LagoClient {
@@ -18,6 +19,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of payments or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_payments(
         &self,
         request: Option<ListPaymentsRequest>,
@@ -38,7 +40,30 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_payments", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all payments, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_payments`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the payments list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each payment or an error
+    pub fn list_payments_stream(
+        &self,
+        request: Option<ListPaymentsRequest>,
+    ) -> ListStream<ListPaymentsRequest, ListPaymentsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_payments(Some(request)).await }),
+        )
     }
 
     /// Retrieves a specific payment by its Lago ID
@@ -48,10 +73,15 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the payment data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
     pub async fn get_payment(&self, request: GetPaymentRequest) -> Result<GetPaymentResponse> {
         let region = self.config.region()?;
         let url = format!("{}/payments/{}", region.endpoint(), request.lago_id);
-        self.make_request("GET", &url, None::<&()>).await
+        self.make_request("get_payment", "GET", &url, None::<&()>)
+            .await
     }
 
     /// Creates a manual payment for an invoice
@@ -61,13 +91,22 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created payment or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_payment(
         &self,
         request: CreatePaymentRequest,
     ) -> Result<CreatePaymentResponse> {
         let region = self.config.region()?;
         let url = format!("{}/payments", region.endpoint());
-        self.make_request("POST", &url, Some(&request)).await
+        let idempotency_key = request.idempotency_key.clone();
+        self.make_request_with_key(
+            "create_payment",
+            "POST",
+            &url,
+            Some(&request),
+            idempotency_key,
+        )
+        .await
     }
 
     /// Retrieves a list of payments for a specific customer
@@ -77,6 +116,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of payments or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_customer_id))
+    )]
     pub async fn list_customer_payments(
         &self,
         request: ListCustomerPaymentsRequest,
@@ -100,6 +143,27 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_customer_payments", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all customer payments, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_customer_payments`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each customer payment or an error
+    pub fn list_customer_payments_stream(
+        &self,
+        request: ListCustomerPaymentsRequest,
+    ) -> ListStream<ListCustomerPaymentsRequest, ListPaymentsResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_customer_payments(request).await })
+        })
     }
 }
@@ -11,6 +11,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 impl LagoClient {
     /// Lists all plans with optional filters and pagination.
@@ -20,6 +21,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of plans or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_plans(&self, request: Option<ListPlansRequest>) -> Result<ListPlansResponse> {
         let region = self.config.region()?;
         let mut url = Url::parse(&format!("{}/plans", region.endpoint()))
@@ -38,7 +40,30 @@ impl LagoClient {
             }
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_plans", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all plans, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_plans`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the plans list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each plan or an error
+    pub fn list_plans_stream(
+        &self,
+        request: Option<ListPlansRequest>,
+    ) -> ListStream<ListPlansRequest, ListPlansResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_plans(Some(request)).await }),
+        )
     }
 
     /// Retrieves a specific plan by its code.
@@ -48,12 +73,17 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the plan or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn get_plan(&self, request: GetPlanRequest) -> Result<GetPlanResponse> {
         let region = self.config.region()?;
         let url = Url::parse(&format!("{}/plans/{}", region.endpoint(), request.code))
             .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_plan", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
     /// Creates a new plan.
@@ -63,12 +93,13 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created plan or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_plan(&self, request: CreatePlanRequest) -> Result<CreatePlanResponse> {
         let region = self.config.region()?;
         let url = Url::parse(&format!("{}/plans", region.endpoint()))
             .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("POST", url.as_str(), Some(&request))
+        self.make_request("create_plan", "POST", url.as_str(), Some(&request))
             .await
     }
 
@@ -79,12 +110,17 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the updated plan or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn update_plan(&self, request: UpdatePlanRequest) -> Result<UpdatePlanResponse> {
         let region = self.config.region()?;
         let url = Url::parse(&format!("{}/plans/{}", region.endpoint(), request.code))
             .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request("update_plan", "PUT", url.as_str(), Some(&request))
+            .await
     }
 
     /// Deletes a plan.
@@ -94,11 +130,16 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the deleted plan or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
     pub async fn delete_plan(&self, request: DeletePlanRequest) -> Result<DeletePlanResponse> {
         let region = self.config.region()?;
         let url = Url::parse(&format!("{}/plans/{}", region.endpoint(), request.code))
             .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("DELETE", url.as_str(), None::<&()>).await
+        self.make_request("delete_plan", "DELETE", url.as_str(), None::<&()>)
+            .await
     }
 }
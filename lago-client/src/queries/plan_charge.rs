@@ -18,10 +18,15 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 impl LagoClient {
     // ─── Plan charges ────────────────────────────────────────────────────────
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.plan_code))
+    )]
     pub async fn list_plan_charges(
         &self,
         request: ListPlanChargesRequest,
@@ -44,9 +49,34 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_plan_charges", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all plan charges, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_plan_charges`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each plan charge or an error
+    pub fn list_plan_charges_stream(
+        &self,
+        request: ListPlanChargesRequest,
+    ) -> ListStream<ListPlanChargesRequest, ListPlanChargesResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_plan_charges(request).await })
+        })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn get_plan_charge(
         &self,
         request: GetPlanChargeRequest,
@@ -60,9 +90,14 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_plan_charge", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.plan_code))
+    )]
     pub async fn create_plan_charge(
         &self,
         request: CreatePlanChargeRequest,
@@ -75,10 +110,14 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("POST", url.as_str(), Some(&request))
+        self.make_request("create_plan_charge", "POST", url.as_str(), Some(&request))
             .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn update_plan_charge(
         &self,
         request: UpdatePlanChargeRequest,
@@ -92,9 +131,14 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request("update_plan_charge", "PUT", url.as_str(), Some(&request))
+            .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn delete_plan_charge(
         &self,
         request: DeletePlanChargeRequest,
@@ -118,11 +162,16 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("DELETE", url.as_str(), None::<&()>).await
+        self.make_request("delete_plan_charge", "DELETE", url.as_str(), None::<&()>)
+            .await
     }
 
     // ─── Plan fixed charges ──────────────────────────────────────────────────
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.plan_code))
+    )]
     pub async fn list_plan_fixed_charges(
         &self,
         request: ListPlanFixedChargesRequest,
@@ -145,9 +194,34 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_plan_fixed_charges", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all plan fixed charges, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_plan_fixed_charges`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each plan fixed charge or an error
+    pub fn list_plan_fixed_charges_stream(
+        &self,
+        request: ListPlanFixedChargesRequest,
+    ) -> ListStream<ListPlanFixedChargesRequest, ListPlanFixedChargesResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_plan_fixed_charges(request).await })
+        })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.fixed_charge_code))
+    )]
     pub async fn get_plan_fixed_charge(
         &self,
         request: GetPlanFixedChargeRequest,
@@ -161,9 +235,14 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_plan_fixed_charge", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.plan_code))
+    )]
     pub async fn create_plan_fixed_charge(
         &self,
         request: CreatePlanFixedChargeRequest,
@@ -176,10 +255,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("POST", url.as_str(), Some(&request))
-            .await
+        self.make_request(
+            "create_plan_fixed_charge",
+            "POST",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.fixed_charge_code))
+    )]
     pub async fn update_plan_fixed_charge(
         &self,
         request: UpdatePlanFixedChargeRequest,
@@ -193,9 +281,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request(
+            "update_plan_fixed_charge",
+            "PUT",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.fixed_charge_code))
+    )]
     pub async fn delete_plan_fixed_charge(
         &self,
         request: DeletePlanFixedChargeRequest,
@@ -219,11 +317,21 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("DELETE", url.as_str(), None::<&()>).await
+        self.make_request(
+            "delete_plan_fixed_charge",
+            "DELETE",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 
     // ─── Plan charge filters ─────────────────────────────────────────────────
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn list_plan_charge_filters(
         &self,
         request: ListPlanChargeFiltersRequest,
@@ -247,9 +355,34 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_plan_charge_filters", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
+    /// Streams all plan charge filters, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_plan_charge_filters`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each plan charge filter or an error
+    pub fn list_plan_charge_filters_stream(
+        &self,
+        request: ListPlanChargeFiltersRequest,
+    ) -> ListStream<ListPlanChargeFiltersRequest, ListPlanChargeFiltersResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_plan_charge_filters(request).await })
+        })
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.filter_id))
+    )]
     pub async fn get_plan_charge_filter(
         &self,
         request: GetPlanChargeFilterRequest,
@@ -264,9 +397,14 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_plan_charge_filter", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn create_plan_charge_filter(
         &self,
         request: CreatePlanChargeFilterRequest,
@@ -280,10 +418,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("POST", url.as_str(), Some(&request))
-            .await
+        self.make_request(
+            "create_plan_charge_filter",
+            "POST",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.filter_id))
+    )]
     pub async fn update_plan_charge_filter(
         &self,
         request: UpdatePlanChargeFilterRequest,
@@ -298,9 +445,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request(
+            "update_plan_charge_filter",
+            "PUT",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.filter_id))
+    )]
     pub async fn delete_plan_charge_filter(
         &self,
         request: DeletePlanChargeFilterRequest,
@@ -325,6 +482,12 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("DELETE", url.as_str(), None::<&()>).await
+        self.make_request(
+            "delete_plan_charge_filter",
+            "DELETE",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 }
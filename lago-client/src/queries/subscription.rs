@@ -12,6 +12,7 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 impl LagoClient {
     /// Lists all subscriptions with optional filters and pagination.
@@ -21,6 +22,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of subscriptions or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn list_subscriptions(
         &self,
         request: Option<ListSubscriptionsRequest>,
@@ -42,7 +44,32 @@ impl LagoClient {
             }
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("list_subscriptions", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all subscriptions, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_subscriptions`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the subscriptions list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each subscription or an error
+    pub fn list_subscriptions_stream(
+        &self,
+        request: Option<ListSubscriptionsRequest>,
+    ) -> ListStream<ListSubscriptionsRequest, ListSubscriptionsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| {
+                Box::pin(async move { client.list_subscriptions(Some(request)).await })
+            },
+        )
     }
 
     /// Retrieves a specific subscription by its external ID.
@@ -52,6 +79,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the subscription or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
     pub async fn get_subscription(
         &self,
         request: GetSubscriptionRequest,
@@ -64,7 +95,8 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_subscription", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
     /// Lists all subscriptions for a specific customer.
@@ -74,6 +106,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the list of subscriptions or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_customer_id))
+    )]
     pub async fn list_customer_subscriptions(
         &self,
         request: ListCustomerSubscriptionsRequest,
@@ -97,7 +133,33 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "list_customer_subscriptions",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
+    }
+
+    /// Streams all customer subscriptions, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_customer_subscriptions`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each customer subscription or an error
+    pub fn list_customer_subscriptions_stream(
+        &self,
+        request: ListCustomerSubscriptionsRequest,
+    ) -> ListStream<ListCustomerSubscriptionsRequest, ListSubscriptionsResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_customer_subscriptions(request).await })
+        })
     }
 
     /// Creates a new subscription.
@@ -107,6 +169,7 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the created subscription or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_subscription(
         &self,
         request: CreateSubscriptionRequest,
@@ -115,7 +178,7 @@ impl LagoClient {
         let url = Url::parse(&format!("{}/subscriptions", region.endpoint()))
             .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("POST", url.as_str(), Some(&request))
+        self.make_request("create_subscription", "POST", url.as_str(), Some(&request))
             .await
     }
 
@@ -126,6 +189,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the updated subscription or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
     pub async fn update_subscription(
         &self,
         request: UpdateSubscriptionRequest,
@@ -138,7 +205,8 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request("update_subscription", "PUT", url.as_str(), Some(&request))
+            .await
     }
 
     /// Deletes (terminates) a subscription.
@@ -148,6 +216,10 @@ impl LagoClient {
     ///
     /// # Returns
     /// A `Result` containing the terminated subscription or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
     pub async fn delete_subscription(
         &self,
         request: DeleteSubscriptionRequest,
@@ -171,6 +243,7 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("DELETE", url.as_str(), None::<&()>).await
+        self.make_request("delete_subscription", "DELETE", url.as_str(), None::<&()>)
+            .await
     }
 }
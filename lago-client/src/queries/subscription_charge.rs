@@ -20,10 +20,15 @@ use lago_types::{
 use url::Url;
 
 use crate::client::LagoClient;
+use crate::pagination::ListStream;
 
 impl LagoClient {
     // ─── Subscription charges ────────────────────────────────────────────────
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
     pub async fn list_subscription_charges(
         &self,
         request: ListSubscriptionChargesRequest,
@@ -46,9 +51,39 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "list_subscription_charges",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 
+    /// Streams all subscription charges, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_subscription_charges`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each subscription charge or an error
+    pub fn list_subscription_charges_stream(
+        &self,
+        request: ListSubscriptionChargesRequest,
+    ) -> ListStream<ListSubscriptionChargesRequest, ListSubscriptionChargesResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_subscription_charges(request).await })
+        })
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn get_subscription_charge(
         &self,
         request: GetSubscriptionChargeRequest,
@@ -62,9 +97,14 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request("get_subscription_charge", "GET", url.as_str(), None::<&()>)
+            .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn update_subscription_charge(
         &self,
         request: UpdateSubscriptionChargeRequest,
@@ -78,11 +118,21 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request(
+            "update_subscription_charge",
+            "PUT",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
     // ─── Subscription fixed charges ──────────────────────────────────────────
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.external_id))
+    )]
     pub async fn list_subscription_fixed_charges(
         &self,
         request: ListSubscriptionFixedChargesRequest,
@@ -105,9 +155,39 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "list_subscription_fixed_charges",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
+    }
+
+    /// Streams all subscription fixed charges, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_subscription_fixed_charges`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each subscription fixed charge or an error
+    pub fn list_subscription_fixed_charges_stream(
+        &self,
+        request: ListSubscriptionFixedChargesRequest,
+    ) -> ListStream<ListSubscriptionFixedChargesRequest, ListSubscriptionFixedChargesResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_subscription_fixed_charges(request).await })
+        })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.fixed_charge_code))
+    )]
     pub async fn get_subscription_fixed_charge(
         &self,
         request: GetSubscriptionFixedChargeRequest,
@@ -121,9 +201,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "get_subscription_fixed_charge",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.fixed_charge_code))
+    )]
     pub async fn update_subscription_fixed_charge(
         &self,
         request: UpdateSubscriptionFixedChargeRequest,
@@ -137,11 +227,21 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request(
+            "update_subscription_fixed_charge",
+            "PUT",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
     // ─── Subscription charge filters ─────────────────────────────────────────
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn list_subscription_charge_filters(
         &self,
         request: ListSubscriptionChargeFiltersRequest,
@@ -165,9 +265,40 @@ impl LagoClient {
             url.set_query(Some(&query_string));
         }
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "list_subscription_charge_filters",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 
+    /// Streams all subscription charge filters, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_subscription_charge_filters`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the path parameters and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each subscription charge filter or an error
+    pub fn list_subscription_charge_filters_stream(
+        &self,
+        request: ListSubscriptionChargeFiltersRequest,
+    ) -> ListStream<ListSubscriptionChargeFiltersRequest, ListSubscriptionChargeFiltersResponse>
+    {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_subscription_charge_filters(request).await })
+        })
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.filter_id))
+    )]
     pub async fn get_subscription_charge_filter(
         &self,
         request: GetSubscriptionChargeFilterRequest,
@@ -182,9 +313,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("GET", url.as_str(), None::<&()>).await
+        self.make_request(
+            "get_subscription_charge_filter",
+            "GET",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.charge_code))
+    )]
     pub async fn create_subscription_charge_filter(
         &self,
         request: CreateSubscriptionChargeFilterRequest,
@@ -198,10 +339,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("POST", url.as_str(), Some(&request))
-            .await
+        self.make_request(
+            "create_subscription_charge_filter",
+            "POST",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.filter_id))
+    )]
     pub async fn update_subscription_charge_filter(
         &self,
         request: UpdateSubscriptionChargeFilterRequest,
@@ -216,9 +366,19 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("PUT", url.as_str(), Some(&request)).await
+        self.make_request(
+            "update_subscription_charge_filter",
+            "PUT",
+            url.as_str(),
+            Some(&request),
+        )
+        .await
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.filter_id))
+    )]
     pub async fn delete_subscription_charge_filter(
         &self,
         request: DeleteSubscriptionChargeFilterRequest,
@@ -233,6 +393,12 @@ impl LagoClient {
         ))
         .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
 
-        self.make_request("DELETE", url.as_str(), None::<&()>).await
+        self.make_request(
+            "delete_subscription_charge_filter",
+            "DELETE",
+            url.as_str(),
+            None::<&()>,
+        )
+        .await
     }
 }
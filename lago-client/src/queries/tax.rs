@@ -0,0 +1,147 @@
+use lago_types::{
+    error::{LagoError, Result},
+    requests::tax::{
+        CreateTaxRequest, DeleteTaxRequest, GetTaxRequest, ListTaxesRequest, UpdateTaxRequest,
+    },
+    responses::tax::{
+        CreateTaxResponse, DeleteTaxResponse, GetTaxResponse, ListTaxesResponse, UpdateTaxResponse,
+    },
+};
+use serde::Serialize;
+use url::Url;
+
+use crate::client::LagoClient;
+use crate::pagination::ListStream;
+
+/// Internal struct for serializing update request body (without the code field)
+#[derive(Serialize)]
+struct UpdateTaxBody {
+    tax: lago_types::requests::tax::UpdateTaxInput,
+}
+
+impl LagoClient {
+    /// Retrieves a list of taxes with optional filtering parameters
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the tax list
+    ///
+    /// # Returns
+    /// A `Result` containing the list of taxes or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn list_taxes(&self, request: Option<ListTaxesRequest>) -> Result<ListTaxesResponse> {
+        let request = request.unwrap_or_default();
+        let region = self.config.region()?;
+        let mut url = Url::parse(&format!("{}/taxes", region.endpoint()))
+            .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
+
+        let query_params = request.to_query_params();
+
+        if !query_params.is_empty() {
+            let query_string = query_params
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            url.set_query(Some(&query_string));
+        }
+
+        self.make_request("list_taxes", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all taxes, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_taxes`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the taxes list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each tax or an error
+    pub fn list_taxes_stream(
+        &self,
+        request: Option<ListTaxesRequest>,
+    ) -> ListStream<ListTaxesRequest, ListTaxesResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| Box::pin(async move { client.list_taxes(Some(request)).await }),
+        )
+    }
+
+    /// Retrieves a tax by its code
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the tax code to retrieve
+    ///
+    /// # Returns
+    /// A `Result` containing the tax data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
+    pub async fn get_tax(&self, request: GetTaxRequest) -> Result<GetTaxResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/taxes/{}", region.endpoint(), request.code);
+
+        self.make_request("get_tax", "GET", &url, None::<&()>).await
+    }
+
+    /// Creates a new tax
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the tax data to create
+    ///
+    /// # Returns
+    /// A `Result` containing the created tax data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_tax(&self, request: CreateTaxRequest) -> Result<CreateTaxResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/taxes", region.endpoint());
+
+        self.make_request("create_tax", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Updates an existing tax
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the tax code and update data
+    ///
+    /// # Returns
+    /// A `Result` containing the updated tax data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
+    pub async fn update_tax(&self, request: UpdateTaxRequest) -> Result<UpdateTaxResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/taxes/{}", region.endpoint(), request.code);
+
+        let body = UpdateTaxBody { tax: request.tax };
+
+        self.make_request("update_tax", "PUT", &url, Some(&body))
+            .await
+    }
+
+    /// Deletes a tax by its code
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the tax code to delete
+    ///
+    /// # Returns
+    /// A `Result` containing the deleted tax data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.code))
+    )]
+    pub async fn delete_tax(&self, request: DeleteTaxRequest) -> Result<DeleteTaxResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/taxes/{}", region.endpoint(), request.code);
+
+        self.make_request("delete_tax", "DELETE", &url, None::<&()>)
+            .await
+    }
+}
@@ -0,0 +1,293 @@
+use lago_types::{
+    error::{LagoError, Result},
+    requests::wallet::{
+        CreateWalletRequest, CreateWalletTransactionRequest, GetWalletRequest,
+        ListWalletTransactionsRequest, ListWalletsRequest, TerminateWalletRequest,
+        UpdateWalletRequest,
+    },
+    responses::wallet::{
+        CreateWalletResponse, CreateWalletTransactionResponse, GetWalletResponse,
+        ListWalletTransactionsResponse, ListWalletsResponse, TerminateWalletResponse,
+        UpdateWalletResponse,
+    },
+};
+use serde::Serialize;
+use url::Url;
+
+use crate::client::LagoClient;
+use crate::pagination::ListStream;
+
+/// Internal struct for serializing update request body (without the lago_id field)
+#[derive(Serialize)]
+struct UpdateWalletBody {
+    wallet: lago_types::requests::wallet::UpdateWalletInput,
+}
+
+/// Wallet-related operations for the Lago client
+impl LagoClient {
+    /// Retrieves the wallets of a customer
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID and pagination
+    ///
+    /// # Returns
+    /// A `Result` containing the list of wallets or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn list_wallets(&self, request: ListWalletsRequest) -> Result<ListWalletsResponse> {
+        let region = self.config.region()?;
+        let mut url = Url::parse(&format!("{}/wallets", region.endpoint()))
+            .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
+
+        let query_params = request.to_query_params();
+
+        if !query_params.is_empty() {
+            let query_string = query_params
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            url.set_query(Some(&query_string));
+        }
+
+        self.make_request("list_wallets", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all wallets, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_wallets`], so the configured retry
+    /// policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the customer external ID and pagination
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each wallet or an error
+    pub fn list_wallets_stream(
+        &self,
+        request: ListWalletsRequest,
+    ) -> ListStream<ListWalletsRequest, ListWalletsResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_wallets(request).await })
+        })
+    }
+
+    /// Retrieves a wallet by its Lago ID
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet Lago ID to retrieve
+    ///
+    /// # Returns
+    /// A `Result` containing the wallet data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn get_wallet(&self, request: GetWalletRequest) -> Result<GetWalletResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/wallets/{}", region.endpoint(), request.lago_id);
+
+        self.make_request("get_wallet", "GET", &url, None::<&()>)
+            .await
+    }
+
+    /// Creates a new wallet
+    ///
+    /// Paid and granted credits set on the input are added to the wallet
+    /// through an initial set of wallet transactions.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet data to create
+    ///
+    /// # Returns
+    /// A `Result` containing the created wallet data or an error
+    ///
+    /// # Example
+    /// ```no_run
+    /// use lago_client::LagoClient;
+    /// use lago_types::models::RecurringTransactionInterval;
+    /// use lago_types::requests::wallet::{
+    ///     CreateWalletInput, CreateWalletRequest, RecurringTransactionRuleInput,
+    /// };
+    ///
+    /// # async fn example() -> lago_types::error::Result<()> {
+    /// let client = LagoClient::from_env()?;
+    /// let input = CreateWalletInput::new(
+    ///     "customer_123".to_string(),
+    ///     "1.0".to_string(),
+    ///     "USD".to_string(),
+    /// )
+    /// .with_name("Prepaid credits".to_string())
+    /// .with_paid_credits("100".to_string())
+    /// .with_recurring_transaction_rules(vec![
+    ///     RecurringTransactionRuleInput::interval(RecurringTransactionInterval::Monthly)
+    ///         .with_fixed_credits("50".to_string(), "0".to_string()),
+    /// ]);
+    ///
+    /// let response = client.create_wallet(CreateWalletRequest::new(input)).await?;
+    /// println!("Created wallet: {}", response.wallet.lago_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_wallet(
+        &self,
+        request: CreateWalletRequest,
+    ) -> Result<CreateWalletResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/wallets", region.endpoint());
+
+        self.make_request("create_wallet", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Updates an existing wallet
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet Lago ID and update data
+    ///
+    /// # Returns
+    /// A `Result` containing the updated wallet data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn update_wallet(
+        &self,
+        request: UpdateWalletRequest,
+    ) -> Result<UpdateWalletResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/wallets/{}", region.endpoint(), request.lago_id);
+
+        let body = UpdateWalletBody {
+            wallet: request.wallet,
+        };
+
+        self.make_request("update_wallet", "PUT", &url, Some(&body))
+            .await
+    }
+
+    /// Terminates a wallet
+    ///
+    /// Remaining credits are lost once the wallet is terminated.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet Lago ID to terminate
+    ///
+    /// # Returns
+    /// A `Result` containing the terminated wallet data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn terminate_wallet(
+        &self,
+        request: TerminateWalletRequest,
+    ) -> Result<TerminateWalletResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/wallets/{}", region.endpoint(), request.lago_id);
+
+        self.make_request("terminate_wallet", "DELETE", &url, None::<&()>)
+            .await
+    }
+
+    /// Creates wallet transactions to top up, grant or void credits
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet ID and credit amounts
+    ///
+    /// # Returns
+    /// A `Result` containing the created wallet transactions or an error
+    ///
+    /// # Example
+    /// ```no_run
+    /// use lago_client::LagoClient;
+    /// use lago_types::requests::wallet::{
+    ///     CreateWalletTransactionInput, CreateWalletTransactionRequest,
+    /// };
+    ///
+    /// # async fn example() -> lago_types::error::Result<()> {
+    /// let client = LagoClient::from_env()?;
+    /// let input = CreateWalletTransactionInput::top_up(
+    ///     "1a901a90-1a90-1a90-1a90-1a901a901a90".to_string(),
+    ///     "20".to_string(),
+    /// )
+    /// .with_granted_credits("5".to_string());
+    ///
+    /// let request = CreateWalletTransactionRequest::new(input);
+    /// let response = client.create_wallet_transaction(request).await?;
+    /// println!("Created {} transactions", response.wallet_transactions.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_wallet_transaction(
+        &self,
+        request: CreateWalletTransactionRequest,
+    ) -> Result<CreateWalletTransactionResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/wallet_transactions", region.endpoint());
+
+        self.make_request("create_wallet_transaction", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Retrieves the transactions of a wallet
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet Lago ID and optional filters
+    ///
+    /// # Returns
+    /// A `Result` containing the list of wallet transactions or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.wallet_id))
+    )]
+    pub async fn list_wallet_transactions(
+        &self,
+        request: ListWalletTransactionsRequest,
+    ) -> Result<ListWalletTransactionsResponse> {
+        let region = self.config.region()?;
+        let mut url = Url::parse(&format!(
+            "{}/wallets/{}/wallet_transactions",
+            region.endpoint(),
+            request.wallet_id
+        ))
+        .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
+
+        let query_params = request.to_query_params();
+
+        if !query_params.is_empty() {
+            let query_string = query_params
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            url.set_query(Some(&query_string));
+        }
+
+        self.make_request("list_wallet_transactions", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all wallet transactions, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_wallet_transactions`], so
+    /// the configured retry policy applies to every page. See [`ListStream`] to
+    /// cap the number of items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the wallet Lago ID and optional filters
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each wallet transaction or an error
+    pub fn list_wallet_transactions_stream(
+        &self,
+        request: ListWalletTransactionsRequest,
+    ) -> ListStream<ListWalletTransactionsRequest, ListWalletTransactionsResponse> {
+        ListStream::new(self.clone(), request, |client, request| {
+            Box::pin(async move { client.list_wallet_transactions(request).await })
+        })
+    }
+}
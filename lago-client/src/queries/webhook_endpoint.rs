@@ -0,0 +1,220 @@
+use lago_types::{
+    error::{LagoError, Result},
+    requests::webhook_endpoint::{
+        CreateWebhookEndpointRequest, DeleteWebhookEndpointRequest, GetWebhookEndpointRequest,
+        GetWebhookPublicKeyRequest, ListWebhookEndpointsRequest, UpdateWebhookEndpointRequest,
+    },
+    responses::webhook_endpoint::{
+        CreateWebhookEndpointResponse, DeleteWebhookEndpointResponse, GetWebhookEndpointResponse,
+        GetWebhookPublicKeyResponse, ListWebhookEndpointsResponse, UpdateWebhookEndpointResponse,
+    },
+};
+use serde::Serialize;
+use url::Url;
+
+use crate::client::LagoClient;
+use crate::pagination::ListStream;
+use crate::webhooks::WebhookVerifier;
+
+/// Internal struct for serializing update request body (without the lago_id field)
+#[derive(Serialize)]
+struct UpdateWebhookEndpointBody {
+    webhook_endpoint: lago_types::requests::webhook_endpoint::UpdateWebhookEndpointInput,
+}
+
+impl LagoClient {
+    /// Retrieves a list of webhook endpoints with optional filtering parameters
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the webhook endpoint list
+    ///
+    /// # Returns
+    /// A `Result` containing the list of webhook endpoints or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn list_webhook_endpoints(
+        &self,
+        request: Option<ListWebhookEndpointsRequest>,
+    ) -> Result<ListWebhookEndpointsResponse> {
+        let request = request.unwrap_or_default();
+        let region = self.config.region()?;
+        let mut url = Url::parse(&format!("{}/webhook_endpoints", region.endpoint()))
+            .map_err(|e| LagoError::Configuration(format!("Invalid URL: {e}")))?;
+
+        let query_params = request.to_query_params();
+
+        if !query_params.is_empty() {
+            let query_string = query_params
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("&");
+            url.set_query(Some(&query_string));
+        }
+
+        self.make_request("list_webhook_endpoints", "GET", url.as_str(), None::<&()>)
+            .await
+    }
+
+    /// Streams all webhook endpoints, following pagination automatically
+    ///
+    /// Each page is fetched through [`LagoClient::list_webhook_endpoints`], so the configured
+    /// retry policy applies to every page. See [`ListStream`] to cap the number of
+    /// items or pages.
+    ///
+    /// # Arguments
+    /// * `request` - Optional filtering parameters for the webhook endpoints list
+    ///
+    /// # Returns
+    /// A `ListStream` yielding each webhook endpoint or an error
+    pub fn list_webhook_endpoints_stream(
+        &self,
+        request: Option<ListWebhookEndpointsRequest>,
+    ) -> ListStream<ListWebhookEndpointsRequest, ListWebhookEndpointsResponse> {
+        ListStream::new(
+            self.clone(),
+            request.unwrap_or_default(),
+            |client, request| {
+                Box::pin(async move { client.list_webhook_endpoints(Some(request)).await })
+            },
+        )
+    }
+
+    /// Retrieves a webhook endpoint by its Lago ID
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the Lago ID of the webhook endpoint
+    ///
+    /// # Returns
+    /// A `Result` containing the webhook endpoint data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn get_webhook_endpoint(
+        &self,
+        request: GetWebhookEndpointRequest,
+    ) -> Result<GetWebhookEndpointResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/webhook_endpoints/{}",
+            region.endpoint(),
+            request.lago_id
+        );
+
+        self.make_request("get_webhook_endpoint", "GET", &url, None::<&()>)
+            .await
+    }
+
+    /// Registers a new webhook endpoint
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the webhook URL and signature algorithm
+    ///
+    /// # Returns
+    /// A `Result` containing the created webhook endpoint data or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_webhook_endpoint(
+        &self,
+        request: CreateWebhookEndpointRequest,
+    ) -> Result<CreateWebhookEndpointResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/webhook_endpoints", region.endpoint());
+
+        self.make_request("create_webhook_endpoint", "POST", &url, Some(&request))
+            .await
+    }
+
+    /// Updates an existing webhook endpoint
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the Lago ID of the webhook endpoint and update data
+    ///
+    /// # Returns
+    /// A `Result` containing the updated webhook endpoint data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn update_webhook_endpoint(
+        &self,
+        request: UpdateWebhookEndpointRequest,
+    ) -> Result<UpdateWebhookEndpointResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/webhook_endpoints/{}",
+            region.endpoint(),
+            request.lago_id
+        );
+
+        let body = UpdateWebhookEndpointBody {
+            webhook_endpoint: request.webhook_endpoint,
+        };
+
+        self.make_request("update_webhook_endpoint", "PUT", &url, Some(&body))
+            .await
+    }
+
+    /// Deletes a webhook endpoint by its Lago ID
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the Lago ID of the webhook endpoint to delete
+    ///
+    /// # Returns
+    /// A `Result` containing the deleted webhook endpoint data or an error
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, fields(lago.resource_id = %request.lago_id))
+    )]
+    pub async fn delete_webhook_endpoint(
+        &self,
+        request: DeleteWebhookEndpointRequest,
+    ) -> Result<DeleteWebhookEndpointResponse> {
+        let region = self.config.region()?;
+        let url = format!(
+            "{}/webhook_endpoints/{}",
+            region.endpoint(),
+            request.lago_id
+        );
+
+        self.make_request("delete_webhook_endpoint", "DELETE", &url, None::<&()>)
+            .await
+    }
+
+    /// Retrieves the public key Lago signs JWT webhooks with
+    ///
+    /// The key is returned as the base64-encoded PEM accepted by
+    /// [`WebhookVerifier::jwt`].
+    ///
+    /// # Arguments
+    /// * `request` - The get webhook public key request
+    ///
+    /// # Returns
+    /// A `Result` containing the public key or an error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_webhook_public_key(
+        &self,
+        _request: GetWebhookPublicKeyRequest,
+    ) -> Result<GetWebhookPublicKeyResponse> {
+        let region = self.config.region()?;
+        let url = format!("{}/webhooks/json_public_key", region.endpoint());
+
+        self.make_request("get_webhook_public_key", "GET", &url, None::<&()>)
+            .await
+    }
+
+    /// Builds a [`WebhookVerifier`] for JWT signed webhooks from the public
+    /// key of the organization
+    ///
+    /// The key rarely changes, so fetch it once at startup and reuse the
+    /// verifier for every delivery.
+    ///
+    /// # Returns
+    /// A `Result` containing the verifier or an error if the key can't be fetched or parsed
+    pub async fn webhook_verifier(&self) -> Result<WebhookVerifier> {
+        let response = self
+            .get_webhook_public_key(GetWebhookPublicKeyRequest::new())
+            .await?;
+
+        WebhookVerifier::jwt(&response.webhook.public_key)
+    }
+}
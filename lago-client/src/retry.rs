@@ -1,12 +1,28 @@
-use std::time::Duration;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use lago_types::error::LagoError;
+
+use crate::client::RateLimitInfo;
 
 /// Defines the retry behavior for failed requests
 ///
 /// This enum controls how the client handles retry attempts when requests fail.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RetryMode {
+    /// Requests are never retried.
     Off,
+    /// Failed requests are retried with exponential backoff.
     Standard,
+    /// Like `Standard`, and requests are also paced client-side to stay within
+    /// the API rate limit.
+    ///
+    /// The client keeps a token bucket fed by the `x-ratelimit-*` response
+    /// headers and shared by all its clones. Once the requests left in the
+    /// current window are used up, new requests wait for the window to reset
+    /// (at most `max_delay`) instead of being rejected with a 429.
     Adaptive,
 }
 
@@ -20,10 +36,129 @@ impl Default for RetryMode {
     }
 }
 
+/// Random jitter applied to backoff delays
+///
+/// Jitter spreads the retries of concurrent clients over time, so they don't
+/// hit the API again all at once after an outage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Jitter {
+    /// The exponential backoff delay is used as is.
+    #[default]
+    None,
+    /// A random delay between zero and the backoff delay.
+    Full,
+    /// Half the backoff delay plus a random delay up to the other half.
+    Equal,
+    /// A random delay between the initial delay and three times the previous
+    /// delay, capped at the maximum delay.
+    Decorrelated,
+}
+
+/// A failed attempt, as seen by a [`RetryPolicy`].
+#[derive(Debug)]
+pub struct RetryContext<'a> {
+    /// The error of the attempt.
+    pub error: &'a LagoError,
+    /// HTTP method of the request.
+    pub method: &'a str,
+    /// Number of attempts made so far, including the failed one.
+    pub attempt: u32,
+    /// Response headers, or `None` when no response was received.
+    pub headers: Option<&'a HeaderMap>,
+    /// Time elapsed since the first attempt.
+    pub elapsed: Duration,
+    /// Delay waited before the failed attempt, if it was a retry.
+    pub previous_delay: Option<Duration>,
+}
+
+impl RetryContext<'_> {
+    /// Whether the error is transient: a request that got no response, a
+    /// rate limit (429) or a server error (5xx).
+    pub fn is_retryable(&self) -> bool {
+        match self.error {
+            LagoError::Http(_) | LagoError::Transport(_) => true,
+            LagoError::RateLimit => true,
+            LagoError::ServerError { .. } => true,
+            LagoError::Api { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// Returns the delay requested by the `Retry-After` response header
+    ///
+    /// Both forms of the header are supported: a number of seconds and an
+    /// HTTP date.
+    pub fn retry_after(&self) -> Option<Duration> {
+        let value = self.headers?.get(RETRY_AFTER)?.to_str().ok()?.trim();
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let date = httpdate::parse_http_date(value).ok()?;
+        Some(
+            date.duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+
+    /// Returns the time until the rate limit window resets, from the
+    /// `x-ratelimit-reset` response header.
+    pub fn rate_limit_reset(&self) -> Option<Duration> {
+        let value = self.headers?.get("x-ratelimit-reset")?.to_str().ok()?;
+        value.trim().parse::<u64>().ok().map(Duration::from_secs)
+    }
+}
+
+/// Decides whether and when a failed request is retried
+///
+/// [`RetryConfig`] is the built-in policy. Implement this trait and set it
+/// with [`crate::ConfigBuilder::retry_policy`] for custom rules, e.g. to
+/// retry specific errors only or to share a retry budget across clients.
+///
+/// Unsafe requests (POST) without an idempotency key are never retried on
+/// errors other than rate limits, whatever the policy says.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use lago_client::{RetryContext, RetryPolicy};
+/// use lago_types::error::LagoError;
+///
+/// /// Retries rate limited requests only, when the server says so.
+/// struct RateLimitOnly;
+///
+/// impl RetryPolicy for RateLimitOnly {
+///     fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+///         match context.error {
+///             LagoError::RateLimit if context.attempt < 5 => context.retry_after(),
+///             _ => None,
+///         }
+///     }
+/// }
+/// ```
+pub trait RetryPolicy: Send + Sync {
+    /// Returns the delay before retrying a failed attempt
+    ///
+    /// # Arguments
+    /// * `context` - The failed attempt
+    ///
+    /// # Returns
+    /// The delay to wait before the next attempt, or `None` to give up and
+    /// return the error
+    fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration>;
+}
+
 /// Configuration settings for retry behavior
 ///
 /// This struct contains all the parameters needed to configure how the client
 /// handles retry attempts, including timing, limits, and backoff strategies.
+///
+/// It is also the default [`RetryPolicy`]: transient errors are retried up to
+/// `max_attempts` times with exponential backoff and optional [`Jitter`]. A
+/// delay requested by the server (`Retry-After`, or `x-ratelimit-reset` on a
+/// 429) takes precedence over the backoff. Delays never exceed `max_delay`,
+/// and no retry starts past the retry budget.
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
     pub(crate) mode: RetryMode,
@@ -31,6 +166,10 @@ pub struct RetryConfig {
     pub(crate) initial_delay: Duration,
     pub(crate) max_delay: Duration,
     pub(crate) backoff_multiplier: f64,
+    pub(crate) retry_without_idempotency_key: bool,
+    pub(crate) jitter: Jitter,
+    pub(crate) honor_retry_after: bool,
+    pub(crate) budget: Option<Duration>,
 }
 
 impl RetryConfig {
@@ -82,6 +221,38 @@ impl RetryConfig {
         &self.max_delay
     }
 
+    /// Whether unsafe requests (POST) sent without an idempotency key may be retried
+    ///
+    /// # Returns
+    /// `true` if such requests are retried like any other request
+    pub fn retry_without_idempotency_key(&self) -> bool {
+        self.retry_without_idempotency_key
+    }
+
+    /// Gets the jitter applied to backoff delays
+    ///
+    /// # Returns
+    /// The jitter strategy
+    pub fn jitter(&self) -> Jitter {
+        self.jitter
+    }
+
+    /// Whether the `Retry-After` response header is used as the retry delay
+    ///
+    /// # Returns
+    /// `true` if the delay requested by the server takes precedence over the backoff
+    pub fn honor_retry_after(&self) -> bool {
+        self.honor_retry_after
+    }
+
+    /// Gets the overall time budget for retries
+    ///
+    /// # Returns
+    /// The maximum time from the first attempt after which no retry starts, if any
+    pub fn budget(&self) -> Option<Duration> {
+        self.budget
+    }
+
     /// Calculates the delay duration for a specific retry attempt
     ///
     /// This method implements exponential backoff with a configurable multiplier
@@ -100,7 +271,7 @@ impl RetryConfig {
         let delay_secs =
             self.initial_delay.as_secs_f64() * self.backoff_multiplier.powi(attempt as i32);
 
-        let delay = Duration::from_secs_f64(delay_secs);
+        let delay = Duration::try_from_secs_f64(delay_secs).unwrap_or(self.max_delay);
 
         if delay > self.max_delay {
             self.max_delay
@@ -108,6 +279,55 @@ impl RetryConfig {
             delay
         }
     }
+
+    /// Applies the configured jitter to the backoff delay of a retry.
+    fn jittered_delay(&self, attempt: u32, previous_delay: Option<Duration>) -> Duration {
+        let backoff = self.delay_for_attempt(attempt);
+        let random_between = |min: Duration, max: Duration| {
+            if max <= min {
+                return min;
+            }
+            min + (max - min).mul_f64(fastrand::f64())
+        };
+
+        match self.jitter {
+            Jitter::None => backoff,
+            Jitter::Full => random_between(Duration::ZERO, backoff),
+            Jitter::Equal => backoff / 2 + random_between(Duration::ZERO, backoff / 2),
+            Jitter::Decorrelated => {
+                let previous = previous_delay.unwrap_or(self.initial_delay);
+                random_between(self.initial_delay, previous.saturating_mul(3)).min(self.max_delay)
+            }
+        }
+    }
+}
+
+impl RetryPolicy for RetryConfig {
+    fn retry_delay(&self, context: &RetryContext<'_>) -> Option<Duration> {
+        if self.mode == RetryMode::Off
+            || context.attempt > self.max_attempts
+            || !context.is_retryable()
+        {
+            return None;
+        }
+
+        let retry_after = self
+            .honor_retry_after
+            .then(|| context.retry_after())
+            .flatten();
+        let server_delay = match context.error {
+            LagoError::RateLimit => retry_after.or_else(|| context.rate_limit_reset()),
+            _ => retry_after,
+        };
+        let delay = server_delay
+            .unwrap_or_else(|| self.jittered_delay(context.attempt, context.previous_delay))
+            .min(self.max_delay);
+
+        match self.budget {
+            Some(budget) if context.elapsed + delay > budget => None,
+            _ => Some(delay),
+        }
+    }
 }
 
 impl Default for RetryConfig {
@@ -122,6 +342,10 @@ impl Default for RetryConfig {
             initial_delay: Duration::from_millis(100),
             max_delay: Duration::from_secs(30),
             backoff_multiplier: 2.0,
+            retry_without_idempotency_key: false,
+            jitter: Jitter::None,
+            honor_retry_after: true,
+            budget: None,
         }
     }
 }
@@ -137,6 +361,10 @@ pub struct RetryConfigBuilder {
     initial_delay: Duration,
     max_delay: Duration,
     backoff_multiplier: f64,
+    retry_without_idempotency_key: bool,
+    jitter: Jitter,
+    honor_retry_after: bool,
+    budget: Option<Duration>,
 }
 
 impl RetryConfigBuilder {
@@ -151,6 +379,10 @@ impl RetryConfigBuilder {
             initial_delay: Duration::from_millis(100),
             max_delay: Duration::from_secs(30),
             backoff_multiplier: 2.0,
+            retry_without_idempotency_key: false,
+            jitter: Jitter::None,
+            honor_retry_after: true,
+            budget: None,
         }
     }
 
@@ -214,6 +446,63 @@ impl RetryConfigBuilder {
         self
     }
 
+    /// Allows retrying unsafe requests (POST) that carry no idempotency key
+    ///
+    /// By default such requests are not retried, since the server may already
+    /// have applied the first attempt. Keys are attached automatically unless
+    /// disabled with [`crate::ConfigBuilder::idempotency_keys`].
+    ///
+    /// # Arguments
+    /// * `allow` - Whether to retry unsafe requests without an idempotency key
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn retry_without_idempotency_key(mut self, allow: bool) -> Self {
+        self.retry_without_idempotency_key = allow;
+        self
+    }
+
+    /// Sets the jitter applied to backoff delays
+    ///
+    /// # Arguments
+    /// * `jitter` - The jitter strategy
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether the `Retry-After` response header is used as the retry delay
+    ///
+    /// Enabled by default. The delay is still capped at the maximum delay.
+    ///
+    /// # Arguments
+    /// * `honor` - Whether to wait for the delay requested by the server
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn honor_retry_after(mut self, honor: bool) -> Self {
+        self.honor_retry_after = honor;
+        self
+    }
+
+    /// Sets the overall time budget for retries
+    ///
+    /// No retry starts if it would begin more than `budget` after the first
+    /// attempt of the call, so a call gives up in bounded time.
+    ///
+    /// # Arguments
+    /// * `budget` - The maximum time spent retrying a call
+    ///
+    /// # Returns
+    /// The builder instance for method chaining
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
     /// Builds the final retry configuration instance
     ///
     /// # Returns
@@ -225,6 +514,10 @@ impl RetryConfigBuilder {
             initial_delay: self.initial_delay,
             max_delay: self.max_delay,
             backoff_multiplier: self.backoff_multiplier,
+            retry_without_idempotency_key: self.retry_without_idempotency_key,
+            jitter: self.jitter,
+            honor_retry_after: self.honor_retry_after,
+            budget: self.budget,
         }
     }
 }
@@ -237,3 +530,366 @@ impl Default for RetryConfigBuilder {
         Self::new()
     }
 }
+
+/// Client-side token bucket used by [`RetryMode::Adaptive`].
+///
+/// The bucket holds the requests left in the current rate limit window, as
+/// reported by the last response, minus the requests sent since then that the
+/// server hasn't accounted for yet.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug, Default)]
+struct BucketState {
+    limit: Option<u32>,
+    tokens: Option<u32>,
+    reset_at: Option<Instant>,
+    in_flight: u32,
+}
+
+/// A request slot taken from a [`RateLimiter`], released when dropped.
+pub(crate) struct RateLimitPermit {
+    limiter: Arc<RateLimiter>,
+}
+
+impl Drop for RateLimitPermit {
+    fn drop(&mut self) {
+        let mut state = self.limiter.lock();
+        state.in_flight = state.in_flight.saturating_sub(1);
+    }
+}
+
+impl RateLimiter {
+    /// Waits for a token, for at most `max_wait`
+    ///
+    /// When the wait times out the request is let through anyway, so a stale
+    /// bucket cannot block the client forever.
+    pub(crate) async fn acquire(self: &Arc<Self>, max_wait: Duration) -> RateLimitPermit {
+        let deadline = Instant::now() + max_wait;
+        loop {
+            let now = Instant::now();
+            match self.try_reserve(now) {
+                Some(wait) if now < deadline => {
+                    tokio::time::sleep(wait.min(deadline - now)).await;
+                }
+                Some(_) => {
+                    self.lock().in_flight += 1;
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        RateLimitPermit {
+            limiter: self.clone(),
+        }
+    }
+
+    /// Takes a token, or returns how long to wait for the window to reset.
+    fn try_reserve(&self, now: Instant) -> Option<Duration> {
+        let mut state = self.lock();
+        if let Some(reset_at) = state.reset_at
+            && now >= reset_at
+        {
+            state.tokens = state.limit;
+            state.reset_at = None;
+        }
+
+        match (state.tokens, state.reset_at) {
+            (Some(0), Some(reset_at)) => Some(reset_at - now),
+            (tokens, _) => {
+                state.tokens = tokens.map(|t| t.saturating_sub(1));
+                state.in_flight += 1;
+                None
+            }
+        }
+    }
+
+    /// Refills the bucket from the rate limit headers of a response
+    ///
+    /// Must be called while the permit of the request is held, so the request
+    /// itself isn't counted as in flight.
+    pub(crate) fn update(&self, info: &RateLimitInfo, now: Instant) {
+        let mut state = self.lock();
+        if info.limit.is_some() {
+            state.limit = info.limit;
+        }
+        if let Some(reset) = info.reset {
+            state.reset_at = Some(now + Duration::from_secs(reset));
+        }
+        if let Some(remaining) = info.remaining {
+            let unaccounted = state.in_flight.saturating_sub(1);
+            state.tokens = Some(remaining.saturating_sub(unaccounted));
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BucketState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn retry_config() -> RetryConfig {
+        RetryConfig::builder().max_attempts(3).build()
+    }
+
+    fn context<'a>(
+        error: &'a LagoError,
+        attempt: u32,
+        headers: Option<&'a HeaderMap>,
+    ) -> RetryContext<'a> {
+        RetryContext {
+            error,
+            method: "GET",
+            attempt,
+            headers,
+            elapsed: Duration::ZERO,
+            previous_delay: None,
+        }
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    fn server_error() -> LagoError {
+        LagoError::ServerError {
+            status: 500,
+            message: "Server Error".to_string(),
+        }
+    }
+
+    #[test]
+    fn retries_transient_errors_only() {
+        let config = retry_config();
+
+        assert!(
+            config
+                .retry_delay(&context(&LagoError::RateLimit, 1, None))
+                .is_some()
+        );
+        assert!(
+            config
+                .retry_delay(&context(&server_error(), 1, None))
+                .is_some()
+        );
+        let transport_error = LagoError::Transport("reset".to_string());
+        assert!(
+            config
+                .retry_delay(&context(&transport_error, 1, None))
+                .is_some()
+        );
+
+        let client_error = LagoError::Api {
+            status: 400,
+            code: None,
+            message: "Bad Request".to_string(),
+        };
+        assert!(
+            config
+                .retry_delay(&context(&client_error, 1, None))
+                .is_none()
+        );
+        assert!(
+            config
+                .retry_delay(&context(&LagoError::Unauthorized, 1, None))
+                .is_none()
+        );
+
+        assert!(
+            config
+                .retry_delay(&context(&server_error(), 3, None))
+                .is_some()
+        );
+        assert!(
+            config
+                .retry_delay(&context(&server_error(), 4, None))
+                .is_none()
+        );
+
+        let off = RetryConfig::default();
+        assert!(
+            off.retry_delay(&context(&server_error(), 1, None))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn uses_exponential_backoff() {
+        let config = retry_config();
+
+        // With initial_delay of 100ms and multiplier of 2.0
+        let delay = config.retry_delay(&context(&server_error(), 2, None));
+        assert_eq!(delay, Some(Duration::from_millis(400)));
+
+        let headers = headers(&[("x-ratelimit-remaining", "0")]);
+        let delay = config.retry_delay(&context(&LagoError::RateLimit, 1, Some(&headers)));
+        assert_eq!(delay, Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn uses_rate_limit_reset_for_rate_limits() {
+        let config = retry_config();
+        let headers = headers(&[("x-ratelimit-reset", "10")]);
+
+        let delay = config.retry_delay(&context(&LagoError::RateLimit, 1, Some(&headers)));
+        assert_eq!(delay, Some(Duration::from_secs(10)));
+
+        let delay = config.retry_delay(&context(&server_error(), 1, Some(&headers)));
+        assert_eq!(delay, Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let config = retry_config();
+        let seconds = headers(&[("retry-after", "7"), ("x-ratelimit-reset", "10")]);
+        let delay = config.retry_delay(&context(&LagoError::RateLimit, 1, Some(&seconds)));
+        assert_eq!(delay, Some(Duration::from_secs(7)));
+
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(5));
+        let date = headers(&[("retry-after", &date)]);
+        let delay = config
+            .retry_delay(&context(&server_error(), 1, Some(&date)))
+            .unwrap();
+        assert!(delay > Duration::from_secs(3) && delay <= Duration::from_secs(5));
+
+        let ignored = RetryConfig::builder().honor_retry_after(false).build();
+        let delay = ignored.retry_delay(&context(&server_error(), 1, Some(&seconds)));
+        assert_eq!(delay, Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn caps_delays_at_max_delay() {
+        let config = RetryConfig::builder()
+            .max_delay(Duration::from_secs(15))
+            .build();
+        let headers = headers(&[("x-ratelimit-reset", "120")]);
+
+        let delay = config.retry_delay(&context(&LagoError::RateLimit, 1, Some(&headers)));
+        assert_eq!(delay, Some(Duration::from_secs(15)));
+        assert_eq!(config.delay_for_attempt(2_000), Duration::from_secs(15));
+    }
+
+    #[test]
+    fn stops_retrying_past_the_budget() {
+        let config = RetryConfig::builder()
+            .budget(Duration::from_secs(1))
+            .build();
+        let mut context = context(&LagoError::RateLimit, 1, None);
+
+        context.elapsed = Duration::from_millis(700);
+        assert!(config.retry_delay(&context).is_some());
+        context.elapsed = Duration::from_millis(950);
+        assert!(config.retry_delay(&context).is_none());
+    }
+
+    #[test]
+    fn applies_jitter_within_bounds() {
+        let build = |jitter| {
+            RetryConfig::builder()
+                .initial_delay(Duration::from_millis(100))
+                .max_delay(Duration::from_secs(1))
+                .jitter(jitter)
+                .build()
+        };
+        let error = server_error();
+
+        for _ in 0..100 {
+            let full = build(Jitter::Full)
+                .retry_delay(&context(&error, 2, None))
+                .unwrap();
+            assert!(full <= Duration::from_millis(400));
+
+            let equal = build(Jitter::Equal)
+                .retry_delay(&context(&error, 2, None))
+                .unwrap();
+            assert!(equal >= Duration::from_millis(200) && equal <= Duration::from_millis(400));
+
+            let mut decorrelated = context(&error, 2, None);
+            decorrelated.previous_delay = Some(Duration::from_millis(500));
+            let delay = build(Jitter::Decorrelated)
+                .retry_delay(&decorrelated)
+                .unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_secs(1));
+        }
+    }
+
+    fn info(limit: u32, remaining: u32, reset: u64) -> RateLimitInfo {
+        RateLimitInfo {
+            limit: Some(limit),
+            remaining: Some(remaining),
+            reset: Some(reset),
+            method: "GET".to_string(),
+            url: "http://lago.test".to_string(),
+        }
+    }
+
+    #[test]
+    fn rate_limiter_lets_requests_through_until_headers_are_known() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+
+        assert_eq!(limiter.try_reserve(now), None);
+        assert_eq!(limiter.try_reserve(now), None);
+    }
+
+    #[test]
+    fn rate_limiter_waits_for_reset_once_tokens_are_used() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+
+        assert_eq!(limiter.try_reserve(now), None);
+        limiter.update(&info(10, 2, 30), now);
+
+        assert_eq!(limiter.try_reserve(now), None);
+        assert_eq!(limiter.try_reserve(now), None);
+        assert_eq!(limiter.try_reserve(now), Some(Duration::from_secs(30)));
+        assert_eq!(
+            limiter.try_reserve(now + Duration::from_secs(10)),
+            Some(Duration::from_secs(20))
+        );
+
+        // The window reset: the bucket refills to the limit.
+        let later = now + Duration::from_secs(30);
+        for _ in 0..10 {
+            assert_eq!(limiter.try_reserve(later), None);
+        }
+        limiter.update(&info(10, 0, 60), later);
+        assert_eq!(limiter.try_reserve(later), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn rate_limiter_discounts_requests_in_flight() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+
+        // Three concurrent requests; the first response reports 2 remaining,
+        // which doesn't account for the two others yet.
+        for _ in 0..3 {
+            assert_eq!(limiter.try_reserve(now), None);
+        }
+        limiter.update(&info(10, 2, 30), now);
+
+        assert!(limiter.try_reserve(now).is_some());
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_permits_are_released_on_drop() {
+        let limiter = Arc::new(RateLimiter::default());
+
+        let permit = limiter.acquire(Duration::ZERO).await;
+        assert_eq!(limiter.lock().in_flight, 1);
+        drop(permit);
+        assert_eq!(limiter.lock().in_flight, 0);
+    }
+}
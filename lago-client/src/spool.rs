@@ -0,0 +1,575 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use lago_types::error::{LagoError, Result};
+use lago_types::requests::event::{CreateEventInput, CreateEventRequest};
+use lago_types::responses::event::CreateEventResponse;
+
+use crate::client::LagoClient;
+
+const SEGMENT_PREFIX: &str = "segment-";
+const SEGMENT_EXTENSION: &str = "ndjson";
+
+/// A line of a spool segment.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Record {
+    Append { event: CreateEventInput },
+    Ack { transaction_id: String },
+}
+
+struct PendingEvent {
+    segment: u64,
+    sequence: u64,
+    event: CreateEventInput,
+}
+
+struct SpoolState {
+    active_segment: u64,
+    active_file: File,
+    active_bytes: u64,
+    next_sequence: u64,
+    pending: HashMap<String, PendingEvent>,
+    pending_per_segment: BTreeMap<u64, usize>,
+}
+
+/// The segments of a spool, shared with the blocking tasks writing to them.
+struct SpoolLog {
+    dir: PathBuf,
+    state: Mutex<SpoolState>,
+}
+
+/// Durable, file-backed spool for usage events
+///
+/// Every event is appended to a write-ahead log before being sent, and only
+/// marked as acknowledged once Lago returned a [`CreateEventResponse`]. The
+/// log is a directory of NDJSON segments: a new segment is started once the
+/// active one grows past `max_segment_bytes`, and segments are deleted once
+/// all of their events are acknowledged.
+///
+/// Events still pending when the process stops are loaded again by
+/// [`EventSpool::open`] and sent by [`EventSpool::replay`]. The spool holds a
+/// single entry per `transaction_id`, so an event is never spooled twice.
+///
+/// Events rejected by the API with a client error (other than 401 and 429)
+/// can never succeed and are dropped from the spool; every other failure keeps
+/// the event for the next replay.
+///
+/// Records are written and synced to disk on the blocking thread pool, so
+/// spooling never stalls the async runtime.
+///
+/// # Example
+/// ```no_run
+/// use lago_client::{EventSpool, LagoClient};
+/// use lago_types::requests::event::{CreateEventInput, CreateEventRequest};
+///
+/// # async fn example() -> lago_types::error::Result<()> {
+/// let client = LagoClient::from_env()?;
+/// let spool = EventSpool::open(client, "/var/lib/my-app/lago-spool")?;
+///
+/// // Send the events left over by a previous run
+/// spool.replay().await?;
+///
+/// let input = CreateEventInput::for_subscription(
+///     "transaction_123".to_string(),
+///     "sub_123".to_string(),
+///     "api_calls".to_string(),
+/// );
+/// spool.create_event(CreateEventRequest::new(input)).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct EventSpool {
+    client: LagoClient,
+    max_segment_bytes: u64,
+    log: Arc<SpoolLog>,
+}
+
+impl EventSpool {
+    /// Default size after which a new segment is started (16 MiB).
+    pub const DEFAULT_MAX_SEGMENT_BYTES: u64 = 16 * 1024 * 1024;
+
+    /// Opens the spool stored in a directory, creating it if needed
+    ///
+    /// Pending events of existing segments are loaded so they can be replayed.
+    ///
+    /// # Arguments
+    /// * `client` - The client used to send the events
+    /// * `dir` - The directory holding the spool segments
+    ///
+    /// # Returns
+    /// A `Result` containing the spool or an I/O error
+    pub fn open(client: LagoClient, dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let mut pending = HashMap::new();
+        let mut pending_per_segment = BTreeMap::new();
+        let mut next_sequence = 0;
+        let segments = list_segments(&dir)?;
+
+        for &segment in &segments {
+            let file = File::open(segment_path(&dir, segment))?;
+            for line in BufReader::new(file).lines() {
+                let line = line?;
+                // A torn trailing line is left behind if the process died
+                // mid-write; the event it held was never sent.
+                let Ok(record) = serde_json::from_str::<Record>(&line) else {
+                    continue;
+                };
+
+                match record {
+                    Record::Append { event } => {
+                        if pending.contains_key(&event.transaction_id) {
+                            continue;
+                        }
+                        *pending_per_segment.entry(segment).or_insert(0) += 1;
+                        pending.insert(
+                            event.transaction_id.clone(),
+                            PendingEvent {
+                                segment,
+                                sequence: next_sequence,
+                                event,
+                            },
+                        );
+                        next_sequence += 1;
+                    }
+                    Record::Ack { transaction_id } => {
+                        if let Some(acked) = pending.remove(&transaction_id) {
+                            decrement(&mut pending_per_segment, acked.segment);
+                        }
+                    }
+                }
+            }
+        }
+
+        for &segment in &segments {
+            if !pending_per_segment.contains_key(&segment) {
+                fs::remove_file(segment_path(&dir, segment))?;
+            }
+        }
+
+        // Always start a fresh segment so new records never follow a torn line.
+        let active_segment = segments.last().map_or(0, |last| last + 1);
+        let active_file = open_segment(&dir, active_segment)?;
+
+        Ok(Self {
+            client,
+            max_segment_bytes: Self::DEFAULT_MAX_SEGMENT_BYTES,
+            log: Arc::new(SpoolLog {
+                dir,
+                state: Mutex::new(SpoolState {
+                    active_segment,
+                    active_file,
+                    active_bytes: 0,
+                    next_sequence,
+                    pending,
+                    pending_per_segment,
+                }),
+            }),
+        })
+    }
+
+    /// Sets the size after which a new segment is started
+    ///
+    /// # Arguments
+    /// * `max_segment_bytes` - The segment size threshold in bytes
+    ///
+    /// # Returns
+    /// The spool instance for method chaining
+    pub fn with_max_segment_bytes(mut self, max_segment_bytes: u64) -> Self {
+        self.max_segment_bytes = max_segment_bytes;
+        self
+    }
+
+    /// Spools an event, then sends it to Lago
+    ///
+    /// The event stays in the spool if it could not be delivered, and is sent
+    /// again by the next [`EventSpool::replay`].
+    ///
+    /// # Arguments
+    /// * `request` - The request containing the event data to create
+    ///
+    /// # Returns
+    /// A `Result` containing the created event acknowledgment or an error
+    pub async fn create_event(&self, request: CreateEventRequest) -> Result<CreateEventResponse> {
+        let event = request.event.clone();
+        let max_segment_bytes = self.max_segment_bytes;
+        self.blocking(move |log| log.append(&event, max_segment_bytes))
+            .await?;
+        self.deliver(request).await
+    }
+
+    /// Sends every pending event, oldest first
+    ///
+    /// Replay stops at the first event that could not be delivered because of
+    /// a transient error, which is returned.
+    ///
+    /// # Returns
+    /// A `Result` containing the number of events acknowledged by Lago or an error
+    pub async fn replay(&self) -> Result<usize> {
+        let mut delivered = 0;
+
+        for event in self.pending() {
+            match self.deliver(CreateEventRequest::new(event)).await {
+                Ok(_) => delivered += 1,
+                Err(error) if is_transient(&error) => return Err(error),
+                Err(_) => {}
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Returns the events not yet acknowledged, oldest first
+    pub fn pending(&self) -> Vec<CreateEventInput> {
+        let state = self.log.lock();
+        let mut pending: Vec<_> = state.pending.values().collect();
+        pending.sort_by_key(|pending| pending.sequence);
+        pending.into_iter().map(|p| p.event.clone()).collect()
+    }
+
+    /// Returns the number of events not yet acknowledged
+    pub fn len(&self) -> usize {
+        self.log.lock().pending.len()
+    }
+
+    /// Returns whether every spooled event was acknowledged
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    async fn deliver(&self, request: CreateEventRequest) -> Result<CreateEventResponse> {
+        let transaction_id = request.event.transaction_id.clone();
+
+        let result = self.client.create_event(request).await;
+        let settled = match &result {
+            Ok(_) => true,
+            Err(error) => !is_transient(error),
+        };
+        if settled {
+            self.blocking(move |log| log.ack(&transaction_id)).await?;
+        }
+        result
+    }
+
+    /// Runs file I/O on the spool segments on the blocking thread pool.
+    async fn blocking<T, F>(&self, operation: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&SpoolLog) -> Result<T> + Send + 'static,
+    {
+        let log = self.log.clone();
+        tokio::task::spawn_blocking(move || operation(&log))
+            .await
+            .map_err(|error| LagoError::Io(std::io::Error::other(error)))?
+    }
+}
+
+impl SpoolLog {
+    fn append(&self, event: &CreateEventInput, max_segment_bytes: u64) -> Result<()> {
+        let mut state = self.lock();
+        if state.pending.contains_key(&event.transaction_id) {
+            return Ok(());
+        }
+
+        if state.active_bytes >= max_segment_bytes {
+            self.rotate(&mut state)?;
+        }
+
+        let record = Record::Append {
+            event: event.clone(),
+        };
+        let segment = state.active_segment;
+        let written = write_record(&mut state.active_file, &record)?;
+        state.active_bytes += written;
+
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        *state.pending_per_segment.entry(segment).or_insert(0) += 1;
+        state.pending.insert(
+            event.transaction_id.clone(),
+            PendingEvent {
+                segment,
+                sequence,
+                event: event.clone(),
+            },
+        );
+
+        Ok(())
+    }
+
+    fn ack(&self, transaction_id: &str) -> Result<()> {
+        let mut state = self.lock();
+        let Some(acked) = state.pending.remove(transaction_id) else {
+            return Ok(());
+        };
+
+        let record = Record::Ack {
+            transaction_id: transaction_id.to_string(),
+        };
+        if acked.segment == state.active_segment {
+            state.active_bytes += write_record(&mut state.active_file, &record)?;
+        } else {
+            let mut file = OpenOptions::new()
+                .append(true)
+                .open(segment_path(&self.dir, acked.segment))?;
+            write_record(&mut file, &record)?;
+        }
+
+        decrement(&mut state.pending_per_segment, acked.segment);
+        if acked.segment != state.active_segment
+            && !state.pending_per_segment.contains_key(&acked.segment)
+        {
+            fs::remove_file(segment_path(&self.dir, acked.segment))?;
+        }
+
+        Ok(())
+    }
+
+    fn rotate(&self, state: &mut SpoolState) -> Result<()> {
+        let previous = state.active_segment;
+        state.active_segment += 1;
+        state.active_file = open_segment(&self.dir, state.active_segment)?;
+        state.active_bytes = 0;
+
+        if !state.pending_per_segment.contains_key(&previous) {
+            fs::remove_file(segment_path(&self.dir, previous))?;
+        }
+
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SpoolState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Whether an error may go away by sending the event again later.
+fn is_transient(error: &LagoError) -> bool {
+    match error {
+        LagoError::Api { status, .. } => *status >= 500,
+        LagoError::NotFound { .. }
+        | LagoError::Validation { .. }
+        | LagoError::Forbidden { .. }
+        | LagoError::Conflict { .. }
+        | LagoError::Serialization(_) => false,
+        _ => true,
+    }
+}
+
+fn decrement(pending_per_segment: &mut BTreeMap<u64, usize>, segment: u64) {
+    if let Some(count) = pending_per_segment.get_mut(&segment) {
+        *count -= 1;
+        if *count == 0 {
+            pending_per_segment.remove(&segment);
+        }
+    }
+}
+
+fn write_record(file: &mut File, record: &Record) -> Result<u64> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    file.write_all(&line)?;
+    file.sync_data()?;
+    Ok(line.len() as u64)
+}
+
+fn segment_path(dir: &Path, segment: u64) -> PathBuf {
+    dir.join(format!("{SEGMENT_PREFIX}{segment:020}.{SEGMENT_EXTENSION}"))
+}
+
+fn open_segment(dir: &Path, segment: u64) -> Result<File> {
+    Ok(OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(segment_path(dir, segment))?)
+}
+
+fn list_segments(dir: &Path) -> Result<Vec<u64>> {
+    let mut segments = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let Some(id) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(SEGMENT_PREFIX))
+            .and_then(|name| name.strip_suffix(&format!(".{SEGMENT_EXTENSION}")))
+            .and_then(|id| id.parse().ok())
+        else {
+            continue;
+        };
+        segments.push(id);
+    }
+    segments.sort_unstable();
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Credentials, Region, RetryConfig, RetryMode};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn create_test_client(base_url: &str) -> LagoClient {
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom(base_url.to_string()))
+            .retry_config(RetryConfig::builder().mode(RetryMode::Off).build())
+            .build();
+
+        LagoClient::new(config)
+    }
+
+    fn request(transaction_id: &str) -> CreateEventRequest {
+        CreateEventRequest::new(CreateEventInput::for_customer(
+            transaction_id.to_string(),
+            "customer_123".to_string(),
+            "api_calls".to_string(),
+        ))
+    }
+
+    fn acknowledgment(transaction_id: &str) -> String {
+        json!({"event": {"transaction_id": transaction_id, "code": "api_calls"}}).to_string()
+    }
+
+    fn segment_count(dir: &Path) -> usize {
+        list_segments(dir).unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn test_acknowledged_event_is_removed() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/events")
+            .with_status(200)
+            .with_body(acknowledgment("tx_1"))
+            .create_async()
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let spool = EventSpool::open(create_test_client(&server.url()), dir.path()).unwrap();
+        spool.create_event(request("tx_1")).await.unwrap();
+        assert!(spool.is_empty());
+        drop(spool);
+
+        let spool = EventSpool::open(create_test_client(&server.url()), dir.path()).unwrap();
+        assert!(spool.is_empty());
+        assert_eq!(segment_count(dir.path()), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_events_are_replayed_after_restart() {
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("POST", "/events")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let spool = EventSpool::open(create_test_client(&server.url()), dir.path()).unwrap();
+        assert!(spool.create_event(request("tx_1")).await.is_err());
+        assert!(spool.create_event(request("tx_2")).await.is_err());
+        // Same transaction_id again: the spool keeps a single copy.
+        assert!(spool.create_event(request("tx_1")).await.is_err());
+        assert_eq!(spool.len(), 2);
+        drop(spool);
+        failing.assert_async().await;
+        failing.remove_async().await;
+
+        let delivered = server
+            .mock("POST", "/events")
+            .match_body(Matcher::Regex("tx_[12]".to_string()))
+            .with_status(200)
+            .with_body(acknowledgment("tx_1"))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let spool = EventSpool::open(create_test_client(&server.url()), dir.path()).unwrap();
+        let pending: Vec<_> = spool
+            .pending()
+            .into_iter()
+            .map(|event| event.transaction_id)
+            .collect();
+        assert_eq!(pending, vec!["tx_1", "tx_2"]);
+
+        assert_eq!(spool.replay().await.unwrap(), 2);
+        delivered.assert_async().await;
+        assert!(spool.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_events_are_dropped() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/events")
+            .with_status(422)
+            .with_body(r#"{"status": 422, "error": "Unprocessable Entity"}"#)
+            .create_async()
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let spool = EventSpool::open(create_test_client(&server.url()), dir.path()).unwrap();
+        assert!(matches!(
+            spool.create_event(request("tx_1")).await,
+            Err(LagoError::Validation { .. })
+        ));
+        assert!(spool.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_segments_rotate_and_are_deleted_once_acknowledged() {
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("POST", "/events")
+            .with_status(500)
+            .create_async()
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+
+        let spool = EventSpool::open(create_test_client(&server.url()), dir.path())
+            .unwrap()
+            .with_max_segment_bytes(1);
+        for i in 0..3 {
+            let _ = spool.create_event(request(&format!("tx_{i}"))).await;
+        }
+        assert_eq!(segment_count(dir.path()), 3);
+        failing.remove_async().await;
+
+        server
+            .mock("POST", "/events")
+            .with_status(200)
+            .with_body(acknowledgment("tx"))
+            .create_async()
+            .await;
+
+        assert_eq!(spool.replay().await.unwrap(), 3);
+        // Only the active segment is left.
+        assert_eq!(segment_count(dir.path()), 1);
+    }
+
+    #[tokio::test]
+    async fn test_torn_trailing_line_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let record = serde_json::to_string(&Record::Append {
+            event: request("tx_1").event,
+        })
+        .unwrap();
+        fs::write(
+            segment_path(dir.path(), 0),
+            format!("{record}\n{{\"op\":\"append\",\"ev"),
+        )
+        .unwrap();
+
+        let spool = EventSpool::open(create_test_client("http://localhost"), dir.path()).unwrap();
+        assert_eq!(spool.len(), 1);
+    }
+}
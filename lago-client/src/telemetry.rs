@@ -0,0 +1,438 @@
+//! `tracing` instrumentation of API calls, enabled by the `tracing` feature.
+//!
+//! Every public operation of [`LagoClient`](crate::LagoClient) runs in a span
+//! named after it (`get_customer`, `list_invoices`, ...), which records the
+//! `lago.resource_id` of operations targeting a single resource, e.g. the
+//! charge code of `get_plan_charge`. Inside it, each call opens a
+//! `lago.request` span with the HTTP method, path and trace id, and one `lago.attempt` child span per attempt recording the
+//! response status, latency, rate limit headers and the delay before the
+//! next retry.
+//!
+//! The transport sends each attempt inside its `lago.attempt` span, so events
+//! emitted by the transport are attached to it.
+//!
+//! Requests carry a W3C `traceparent` header. Its trace id comes from the
+//! [`TraceContext`] set with
+//! [`LagoClient::with_trace_context`](crate::LagoClient::with_trace_context),
+//! or from the current span (see [`TraceContext::current`]), and is generated
+//! for each call otherwise.
+//!
+//! Without the feature, the spans compile away and warnings are printed to
+//! stderr instead of being emitted as `tracing` events.
+
+use std::future::Future;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+#[cfg(feature = "tracing")]
+use reqwest::header::HeaderValue;
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+#[cfg(feature = "tracing")]
+use tracing_subscriber::registry::{LookupSpan, Registry};
+
+#[cfg(feature = "tracing")]
+use uuid::Uuid;
+
+use lago_types::error::LagoError;
+
+use crate::client::{LagoClient, RateLimitInfo};
+
+/// Emits a warning as a `tracing` event, or prints it to stderr without the
+/// `tracing` feature.
+macro_rules! warning {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)*);
+        #[cfg(not(feature = "tracing"))]
+        eprintln!("lago: {}", format_args!($($arg)*));
+    }};
+}
+
+pub(crate) use warning;
+
+/// Header carrying the W3C trace context of a request.
+#[cfg(feature = "tracing")]
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// A W3C trace context, as carried by the `traceparent` header.
+///
+/// # Example
+///
+/// ```
+/// use lago_client::TraceContext;
+///
+/// let context = TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+/// assert_eq!(context.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+/// assert!(context.sampled());
+/// ```
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    trace_id: String,
+    parent_id: String,
+    flags: u8,
+}
+
+#[cfg(feature = "tracing")]
+impl TraceContext {
+    /// Creates a sampled context with a random trace id
+    ///
+    /// # Returns
+    /// A new `TraceContext`
+    pub fn new() -> Self {
+        Self {
+            trace_id: Uuid::new_v4().simple().to_string(),
+            parent_id: random_span_id(),
+            flags: 0x01,
+        }
+    }
+
+    /// Parses a `traceparent` header value
+    ///
+    /// # Arguments
+    /// * `header` - The header value, e.g. `00-<trace id>-<parent id>-<flags>`
+    ///
+    /// # Returns
+    /// The parsed context, or `None` if the value is not a valid version 00 traceparent
+    pub fn parse(header: &str) -> Option<Self> {
+        let mut parts = header.trim().split('-');
+        let (version, trace_id, parent_id, flags) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+
+        let is_hex = |value: &str, len: usize| {
+            value.len() == len
+                && value
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        };
+        let is_zero = |value: &str| value.bytes().all(|b| b == b'0');
+        if version != "00"
+            || parts.next().is_some()
+            || !is_hex(trace_id, 32)
+            || !is_hex(parent_id, 16)
+            || is_zero(trace_id)
+            || is_zero(parent_id)
+        {
+            return None;
+        }
+
+        Some(Self {
+            trace_id: trace_id.to_string(),
+            parent_id: parent_id.to_string(),
+            flags: u8::from_str_radix(flags, 16).ok()?,
+        })
+    }
+
+    /// Returns the 32 hex digit trace id.
+    pub fn trace_id(&self) -> &str {
+        &self.trace_id
+    }
+
+    /// Returns the 16 hex digit id of the parent span.
+    pub fn parent_id(&self) -> &str {
+        &self.parent_id
+    }
+
+    /// Returns whether the caller sampled the trace.
+    pub fn sampled(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+
+    /// Returns a context in the same trace with a new parent span id.
+    pub fn child(&self) -> Self {
+        Self {
+            parent_id: random_span_id(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the context of the current span
+    ///
+    /// The context is the one attached with [`TraceContext::attach`] to the
+    /// current span or its closest parent. When none of them carries one, a
+    /// new context is attached to the root span, so that all calls made within
+    /// it share a trace id.
+    ///
+    /// # Returns
+    /// The context, or `None` outside of a span or when the subscriber is not
+    /// built on a `tracing_subscriber` registry
+    pub fn current() -> Option<Self> {
+        tracing::Span::current()
+            .with_subscriber(|(id, dispatch)| {
+                let registry = dispatch.downcast_ref::<Registry>()?;
+                let span = registry.span(id)?;
+                if let Some(context) = span
+                    .scope()
+                    .find_map(|span| span.extensions().get::<Self>().cloned())
+                {
+                    return Some(context);
+                }
+
+                let root = span.scope().from_root().next()?;
+                let context = Self::new();
+                root.extensions_mut().replace(context.clone());
+                Some(context)
+            })
+            .flatten()
+    }
+
+    /// Attaches the context to a span
+    ///
+    /// Calls made within the span, or any of its children, then continue the
+    /// trace, e.g. the one of the incoming request handled in that span.
+    ///
+    /// # Arguments
+    /// * `span` - The span to attach the context to
+    pub fn attach(&self, span: &tracing::Span) {
+        span.with_subscriber(|(id, dispatch)| {
+            if let Some(span) = dispatch
+                .downcast_ref::<Registry>()
+                .and_then(|registry| registry.span(id))
+            {
+                span.extensions_mut().replace(self.clone());
+            }
+        });
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Default for TraceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl std::fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "00-{}-{}-{:02x}",
+            self.trace_id, self.parent_id, self.flags
+        )
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn random_span_id() -> String {
+    Uuid::new_v4().simple().to_string()[..16].to_string()
+}
+
+/// The `lago.request` span of a single API call.
+pub(crate) struct RequestTrace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    context: TraceContext,
+}
+
+/// The `lago.attempt` span of one attempt of an API call.
+pub(crate) struct AttemptTrace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    context: TraceContext,
+    #[cfg(feature = "tracing")]
+    started_at: std::time::Instant,
+}
+
+impl RequestTrace {
+    /// Opens the request span as a child of the current (operation) span.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn start(client: &LagoClient, operation: &str, method: &str, url: &str) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let endpoint = client
+                .config
+                .region()
+                .map(|region| region.endpoint().to_string());
+            let path = endpoint
+                .ok()
+                .and_then(|endpoint| url.strip_prefix(endpoint.as_str()))
+                .unwrap_or(url);
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            let context = client
+                .trace_context
+                .clone()
+                .or_else(TraceContext::current)
+                .unwrap_or_default();
+
+            let span = tracing::info_span!(
+                "lago.request",
+                lago.operation = operation,
+                http.request.method = method,
+                url.path = path,
+                trace_id = context.trace_id(),
+                http.response.status_code = tracing::field::Empty,
+                attempts = tracing::field::Empty,
+            );
+
+            Self { span, context }
+        }
+        #[cfg(not(feature = "tracing"))]
+        Self {}
+    }
+
+    /// Opens the span of an attempt, numbered from 0.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn attempt(&self, attempt: u32) -> AttemptTrace {
+        #[cfg(feature = "tracing")]
+        {
+            let context = self.context.child();
+            let span = tracing::info_span!(
+                parent: &self.span,
+                "lago.attempt",
+                attempt,
+                span_id = context.parent_id(),
+                http.response.status_code = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error = tracing::field::Empty,
+                retry_delay_ms = tracing::field::Empty,
+                ratelimit.limit = tracing::field::Empty,
+                ratelimit.remaining = tracing::field::Empty,
+                ratelimit.reset = tracing::field::Empty,
+            );
+
+            AttemptTrace {
+                span,
+                context,
+                started_at: std::time::Instant::now(),
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        AttemptTrace {}
+    }
+
+    /// Runs the attempts of the call inside the request span.
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            future.instrument(self.span.clone())
+        }
+        #[cfg(not(feature = "tracing"))]
+        future
+    }
+
+    /// Records the final status and number of attempts of the call.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn finish(&self, status: Option<u16>, attempts: u32) {
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(status) = status {
+                self.span.record("http.response.status_code", status);
+            }
+            self.span.record("attempts", attempts);
+        }
+    }
+}
+
+impl AttemptTrace {
+    /// Runs the transport call of the attempt inside the attempt span.
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            future.instrument(self.span.clone())
+        }
+        #[cfg(not(feature = "tracing"))]
+        future
+    }
+
+    /// Adds the `traceparent` header of the attempt to the request headers.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn inject(&self, headers: &mut HeaderMap) {
+        #[cfg(feature = "tracing")]
+        if let Ok(value) = HeaderValue::from_str(&self.context.to_string()) {
+            headers.insert(TRACEPARENT_HEADER, value);
+        }
+    }
+
+    /// Records the response status and latency of the attempt.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_status(&self, status: u16) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("http.response.status_code", status);
+            self.record_latency();
+        }
+    }
+
+    /// Records an error that prevented a response, and the latency of the attempt.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_error(&self, error: &LagoError) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("error", tracing::field::display(error));
+            self.record_latency();
+        }
+    }
+
+    /// Records the rate limit headers of the response.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_rate_limit(&self, info: Option<&RateLimitInfo>) {
+        #[cfg(feature = "tracing")]
+        if let Some(info) = info {
+            if let Some(limit) = info.limit {
+                self.span.record("ratelimit.limit", limit);
+            }
+            if let Some(remaining) = info.remaining {
+                self.span.record("ratelimit.remaining", remaining);
+            }
+            if let Some(reset) = info.reset {
+                self.span.record("ratelimit.reset", reset);
+            }
+        }
+    }
+
+    /// Records the delay before the attempt is retried.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_retry_delay(&self, delay: Duration) {
+        #[cfg(feature = "tracing")]
+        self.span.record("retry_delay_ms", duration_ms(delay));
+    }
+
+    #[cfg(feature = "tracing")]
+    fn record_latency(&self) {
+        self.span
+            .record("latency_ms", duration_ms(self.started_at.elapsed()));
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_traceparent() {
+        let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let context = TraceContext::parse(header).unwrap();
+
+        assert_eq!(context.parent_id(), "00f067aa0ba902b7");
+        assert_eq!(context.to_string(), header);
+
+        let child = context.child();
+        assert_eq!(child.trace_id(), context.trace_id());
+        assert_ne!(child.parent_id(), context.parent_id());
+        assert!(TraceContext::parse(&TraceContext::new().to_string()).is_some());
+    }
+
+    #[test]
+    fn rejects_invalid_traceparent() {
+        for header in [
+            "",
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-zz",
+        ] {
+            assert_eq!(TraceContext::parse(header), None, "{header}");
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//! HTTP transport used by [`LagoClient`](crate::LagoClient) to talk to the API.
+//!
+//! The client builds a [`TransportRequest`] for every attempt and hands it to
+//! the configured [`Transport`]. By default requests go through
+//! [`ReqwestTransport`]; plug in your own HTTP stack with
+//! [`ConfigBuilder::transport`](crate::ConfigBuilder::transport), or use
+//! [`InMemoryTransport`] to script responses in tests.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client as HttpClient;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::Serialize;
+use serde_json::Value;
+
+use lago_types::error::{LagoError, Result};
+
+/// A request ready to be sent to the Lago API.
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// HTTP method (GET, POST, PUT, DELETE).
+    pub method: String,
+    /// Full request URL, including the query string.
+    pub url: String,
+    /// Request headers, including authentication.
+    pub headers: HeaderMap,
+    /// JSON-encoded request body, if any.
+    pub body: Option<Vec<u8>>,
+}
+
+impl TransportRequest {
+    /// Returns the value of a header, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// Parses the request body as JSON.
+    ///
+    /// # Returns
+    /// `None` when the request has no body or the body is not valid JSON
+    pub fn json_body(&self) -> Option<Value> {
+        serde_json::from_slice(self.body.as_deref()?).ok()
+    }
+}
+
+/// A response returned by a [`Transport`].
+#[derive(Debug, Clone, Default)]
+pub struct TransportResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers.
+    pub headers: HeaderMap,
+    /// Raw response body.
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    /// Creates an empty response with the given status
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code
+    ///
+    /// # Returns
+    /// A new `TransportResponse` with no headers and an empty body
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a response with a JSON body
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code
+    /// * `body` - The value serialized as the response body
+    ///
+    /// # Returns
+    /// A new `TransportResponse` with a `content-type: application/json` header
+    pub fn json<T: Serialize>(status: u16, body: &T) -> Self {
+        Self::new(status)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_vec(body).unwrap_or_default())
+    }
+
+    /// Adds a header to the response
+    ///
+    /// Invalid header names or values are ignored.
+    ///
+    /// # Arguments
+    /// * `name` - The header name
+    /// * `value` - The header value
+    ///
+    /// # Returns
+    /// The modified response for method chaining
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            self.headers.insert(name, value);
+        }
+        self
+    }
+
+    /// Sets the response body
+    ///
+    /// # Arguments
+    /// * `body` - The raw body
+    ///
+    /// # Returns
+    /// The modified response for method chaining
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Whether the status is in the 2xx range.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends requests to the Lago API.
+///
+/// Implementations only move bytes: authentication, serialization, error
+/// decoding and retries are handled by the client. Errors returned by `send`
+/// are treated as network failures and retried according to the retry policy.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Sends a single request attempt
+    ///
+    /// # Arguments
+    /// * `request` - The request to send
+    ///
+    /// # Returns
+    /// A `Result` containing the response, whatever its status, or a network error
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse>;
+}
+
+/// The default [`Transport`], backed by a `reqwest` client.
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: HttpClient,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport with its own `reqwest` client
+    ///
+    /// # Arguments
+    /// * `timeout` - The timeout applied to each request
+    /// * `user_agent` - The user agent sent with each request
+    ///
+    /// # Returns
+    /// A new `ReqwestTransport` instance
+    pub fn new(timeout: Duration, user_agent: &str) -> Self {
+        let client = HttpClient::builder()
+            .timeout(timeout)
+            .user_agent(user_agent)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self { client }
+    }
+
+    /// Creates a transport sending requests through an existing `reqwest` client
+    ///
+    /// # Arguments
+    /// * `client` - The configured `reqwest` client (proxies, TLS roots, ...)
+    ///
+    /// # Returns
+    /// A new `ReqwestTransport` instance
+    pub fn from_client(client: HttpClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes()).map_err(|_| {
+            LagoError::Configuration(format!("Unsupported method: {}", request.method))
+        })?;
+
+        let mut builder = self
+            .client
+            .request(method, &request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+
+        Ok(TransportResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// A [`Transport`] that never touches the network.
+///
+/// Responses are scripted up front and returned in order; every request is
+/// recorded so tests can assert on what the client sent. Clones share the
+/// same script and recorded requests, so keep a clone around after handing
+/// the transport to the client.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use lago_client::{Config, Credentials, InMemoryTransport, LagoClient, Region, TransportResponse};
+/// use lago_types::requests::customer::GetCustomerRequest;
+/// use serde_json::json;
+///
+/// # async fn example() -> lago_types::error::Result<()> {
+/// let transport = InMemoryTransport::new();
+/// transport.push_response(TransportResponse::json(
+///     404,
+///     &json!({"status": 404, "error": "Not Found", "code": "customer_not_found"}),
+/// ));
+///
+/// let client = LagoClient::new(
+///     Config::builder()
+///         .credentials(Credentials::new("api-key".to_string()))
+///         .region(Region::Custom("http://lago.test".to_string()))
+///         .transport(Arc::new(transport.clone()))
+///         .build(),
+/// );
+///
+/// let result = client
+///     .get_customer(GetCustomerRequest::new("cust_123".to_string()))
+///     .await;
+/// assert!(result.is_err());
+/// assert_eq!(transport.requests()[0].method, "GET");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct InMemoryTransport {
+    state: Arc<Mutex<InMemoryState>>,
+}
+
+#[derive(Default)]
+struct InMemoryState {
+    responses: VecDeque<Result<TransportResponse>>,
+    requests: Vec<TransportRequest>,
+}
+
+impl InMemoryTransport {
+    /// Creates a transport with no scripted responses
+    ///
+    /// # Returns
+    /// A new `InMemoryTransport` instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response returned to the next unanswered request
+    ///
+    /// # Arguments
+    /// * `response` - The response to return
+    pub fn push_response(&self, response: TransportResponse) {
+        self.lock().responses.push_back(Ok(response));
+    }
+
+    /// Queues a JSON response returned to the next unanswered request
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code
+    /// * `body` - The value serialized as the response body
+    pub fn push_json<T: Serialize>(&self, status: u16, body: &T) {
+        self.push_response(TransportResponse::json(status, body));
+    }
+
+    /// Queues a network failure returned to the next unanswered request
+    ///
+    /// # Arguments
+    /// * `error` - The error returned by `send`
+    pub fn push_error(&self, error: LagoError) {
+        self.lock().responses.push_back(Err(error));
+    }
+
+    /// Returns the requests sent so far, in order.
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Returns the last request sent, if any.
+    pub fn last_request(&self) -> Option<TransportRequest> {
+        self.lock().requests.last().cloned()
+    }
+
+    /// Returns the number of scripted responses not yet consumed.
+    pub fn pending_responses(&self) -> usize {
+        self.lock().responses.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, InMemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl Transport for InMemoryTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let mut state = self.lock();
+        let description = format!("{} {}", request.method, request.url);
+        state.requests.push(request);
+        state.responses.pop_front().unwrap_or_else(|| {
+            Err(LagoError::Transport(format!(
+                "no scripted response for {description}"
+            )))
+        })
+    }
+}
+
+/// Body of a `GET /customers/cust_123` response, with the given tax codes applied.
+#[cfg(test)]
+pub(crate) fn customer_json(tax_codes: &[&str]) -> serde_json::Value {
+    let taxes: Vec<serde_json::Value> = tax_codes
+        .iter()
+        .map(|code| {
+            serde_json::json!({
+                "lago_id": "2b802b80-2b80-2b80-2b80-2b802b802b80",
+                "name": code,
+                "code": code,
+                "rate": 20.0,
+                "applied_to_organization": false,
+                "created_at": "2024-01-01T00:00:00Z"
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "customer": {
+            "lago_id": "1a901a90-1a90-1a90-1a90-1a901a901a90",
+            "external_id": "cust_123",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z",
+            "applicable_timezone": "UTC",
+            "billing_entity_code": "default",
+            "account_type": "customer",
+            "finalize_zero_amount_invoice": "inherit",
+            "skip_invoice_custom_sections": false,
+            "billing_configuration": {},
+            "shipping_address": {},
+            "metadata": [],
+            "integration_customers": [],
+            "taxes": taxes
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, Credentials, LagoClient, Region, RetryConfig};
+    use serde_json::json;
+
+    fn create_client(transport: &InMemoryTransport, max_attempts: u32) -> LagoClient {
+        let config = Config::builder()
+            .credentials(Credentials::new("test-api-key".to_string()))
+            .region(Region::Custom("http://lago.test".to_string()))
+            .retry_config(
+                RetryConfig::builder()
+                    .max_attempts(max_attempts)
+                    .initial_delay(Duration::from_millis(1))
+                    .build(),
+            )
+            .transport(Arc::new(transport.clone()))
+            .build();
+
+        LagoClient::new(config)
+    }
+
+    #[tokio::test]
+    async fn records_requests_and_returns_scripted_responses() {
+        let transport = InMemoryTransport::new();
+        transport.push_json(200, &json!({"id": "123"}));
+        let client = create_client(&transport, 0);
+
+        let response: Value = client
+            .make_request(
+                "test",
+                "POST",
+                "http://lago.test/test",
+                Some(&json!({"name": "a"})),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response["id"], "123");
+        let request = transport.last_request().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "http://lago.test/test");
+        assert_eq!(request.header("authorization"), Some("Bearer test-api-key"));
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert!(request.header(crate::IDEMPOTENCY_KEY_HEADER).is_some());
+        assert_eq!(request.json_body(), Some(json!({"name": "a"})));
+    }
+
+    #[tokio::test]
+    async fn retries_scripted_network_errors() {
+        let transport = InMemoryTransport::new();
+        transport.push_error(LagoError::Transport("connection reset".to_string()));
+        transport.push_response(TransportResponse::json(
+            503,
+            &json!({"status": 503, "error": "Service Unavailable"}),
+        ));
+        transport.push_json(200, &json!({"id": "123"}));
+        let client = create_client(&transport, 3);
+
+        let response: Value = client
+            .make_request("test", "POST", "http://lago.test/test", None::<&()>)
+            .await
+            .unwrap();
+
+        assert_eq!(response["id"], "123");
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        let key = requests[0].header(crate::IDEMPOTENCY_KEY_HEADER);
+        assert!(
+            requests
+                .iter()
+                .all(|r| r.header(crate::IDEMPOTENCY_KEY_HEADER) == key)
+        );
+        assert_eq!(transport.pending_responses(), 0);
+    }
+
+    #[tokio::test]
+    async fn fails_when_no_response_is_scripted() {
+        let transport = InMemoryTransport::new();
+        let client = create_client(&transport, 0);
+
+        let result: Result<Value> = client
+            .make_request("test", "GET", "http://lago.test/test", None::<&()>)
+            .await;
+
+        assert!(matches!(result, Err(LagoError::Transport(_))));
+        assert!(transport.last_request().unwrap().body.is_none());
+    }
+}
@@ -0,0 +1,99 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+/// Result of a route handler: a JSON body or a Lago error response.
+pub(crate) type ApiResult = Result<Json<Value>, ErrorResponse>;
+
+/// An error response using the body format of the Lago API.
+#[derive(Debug)]
+pub(crate) struct ErrorResponse {
+    status: StatusCode,
+    body: Value,
+}
+
+impl ErrorResponse {
+    /// 401 returned when the API key is missing or wrong.
+    pub(crate) fn unauthorized() -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            body: json!({"status": 401, "error": "Unauthorized"}),
+        }
+    }
+
+    /// 400 returned when the body can't be parsed.
+    pub(crate) fn bad_request(message: impl std::fmt::Display) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            body: json!({"status": 400, "error": format!("BadRequest: {message}")}),
+        }
+    }
+
+    /// 404 with a `<resource>_not_found` code.
+    pub(crate) fn not_found(resource: &str) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            body: json!({
+                "status": 404,
+                "error": "Not Found",
+                "code": format!("{resource}_not_found"),
+            }),
+        }
+    }
+
+    /// 405 returned when an action is not allowed in the current state.
+    pub(crate) fn method_not_allowed(code: &str) -> Self {
+        Self {
+            status: StatusCode::METHOD_NOT_ALLOWED,
+            body: json!({"status": 405, "error": "Method Not Allowed", "code": code}),
+        }
+    }
+
+    /// 422 with a single field error.
+    pub(crate) fn validation(field: &str, code: &str) -> Self {
+        Self::validation_details(json!({ field: [code] }))
+    }
+
+    /// 422 with the given `error_details`.
+    pub(crate) fn validation_details(details: Value) -> Self {
+        Self {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            body: json!({
+                "status": 422,
+                "error": "Unprocessable Entity",
+                "code": "validation_errors",
+                "error_details": details,
+            }),
+        }
+    }
+
+    /// Returns the `error_details` of a validation error.
+    pub(crate) fn details(&self) -> Option<&Value> {
+        self.body.get("error_details")
+    }
+}
+
+impl IntoResponse for ErrorResponse {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.body)).into_response()
+    }
+}
+
+/// Parses the JSON object found under `root` in a request body.
+pub(crate) fn parse_input<T: DeserializeOwned>(
+    body: &[u8],
+    root: &str,
+) -> Result<T, ErrorResponse> {
+    let mut value: Value = serde_json::from_slice(body).map_err(ErrorResponse::bad_request)?;
+    let input = value
+        .get_mut(root)
+        .filter(|input| !input.is_null())
+        .map(Value::take)
+        .ok_or_else(|| {
+            ErrorResponse::bad_request(format!("param is missing or the value is empty: {root}"))
+        })?;
+
+    serde_json::from_value(input).map_err(ErrorResponse::bad_request)
+}
@@ -0,0 +1,33 @@
+//! In-process fake Lago API server for integration tests.
+//!
+//! [`FakeLago`] listens on a local port and implements the core endpoints
+//! covered by `lago-client` (customers, plans, subscriptions, events,
+//! invoices, coupons, applied coupons, credit notes and payments) on top of an
+//! in-memory store. Lists are paginated with the same `meta` block as Lago and
+//! failures use Lago's error bodies, so the client runs against it unchanged
+//! through `Region::Custom`.
+//!
+//! ```no_run
+//! use lago_testkit::FakeLago;
+//! use lago_types::requests::customer::{CreateCustomerInput, CreateCustomerRequest};
+//!
+//! # async fn example() -> lago_types::error::Result<()> {
+//! let lago = FakeLago::start().await;
+//! let client = lago.client();
+//!
+//! let input = CreateCustomerInput::new("cust_123".to_string());
+//! client.create_customer(CreateCustomerRequest::new(input)).await?;
+//!
+//! assert_eq!(lago.customers().len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+mod error;
+mod query;
+mod routes;
+mod server;
+mod store;
+
+pub use server::*;
+pub use store::Store;
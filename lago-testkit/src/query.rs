@@ -0,0 +1,135 @@
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+/// Number of items per page when `per_page` is not set.
+pub(crate) const DEFAULT_PER_PAGE: usize = 20;
+
+/// Keys that never filter list results.
+const RESERVED_KEYS: &[&str] = &["page", "per_page", "search_term"];
+
+/// Query string of a list request.
+#[derive(Debug, Default)]
+pub(crate) struct Query {
+    params: Vec<(String, String)>,
+}
+
+impl Query {
+    /// Parses a raw query string, keeping repeated keys such as `status[]`.
+    pub(crate) fn parse(raw: Option<&str>) -> Self {
+        let params = raw
+            .map(|raw| {
+                url::form_urlencoded::parse(raw.as_bytes())
+                    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { params }
+    }
+
+    /// Returns the first value of `key`.
+    pub(crate) fn get(&self, key: &str) -> Option<&str> {
+        self.values(key).into_iter().next()
+    }
+
+    /// Returns every value of `key`, including the `key[]` form.
+    pub(crate) fn values(&self, key: &str) -> Vec<&str> {
+        self.params
+            .iter()
+            .filter(|(k, _)| k.strip_suffix("[]").unwrap_or(k) == key)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    /// Removes `key` so it no longer filters results.
+    pub(crate) fn without(mut self, key: &str) -> Self {
+        self.params
+            .retain(|(k, _)| k.strip_suffix("[]").unwrap_or(k) != key);
+        self
+    }
+
+    /// Adds a filter unless the query already sets `key`.
+    pub(crate) fn with_default(mut self, key: &str, value: &str) -> Self {
+        if self.get(key).is_none() {
+            self.params.push((key.to_string(), value.to_string()));
+        }
+        self
+    }
+
+    /// Whether `item` matches every filter naming one of its top-level fields.
+    ///
+    /// Filters on fields the item doesn't have (date ranges, search terms,
+    /// ...) are ignored; array fields match when any element matches.
+    fn matches(&self, item: &Map<String, Value>) -> bool {
+        let mut keys: Vec<&str> = self
+            .params
+            .iter()
+            .map(|(k, _)| k.strip_suffix("[]").unwrap_or(k))
+            .filter(|k| !RESERVED_KEYS.contains(k))
+            .collect();
+        keys.dedup();
+
+        keys.into_iter().all(|key| match item.get(key) {
+            None => true,
+            Some(field) => {
+                let accepted = self.values(key);
+                match field {
+                    Value::Array(values) => values.iter().any(|v| accepts(&accepted, v)),
+                    value => accepts(&accepted, value),
+                }
+            }
+        })
+    }
+
+    /// Filters `items` and returns the requested page under `key`, with Lago's
+    /// pagination `meta`.
+    pub(crate) fn paginate<T: Serialize>(
+        &self,
+        key: &str,
+        items: impl IntoIterator<Item = T>,
+    ) -> Value {
+        let items: Vec<Value> = items
+            .into_iter()
+            .filter_map(|item| serde_json::to_value(item).ok())
+            .filter(|item| item.as_object().is_some_and(|item| self.matches(item)))
+            .collect();
+
+        let per_page = self
+            .get("per_page")
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_PER_PAGE);
+        let page = self
+            .get("page")
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(1);
+
+        let total_count = items.len();
+        let total_pages = total_count.div_ceil(per_page);
+        let data: Vec<Value> = items
+            .into_iter()
+            .skip((page - 1) * per_page)
+            .take(per_page)
+            .collect();
+
+        json!({
+            key: data,
+            "meta": {
+                "current_page": page,
+                "next_page": (page < total_pages).then_some(page + 1),
+                "prev_page": (page > 1).then_some(page - 1),
+                "total_pages": total_pages,
+                "total_count": total_count,
+            }
+        })
+    }
+}
+
+fn accepts(accepted: &[&str], value: &Value) -> bool {
+    match value {
+        Value::String(s) => accepted.contains(&s.as_str()),
+        Value::Null => false,
+        other => accepted.contains(&other.to_string().as_str()),
+    }
+}
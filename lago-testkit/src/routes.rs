@@ -0,0 +1,51 @@
+pub(crate) mod applied_coupon;
+pub(crate) mod coupon;
+pub(crate) mod credit_note;
+pub(crate) mod customer;
+pub(crate) mod event;
+pub(crate) mod invoice;
+pub(crate) mod payment;
+pub(crate) mod plan;
+pub(crate) mod subscription;
+
+use axum::Router;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::json;
+
+use crate::error::ErrorResponse;
+use crate::server::SharedStore;
+
+/// Builds the router serving every fake endpoint.
+pub(crate) fn router(store: SharedStore) -> Router {
+    Router::new()
+        .merge(customer::routes())
+        .merge(plan::routes())
+        .merge(subscription::routes())
+        .merge(event::routes())
+        .merge(invoice::routes())
+        .merge(coupon::routes())
+        .merge(applied_coupon::routes())
+        .merge(credit_note::routes())
+        .merge(payment::routes())
+        .fallback(|| async {
+            (
+                StatusCode::NOT_FOUND,
+                axum::Json(json!({"status": 404, "error": "Not Found"})),
+            )
+                .into_response()
+        })
+        .with_state(store)
+}
+
+/// Parses a datetime sent by the client (RFC 3339 or a plain date).
+pub(crate) fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>, ErrorResponse> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+        })
+        .map_err(|_| ErrorResponse::validation(field, "invalid_date"))
+}
@@ -0,0 +1,146 @@
+use axum::body::Bytes;
+use axum::extract::{RawQuery, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{
+    AppliedCoupon, AppliedCouponFrequency, AppliedCouponStatus, CouponFrequency,
+};
+use lago_types::requests::applied_coupon::ApplyCouponInput;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new().route(
+        "/applied_coupons",
+        get(list_applied_coupons).post(apply_coupon),
+    )
+}
+
+async fn list_applied_coupons(
+    State(store): State<SharedStore>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+
+    Ok(Json(query.paginate(
+        "applied_coupons",
+        store.applied_coupons.iter().rev(),
+    )))
+}
+
+/// Applies a coupon to a customer, with optional overrides of its amounts.
+async fn apply_coupon(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: ApplyCouponInput = parse_input(&body, "applied_coupon")?;
+
+    let mut store = lock(&store);
+    let customer_id = store
+        .customer(&input.external_customer_id)
+        .and_then(|c| c.lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("customer"))?;
+    let coupon = store
+        .coupon(&input.coupon_code)
+        .ok_or_else(|| ErrorResponse::not_found("coupon"))?;
+
+    let already_applied = store.applied_coupons.iter().any(|a| {
+        a.lago_customer_id == customer_id
+            && a.lago_coupon_id == coupon.lago_id
+            && a.status == AppliedCouponStatus::Active
+    });
+    if already_applied && !coupon.reusable {
+        return Err(ErrorResponse::validation(
+            "coupon",
+            "coupon_is_not_reusable",
+        ));
+    }
+
+    let amount_cents = input.amount_cents.or(coupon.amount_cents);
+    let frequency_duration = input.frequency_duration.or(coupon.frequency_duration);
+    let applied_coupon = AppliedCoupon {
+        lago_id: Uuid::new_v4(),
+        lago_coupon_id: coupon.lago_id,
+        coupon_code: coupon.code.clone(),
+        coupon_name: coupon.name.clone(),
+        lago_customer_id: customer_id,
+        external_customer_id: input.external_customer_id,
+        status: AppliedCouponStatus::Active,
+        frequency: input.frequency.unwrap_or(match coupon.frequency {
+            CouponFrequency::Once => AppliedCouponFrequency::Once,
+            CouponFrequency::Recurring => AppliedCouponFrequency::Recurring,
+            CouponFrequency::Forever => AppliedCouponFrequency::Forever,
+        }),
+        created_at: Utc::now(),
+        amount_cents,
+        amount_cents_remaining: amount_cents,
+        amount_currency: input.amount_currency.or(coupon.amount_currency.clone()),
+        percentage_rate: input.percentage_rate.or(coupon.percentage_rate.clone()),
+        frequency_duration,
+        frequency_duration_remaining: frequency_duration,
+        expiration_at: coupon.expiration_at,
+        terminated_at: None,
+    };
+    store.applied_coupons.push(applied_coupon.clone());
+
+    Ok(Json(json!({ "applied_coupon": applied_coupon })))
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::filters::applied_coupon::AppliedCouponFilter;
+    use lago_types::models::CouponExpiration;
+    use lago_types::requests::applied_coupon::{ApplyCouponRequest, ListAppliedCouponsRequest};
+    use lago_types::requests::coupon::{CreateCouponInput, CreateCouponRequest};
+    use lago_types::requests::customer::{CreateCustomerInput, CreateCustomerRequest};
+
+    use super::*;
+    use crate::FakeLago;
+
+    #[tokio::test]
+    async fn applies_coupons_once_unless_reusable() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+        client
+            .create_customer(CreateCustomerRequest::new(CreateCustomerInput::new(
+                "cust_1".to_string(),
+            )))
+            .await
+            .unwrap();
+        let input = CreateCouponInput::percentage(
+            "Launch".to_string(),
+            "launch".to_string(),
+            "10.0".to_string(),
+            CouponFrequency::Forever,
+            CouponExpiration::NoExpiration,
+        )
+        .with_reusable(false);
+        client
+            .create_coupon(CreateCouponRequest::new(input))
+            .await
+            .unwrap();
+
+        let input = ApplyCouponInput::new("cust_1".to_string(), "launch".to_string());
+        let applied = client
+            .apply_coupon(ApplyCouponRequest::new(input.clone()))
+            .await
+            .unwrap()
+            .applied_coupon;
+        assert_eq!(applied.percentage_rate.as_deref(), Some("10.0"));
+        assert_eq!(applied.frequency, AppliedCouponFrequency::Forever);
+
+        let result = client.apply_coupon(ApplyCouponRequest::new(input)).await;
+        assert!(
+            matches!(&result, Err(e) if e.field_errors("coupon") == ["coupon_is_not_reusable"])
+        );
+
+        let request = ListAppliedCouponsRequest::new().with_filters(
+            AppliedCouponFilter::new().with_external_customer_id("cust_1".to_string()),
+        );
+        let applied = client.list_applied_coupons(Some(request)).await.unwrap();
+        assert_eq!(applied.applied_coupons.len(), 1);
+    }
+}
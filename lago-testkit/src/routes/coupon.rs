@@ -0,0 +1,227 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{Coupon, CouponType};
+use lago_types::requests::coupon::{CreateCouponInput, UpdateCouponInput};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::routes::parse_datetime;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/coupons", get(list_coupons).post(create_coupon))
+        .route(
+            "/coupons/{code}",
+            get(get_coupon).put(update_coupon).delete(delete_coupon),
+        )
+}
+
+async fn list_coupons(State(store): State<SharedStore>, RawQuery(query): RawQuery) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+
+    Ok(Json(query.paginate("coupons", store.coupons.iter().rev())))
+}
+
+async fn get_coupon(State(store): State<SharedStore>, Path(code): Path<String>) -> ApiResult {
+    let store = lock(&store);
+    let coupon = store
+        .coupon(&code)
+        .ok_or_else(|| ErrorResponse::not_found("coupon"))?;
+
+    Ok(Json(json!({ "coupon": coupon })))
+}
+
+async fn create_coupon(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreateCouponInput = parse_input(&body, "coupon")?;
+
+    let mut store = lock(&store);
+    if store.coupon(&input.code).is_some() {
+        return Err(ErrorResponse::validation("code", "value_already_exist"));
+    }
+
+    let coupon = Coupon {
+        lago_id: Uuid::new_v4(),
+        name: input.name,
+        code: input.code,
+        coupon_type: input.coupon_type,
+        amount_cents: input.amount_cents,
+        amount_currency: input.amount_currency,
+        percentage_rate: input.percentage_rate,
+        frequency: input.frequency,
+        frequency_duration: input.frequency_duration,
+        reusable: input.reusable.unwrap_or(true),
+        limited_plans: input.limited_plans.unwrap_or(false),
+        plan_codes: input.plan_codes,
+        limited_billable_metrics: input.limited_billable_metrics.unwrap_or(false),
+        billable_metric_codes: input.billable_metric_codes,
+        expiration: input.expiration,
+        expiration_at: input
+            .expiration_at
+            .as_deref()
+            .map(|value| parse_datetime("expiration_at", value))
+            .transpose()?,
+        created_at: Utc::now(),
+        terminated_at: None,
+    };
+    validate(&coupon)?;
+    store.coupons.push(coupon.clone());
+
+    Ok(Json(json!({ "coupon": coupon })))
+}
+
+async fn update_coupon(
+    State(store): State<SharedStore>,
+    Path(code): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let input: UpdateCouponInput = parse_input(&body, "coupon")?;
+
+    let mut store = lock(&store);
+    let coupon = store
+        .coupons
+        .iter_mut()
+        .find(|c| c.code == code)
+        .ok_or_else(|| ErrorResponse::not_found("coupon"))?;
+
+    let mut updated = coupon.clone();
+    macro_rules! merge {
+        ($($field:ident),*) => {
+            $(if let Some(value) = input.$field {
+                updated.$field = value;
+            })*
+        };
+    }
+    merge!(
+        name,
+        coupon_type,
+        frequency,
+        reusable,
+        limited_plans,
+        limited_billable_metrics,
+        expiration
+    );
+
+    if input.amount_cents.is_some() {
+        updated.amount_cents = input.amount_cents;
+    }
+    if input.amount_currency.is_some() {
+        updated.amount_currency = input.amount_currency;
+    }
+    if input.percentage_rate.is_some() {
+        updated.percentage_rate = input.percentage_rate;
+    }
+    if input.frequency_duration.is_some() {
+        updated.frequency_duration = input.frequency_duration;
+    }
+    if input.plan_codes.is_some() {
+        updated.plan_codes = input.plan_codes;
+    }
+    if input.billable_metric_codes.is_some() {
+        updated.billable_metric_codes = input.billable_metric_codes;
+    }
+    if let Some(expiration_at) = input.expiration_at.as_deref() {
+        updated.expiration_at = Some(parse_datetime("expiration_at", expiration_at)?);
+    }
+    validate(&updated)?;
+    *coupon = updated;
+
+    Ok(Json(json!({ "coupon": coupon })))
+}
+
+/// Removes the coupon and returns it as terminated.
+async fn delete_coupon(State(store): State<SharedStore>, Path(code): Path<String>) -> ApiResult {
+    let mut store = lock(&store);
+    let index = store
+        .coupons
+        .iter()
+        .position(|c| c.code == code)
+        .ok_or_else(|| ErrorResponse::not_found("coupon"))?;
+    let mut coupon = store.coupons.remove(index);
+    coupon.terminated_at = Some(Utc::now());
+
+    Ok(Json(json!({ "coupon": coupon })))
+}
+
+/// Checks that the amount fields match the coupon type.
+fn validate(coupon: &Coupon) -> Result<(), ErrorResponse> {
+    match coupon.coupon_type {
+        CouponType::FixedAmount if coupon.amount_cents.is_none() => Err(ErrorResponse::validation(
+            "amount_cents",
+            "value_is_mandatory",
+        )),
+        CouponType::FixedAmount if coupon.amount_currency.is_none() => Err(
+            ErrorResponse::validation("amount_currency", "value_is_mandatory"),
+        ),
+        CouponType::Percentage if coupon.percentage_rate.is_none() => Err(
+            ErrorResponse::validation("percentage_rate", "value_is_mandatory"),
+        ),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::error::LagoError;
+    use lago_types::models::{CouponExpiration, CouponFrequency};
+    use lago_types::requests::coupon::{
+        CreateCouponRequest, DeleteCouponRequest, GetCouponRequest, UpdateCouponRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    #[tokio::test]
+    async fn manages_coupons() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+
+        let mut input = CreateCouponInput::fixed_amount(
+            "Welcome".to_string(),
+            "welcome".to_string(),
+            1_000,
+            "EUR".to_string(),
+            CouponFrequency::Once,
+            CouponExpiration::NoExpiration,
+        );
+        input.amount_currency = None;
+        let result = client
+            .create_coupon(CreateCouponRequest::new(input.clone()))
+            .await;
+        assert!(
+            matches!(&result, Err(e) if e.field_errors("amount_currency") == ["value_is_mandatory"])
+        );
+
+        input.amount_currency = Some("EUR".to_string());
+        client
+            .create_coupon(CreateCouponRequest::new(input))
+            .await
+            .unwrap();
+
+        let input = UpdateCouponInput::new().with_name("Hello".to_string());
+        let coupon = client
+            .update_coupon(UpdateCouponRequest::new("welcome".to_string(), input))
+            .await
+            .unwrap()
+            .coupon;
+        assert_eq!(coupon.name, "Hello");
+        assert_eq!(coupon.amount_cents, Some(1_000));
+
+        let coupon = client
+            .delete_coupon(DeleteCouponRequest::new("welcome".to_string()))
+            .await
+            .unwrap()
+            .coupon;
+        assert!(coupon.terminated_at.is_some());
+        let result = client
+            .get_coupon(GetCouponRequest::new("welcome".to_string()))
+            .await;
+        assert!(matches!(result, Err(LagoError::NotFound { resource }) if resource == "coupon"));
+    }
+}
@@ -0,0 +1,378 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{
+    CreditNote, CreditNoteCreditStatus, CreditNoteItem, CreditNoteRefundStatus,
+    EstimatedCreditNote, EstimatedCreditNoteItem, InvoiceStatus,
+};
+use lago_types::requests::credit_note::{
+    CreateCreditNoteInput, EstimateCreditNoteInput, UpdateCreditNoteInput,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::routes::invoice::{invoice_customer_id, invoice_mut};
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route(
+            "/credit_notes",
+            get(list_credit_notes).post(create_credit_note),
+        )
+        .route("/credit_notes/estimate", post(estimate_credit_note))
+        .route(
+            "/credit_notes/{lago_id}",
+            get(get_credit_note).put(update_credit_note),
+        )
+        .route("/credit_notes/{lago_id}/void", put(void_credit_note))
+}
+
+async fn list_credit_notes(
+    State(store): State<SharedStore>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+    let customer_id = query.get("external_customer_id");
+
+    Ok(Json(query.paginate(
+        "credit_notes",
+        store.credit_notes.iter().rev().filter(|c| {
+            customer_id.is_none_or(|id| {
+                store
+                    .invoice(&c.lago_invoice_id.to_string())
+                    .and_then(invoice_customer_id)
+                    == Some(id)
+            })
+        }),
+    )))
+}
+
+async fn get_credit_note(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let credit_note = store
+        .credit_notes
+        .iter()
+        .find(|c| c.lago_id.to_string() == lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("credit_note"))?;
+
+    Ok(Json(json!({ "credit_note": credit_note })))
+}
+
+/// Credits or refunds part of a finalized invoice.
+async fn create_credit_note(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreateCreditNoteInput = parse_input(&body, "credit_note")?;
+
+    let mut store = lock(&store);
+    let sequential_id = store.credit_notes.len() as i32 + 1;
+    let invoice = invoice_mut(&mut store.invoices, &input.invoice_id)?;
+    if !matches!(invoice.status, InvoiceStatus::Finalized) {
+        return Err(ErrorResponse::method_not_allowed("invalid_invoice_status"));
+    }
+
+    let fees = invoice.fees.as_deref().unwrap_or_default();
+    let mut items = Vec::with_capacity(input.items.len());
+    for item in &input.items {
+        let fee = fees
+            .iter()
+            .find(|f| f.lago_id.to_string() == item.fee_id)
+            .ok_or_else(|| ErrorResponse::not_found("fee"))?;
+        items.push(CreditNoteItem {
+            lago_id: Uuid::new_v4(),
+            amount_cents: item.amount_cents,
+            amount_currency: invoice.currency.clone(),
+            fee: Some(json!(fee)),
+        });
+    }
+
+    let total = input.credit_amount_cents + input.refund_amount_cents;
+    if items.iter().map(|i| i.amount_cents).sum::<i64>() != total {
+        return Err(ErrorResponse::validation(
+            "base",
+            "does_not_match_item_amounts",
+        ));
+    }
+    if total > invoice.total_amount_cents - invoice.credit_notes_amount_cents {
+        return Err(ErrorResponse::validation(
+            "base",
+            "higher_than_remaining_invoice_amount",
+        ));
+    }
+    invoice.credit_notes_amount_cents += total;
+
+    let now = Utc::now();
+    let credit_note = CreditNote {
+        lago_id: Uuid::new_v4(),
+        sequential_id,
+        number: format!("{}-CN{sequential_id:03}", invoice.number),
+        lago_invoice_id: invoice.lago_id.unwrap_or_default(),
+        invoice_number: invoice.number.clone(),
+        issuing_date: now.date_naive().to_string(),
+        credit_status: (input.credit_amount_cents > 0).then_some(CreditNoteCreditStatus::Available),
+        refund_status: (input.refund_amount_cents > 0).then_some(CreditNoteRefundStatus::Pending),
+        reason: input.reason,
+        description: input.description,
+        currency: invoice.currency.clone(),
+        total_amount_cents: total,
+        taxes_amount_cents: 0,
+        taxes_rate: 0.0,
+        sub_total_excluding_taxes_amount_cents: total,
+        balance_amount_cents: input.credit_amount_cents,
+        credit_amount_cents: input.credit_amount_cents,
+        refund_amount_cents: input.refund_amount_cents,
+        coupons_adjustment_amount_cents: 0,
+        file_url: None,
+        self_billed: Some(false),
+        created_at: now,
+        updated_at: now,
+        items: Some(items),
+        applied_taxes: Some(Vec::new()),
+    };
+    store.credit_notes.push(credit_note.clone());
+
+    Ok(Json(json!({ "credit_note": credit_note })))
+}
+
+async fn update_credit_note(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let input: UpdateCreditNoteInput = parse_input(&body, "credit_note")?;
+
+    let mut store = lock(&store);
+    let credit_note = store
+        .credit_notes
+        .iter_mut()
+        .find(|c| c.lago_id.to_string() == lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("credit_note"))?;
+    if input.refund_status.is_some() {
+        credit_note.refund_status = input.refund_status;
+    }
+    credit_note.updated_at = Utc::now();
+
+    Ok(Json(json!({ "credit_note": credit_note })))
+}
+
+/// Sets the remaining credit of the credit note to zero.
+async fn void_credit_note(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+) -> ApiResult {
+    let mut store = lock(&store);
+    let credit_note = store
+        .credit_notes
+        .iter_mut()
+        .find(|c| c.lago_id.to_string() == lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("credit_note"))?;
+    if credit_note.credit_status != Some(CreditNoteCreditStatus::Available)
+        || credit_note.balance_amount_cents == 0
+    {
+        return Err(ErrorResponse::method_not_allowed("no_voidable_amount"));
+    }
+    credit_note.credit_status = Some(CreditNoteCreditStatus::Voided);
+    credit_note.balance_amount_cents = 0;
+    credit_note.updated_at = Utc::now();
+
+    Ok(Json(json!({ "credit_note": credit_note })))
+}
+
+/// Computes the amounts of a credit note for the given fee items, without taxes.
+async fn estimate_credit_note(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: EstimateCreditNoteInput = parse_input(&body, "credit_note")?;
+
+    let mut store = lock(&store);
+    let invoice = invoice_mut(&mut store.invoices, &input.invoice_id)?;
+    let fees = invoice.fees.as_deref().unwrap_or_default();
+    let mut items = Vec::with_capacity(input.items.len());
+    for item in &input.items {
+        let fee = fees
+            .iter()
+            .find(|f| f.lago_id.to_string() == item.fee_id)
+            .ok_or_else(|| ErrorResponse::not_found("fee"))?;
+        items.push(EstimatedCreditNoteItem {
+            lago_fee_id: fee.lago_id,
+            amount_cents: item.amount_cents,
+        });
+    }
+
+    let remaining = invoice.total_amount_cents - invoice.credit_notes_amount_cents;
+    let total = items
+        .iter()
+        .map(|i| i.amount_cents)
+        .sum::<i64>()
+        .min(remaining);
+    let estimate = EstimatedCreditNote {
+        lago_invoice_id: invoice.lago_id.unwrap_or_default(),
+        invoice_number: invoice.number.clone(),
+        currency: invoice.currency.clone(),
+        taxes_amount_cents: 0,
+        taxes_rate: 0.0,
+        sub_total_excluding_taxes_amount_cents: total,
+        max_creditable_amount_cents: total,
+        max_refundable_amount_cents: total,
+        coupons_adjustment_amount_cents: 0,
+        items,
+        applied_taxes: Vec::new(),
+    };
+
+    Ok(Json(json!({ "estimated_credit_note": estimate })))
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::error::LagoError;
+    use lago_types::models::CreditNoteReason;
+    use lago_types::requests::credit_note::{
+        CreateCreditNoteItemInput, CreateCreditNoteRequest, EstimateCreditNoteRequest,
+        UpdateCreditNoteRequest, VoidCreditNoteRequest,
+    };
+    use lago_types::requests::customer::{CreateCustomerInput, CreateCustomerRequest};
+    use lago_types::requests::invoice::{
+        CreateInvoiceFeeInput, CreateInvoiceInput, CreateInvoiceRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    #[tokio::test]
+    async fn credits_invoices_up_to_their_total() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+        client
+            .create_customer(CreateCustomerRequest::new(CreateCustomerInput::new(
+                "cust_1".to_string(),
+            )))
+            .await
+            .unwrap();
+        let fees = vec![
+            CreateInvoiceFeeInput::new("setup".to_string(), 1.0).with_unit_amount_cents(1_000),
+        ];
+        let invoice = client
+            .create_invoice(CreateInvoiceRequest::new(CreateInvoiceInput::new(
+                "cust_1".to_string(),
+                "EUR".to_string(),
+                fees,
+            )))
+            .await
+            .unwrap()
+            .invoice;
+        let invoice_id = invoice.lago_id.unwrap().to_string();
+        let fee_id = invoice.fees.unwrap()[0].lago_id.to_string();
+
+        let estimate = client
+            .estimate_credit_note(EstimateCreditNoteRequest::new(
+                EstimateCreditNoteInput::new(
+                    invoice_id.clone(),
+                    vec![CreateCreditNoteItemInput::new(fee_id.clone(), 600)],
+                ),
+            ))
+            .await
+            .unwrap()
+            .estimated_credit_note;
+        assert_eq!(estimate.max_refundable_amount_cents, 600);
+        assert_eq!(estimate.items[0].lago_fee_id.to_string(), fee_id);
+
+        let credit = |amount| {
+            CreateCreditNoteRequest::new(CreateCreditNoteInput::new(
+                invoice_id.clone(),
+                CreditNoteReason::Other,
+                0,
+                amount,
+                vec![CreateCreditNoteItemInput::new(fee_id.clone(), amount)],
+            ))
+        };
+        let credit_note = client
+            .create_credit_note(credit(600))
+            .await
+            .unwrap()
+            .credit_note;
+        assert_eq!(
+            credit_note.refund_status,
+            Some(CreditNoteRefundStatus::Pending)
+        );
+
+        let result = client.create_credit_note(credit(600)).await;
+        assert!(
+            matches!(&result, Err(e) if e.field_errors("base") == ["higher_than_remaining_invoice_amount"])
+        );
+
+        let input =
+            UpdateCreditNoteInput::new().with_refund_status(CreditNoteRefundStatus::Succeeded);
+        let credit_note = client
+            .update_credit_note(UpdateCreditNoteRequest::new(
+                credit_note.lago_id.to_string(),
+                input,
+            ))
+            .await
+            .unwrap()
+            .credit_note;
+        assert_eq!(
+            credit_note.refund_status,
+            Some(CreditNoteRefundStatus::Succeeded)
+        );
+        assert_eq!(lago.invoices()[0].credit_notes_amount_cents, 600);
+
+        let result = client
+            .void_credit_note(VoidCreditNoteRequest::new(credit_note.lago_id.to_string()))
+            .await;
+        assert!(matches!(
+            result,
+            Err(LagoError::Api { status: 405, code: Some(code), .. }) if code == "no_voidable_amount"
+        ));
+    }
+
+    #[tokio::test]
+    async fn voids_remaining_credit() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+        client
+            .create_customer(CreateCustomerRequest::new(CreateCustomerInput::new(
+                "cust_1".to_string(),
+            )))
+            .await
+            .unwrap();
+        let fees = vec![
+            CreateInvoiceFeeInput::new("setup".to_string(), 1.0).with_unit_amount_cents(1_000),
+        ];
+        let invoice = client
+            .create_invoice(CreateInvoiceRequest::new(CreateInvoiceInput::new(
+                "cust_1".to_string(),
+                "EUR".to_string(),
+                fees,
+            )))
+            .await
+            .unwrap()
+            .invoice;
+        let fee_id = invoice.fees.unwrap()[0].lago_id.to_string();
+
+        let input = CreateCreditNoteInput::new(
+            invoice.lago_id.unwrap().to_string(),
+            CreditNoteReason::OrderChange,
+            400,
+            0,
+            vec![CreateCreditNoteItemInput::new(fee_id, 400)],
+        );
+        let credit_note = client
+            .create_credit_note(CreateCreditNoteRequest::new(input))
+            .await
+            .unwrap()
+            .credit_note;
+
+        let voided = client
+            .void_credit_note(VoidCreditNoteRequest::new(credit_note.lago_id.to_string()))
+            .await
+            .unwrap()
+            .credit_note;
+        assert_eq!(voided.credit_status, Some(CreditNoteCreditStatus::Voided));
+        assert_eq!(voided.balance_amount_cents, 0);
+    }
+}
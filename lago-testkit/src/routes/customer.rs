@@ -0,0 +1,382 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{
+    Customer, CustomerAccountType, CustomerBillingConfiguration, CustomerFinalizeZeroAmountInvoice,
+    CustomerIntegration, CustomerMetadata, CustomerShippingAddress,
+};
+use lago_types::requests::customer::CreateCustomerInput;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/customers", get(list_customers).post(create_customer))
+        .route(
+            "/customers/{external_id}",
+            get(get_customer).delete(delete_customer),
+        )
+        .route("/customers/{external_id}/portal_url", get(get_portal_url))
+}
+
+async fn list_customers(State(store): State<SharedStore>, RawQuery(query): RawQuery) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+
+    Ok(Json(
+        query.paginate("customers", store.customers.iter().rev()),
+    ))
+}
+
+async fn get_customer(
+    State(store): State<SharedStore>,
+    Path(external_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let customer = store
+        .customer(&external_id)
+        .ok_or_else(|| ErrorResponse::not_found("customer"))?;
+
+    Ok(Json(json!({ "customer": customer })))
+}
+
+async fn delete_customer(
+    State(store): State<SharedStore>,
+    Path(external_id): Path<String>,
+) -> ApiResult {
+    let mut store = lock(&store);
+    let index = store
+        .customers
+        .iter()
+        .position(|c| c.external_id.as_deref() == Some(external_id.as_str()))
+        .ok_or_else(|| ErrorResponse::not_found("customer"))?;
+    let customer = store.customers.remove(index);
+
+    Ok(Json(json!({ "customer": customer })))
+}
+
+async fn get_portal_url(
+    State(store): State<SharedStore>,
+    Path(external_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let customer = store
+        .customer(&external_id)
+        .ok_or_else(|| ErrorResponse::not_found("customer"))?;
+    let token = customer.lago_id.unwrap_or_default().simple();
+
+    Ok(Json(json!({
+        "customer": { "portal_url": format!("https://app.lago.test/customer-portal/{token}") }
+    })))
+}
+
+/// Creates the customer, or updates it when the external ID is already known.
+async fn create_customer(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreateCustomerInput = parse_input(&body, "customer")?;
+    if input.external_id.is_empty() {
+        return Err(ErrorResponse::validation(
+            "external_id",
+            "value_is_mandatory",
+        ));
+    }
+
+    let mut store = lock(&store);
+    let position = store
+        .customers
+        .iter()
+        .position(|c| c.external_id.as_deref() == Some(input.external_id.as_str()));
+
+    let customer = match position {
+        Some(index) => {
+            let customer = &mut store.customers[index];
+            update_customer(customer, input);
+            customer.clone()
+        }
+        None => {
+            let customer = build_customer(input, store.customers.len() as i32 + 1);
+            store.customers.push(customer.clone());
+            customer
+        }
+    };
+
+    Ok(Json(json!({ "customer": customer })))
+}
+
+fn build_customer(input: CreateCustomerInput, sequential_id: i32) -> Customer {
+    let now = Utc::now();
+    let mut customer = Customer {
+        lago_id: Some(Uuid::new_v4()),
+        sequential_id: Some(sequential_id),
+        slug: Some(format!("LAG-TEST-{sequential_id:03}")),
+        external_id: Some(input.external_id.clone()),
+        applicable_timezone: "UTC".to_string(),
+        created_at: now,
+        billing_entity_code: "default".to_string(),
+        address_line1: None,
+        address_line2: None,
+        city: None,
+        country: None,
+        currency: None,
+        email: None,
+        legal_name: None,
+        legal_number: None,
+        logo_url: None,
+        name: None,
+        firstname: None,
+        lastname: None,
+        account_type: CustomerAccountType::Customer,
+        customer_type: None,
+        phone: None,
+        state: None,
+        tax_identification_number: None,
+        timezone: None,
+        url: None,
+        zipcode: None,
+        net_payment_term: None,
+        updated_at: now,
+        finalize_zero_amount_invoice: CustomerFinalizeZeroAmountInvoice::Inherit,
+        skip_invoice_custom_sections: false,
+        billing_configuration: CustomerBillingConfiguration {
+            invoice_grace_period: None,
+            payment_provider: None,
+            payment_provider_code: None,
+            provider_customer_id: None,
+            sync: None,
+            sync_with_provider: None,
+            document_locale: None,
+            provider_payment_methods: None,
+        },
+        shipping_address: CustomerShippingAddress {
+            address_line1: None,
+            address_line2: None,
+            city: None,
+            country: None,
+            state: None,
+            zipcode: None,
+        },
+        metadata: Vec::new(),
+        integration_customers: Vec::new(),
+        taxes: None,
+    };
+
+    update_customer(&mut customer, input);
+    customer
+}
+
+/// Applies the fields set in `input`, leaving the others untouched.
+fn update_customer(customer: &mut Customer, input: CreateCustomerInput) {
+    macro_rules! merge {
+        ($($field:ident),*) => {
+            $(if input.$field.is_some() {
+                customer.$field = input.$field;
+            })*
+        };
+    }
+
+    merge!(
+        name,
+        firstname,
+        lastname,
+        email,
+        address_line1,
+        address_line2,
+        city,
+        country,
+        state,
+        zipcode,
+        phone,
+        url,
+        legal_name,
+        legal_number,
+        logo_url,
+        tax_identification_number,
+        timezone,
+        currency,
+        net_payment_term,
+        customer_type
+    );
+
+    if let Some(timezone) = &customer.timezone {
+        customer.applicable_timezone = timezone.clone();
+    }
+    if let Some(value) = input.finalize_zero_amount_invoice {
+        customer.finalize_zero_amount_invoice = value;
+    }
+    if let Some(billing) = input.billing_configuration {
+        customer.billing_configuration = CustomerBillingConfiguration {
+            invoice_grace_period: billing.invoice_grace_period,
+            payment_provider: billing.payment_provider,
+            payment_provider_code: billing.payment_provider_code,
+            provider_customer_id: billing.provider_customer_id,
+            sync: billing.sync,
+            sync_with_provider: billing.sync_with_provider,
+            document_locale: billing.document_locale,
+            provider_payment_methods: billing.provider_payment_methods,
+        };
+    }
+    if let Some(address) = input.shipping_address {
+        customer.shipping_address = CustomerShippingAddress {
+            address_line1: address.address_line1,
+            address_line2: address.address_line2,
+            city: address.city,
+            country: address.country,
+            state: address.state,
+            zipcode: address.zipcode,
+        };
+    }
+    if let Some(metadata) = input.metadata {
+        customer.metadata = metadata
+            .into_iter()
+            .map(|m| CustomerMetadata {
+                lago_id: Uuid::new_v4(),
+                key: m.key,
+                value: m.value,
+                display_in_invoice: m.display_in_invoice,
+                created_at: Utc::now(),
+            })
+            .collect();
+    }
+    if let Some(integrations) = input.integration_customers {
+        customer.integration_customers = integrations
+            .into_iter()
+            .map(|i| CustomerIntegration {
+                lago_id: Uuid::new_v4(),
+                integration_type: i.integration_type,
+                integration_code: Some(i.integration_code),
+                external_customer_id: i.external_customer_id,
+                sync_with_provider: i.sync_with_provider.unwrap_or_default(),
+                subsidiary_id: i.subsidiary_id,
+                targeted_object: i.targeted_object,
+            })
+            .collect();
+    }
+    customer.updated_at = Utc::now();
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_client::{Credentials, LagoClient};
+    use lago_types::error::LagoError;
+    use lago_types::models::{CustomerIntegrationType, PaginationParams};
+    use lago_types::requests::customer::{
+        CreateCustomerIntegration, CreateCustomerRequest, DeleteCustomerRequest,
+        GetCustomerPortalUrlRequest, GetCustomerRequest, ListCustomersRequest, UpdateCustomerInput,
+        UpdateCustomerRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    #[tokio::test]
+    async fn creates_lists_and_upserts_customers() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+
+        for id in ["cust_1", "cust_2", "cust_3"] {
+            let input = CreateCustomerInput::new(id.to_string()).with_name(format!("Name {id}"));
+            client
+                .create_customer(CreateCustomerRequest::new(input))
+                .await
+                .unwrap();
+        }
+
+        let input = CreateCustomerInput::new("cust_1".to_string()).with_email("a@b.c".to_string());
+        let updated = client
+            .create_customer(CreateCustomerRequest::new(input))
+            .await
+            .unwrap()
+            .customer;
+        assert_eq!(updated.name.as_deref(), Some("Name cust_1"));
+        assert_eq!(updated.email.as_deref(), Some("a@b.c"));
+
+        let request = ListCustomersRequest::new()
+            .with_pagination(PaginationParams::new().with_page(1).with_per_page(2));
+        let page = client.list_customers(Some(request)).await.unwrap();
+        assert_eq!(page.customers.len(), 2);
+        assert_eq!(page.customers[0].external_id.as_deref(), Some("cust_3"));
+        assert_eq!(page.meta.total_count, 3);
+        assert_eq!(page.meta.total_pages, 2);
+        assert_eq!(page.meta.next_page, Some(2));
+    }
+
+    #[tokio::test]
+    async fn updates_and_deletes_customers() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+
+        let input = CreateCustomerInput::new("cust_1".to_string()).with_name("Acme".to_string());
+        client
+            .create_customer(CreateCustomerRequest::new(input))
+            .await
+            .unwrap();
+
+        let input = UpdateCustomerInput::new()
+            .with_email("billing@acme.test".to_string())
+            .with_integration_customers(vec![
+                CreateCustomerIntegration::new(
+                    CustomerIntegrationType::Netsuite,
+                    "netsuite".to_string(),
+                )
+                .with_subsidiary_id("2".to_string()),
+            ]);
+        let customer = client
+            .update_customer(UpdateCustomerRequest::new("cust_1".to_string(), input))
+            .await
+            .unwrap()
+            .customer;
+        assert_eq!(customer.name.as_deref(), Some("Acme"));
+        assert_eq!(customer.email.as_deref(), Some("billing@acme.test"));
+        assert_eq!(
+            customer.integration_customers[0].integration_type,
+            CustomerIntegrationType::Netsuite
+        );
+        assert_eq!(
+            customer.integration_customers[0].subsidiary_id.as_deref(),
+            Some("2")
+        );
+
+        let portal = client
+            .get_customer_portal_url(GetCustomerPortalUrlRequest::new("cust_1".to_string()))
+            .await
+            .unwrap();
+        assert!(portal.customer.portal_url.starts_with("https://"));
+
+        let deleted = client
+            .delete_customer(DeleteCustomerRequest::new("cust_1".to_string()))
+            .await
+            .unwrap()
+            .customer;
+        assert_eq!(deleted.external_id.as_deref(), Some("cust_1"));
+        assert!(lago.customers().is_empty());
+
+        let result = client
+            .delete_customer(DeleteCustomerRequest::new("cust_1".to_string()))
+            .await;
+        assert!(matches!(result, Err(LagoError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn returns_lago_errors() {
+        let lago = FakeLago::start().await;
+
+        let result = lago
+            .client()
+            .get_customer(GetCustomerRequest::new("missing".to_string()))
+            .await;
+        assert!(matches!(result, Err(LagoError::NotFound { resource }) if resource == "customer"));
+
+        let client = LagoClient::new(
+            lago.config()
+                .credentials(Credentials::new("wrong".to_string()))
+                .build(),
+        );
+        let result = client.list_customers(None).await;
+        assert!(matches!(result, Err(LagoError::Unauthorized)));
+    }
+}
@@ -0,0 +1,246 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use lago_types::models::Event;
+use lago_types::requests::event::CreateEventInput;
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::routes::parse_datetime;
+use crate::server::{SharedStore, lock};
+use crate::store::Store;
+
+/// Maximum number of events accepted by `POST /events/batch`.
+const MAX_BATCH_SIZE: usize = 100;
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/events", get(list_events).post(create_event))
+        .route("/events/batch", post(create_events_batch))
+        .route("/events/{transaction_id}", get(get_event))
+}
+
+async fn list_events(State(store): State<SharedStore>, RawQuery(query): RawQuery) -> ApiResult {
+    let query = Query::parse(query.as_deref());
+    let from = query
+        .get("timestamp_from")
+        .map(|value| parse_datetime("timestamp_from", value))
+        .transpose()?;
+    let to = query
+        .get("timestamp_to")
+        .map(|value| parse_datetime("timestamp_to", value))
+        .transpose()?;
+
+    let store = lock(&store);
+    let events = store.events.iter().rev().filter(|e| {
+        from.is_none_or(|from| e.timestamp >= from) && to.is_none_or(|to| e.timestamp <= to)
+    });
+
+    Ok(Json(query.paginate("events", events)))
+}
+
+async fn get_event(
+    State(store): State<SharedStore>,
+    Path(transaction_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let event = store
+        .events
+        .iter()
+        .find(|e| e.transaction_id == transaction_id)
+        .ok_or_else(|| ErrorResponse::not_found("event"))?;
+
+    Ok(Json(json!({ "event": event })))
+}
+
+async fn create_event(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreateEventInput = parse_input(&body, "event")?;
+
+    let mut store = lock(&store);
+    let event = build_event(&store, &input)?;
+    store.events.push(event.clone());
+
+    Ok(Json(json!({ "event": acknowledgment(event, input) })))
+}
+
+/// Ingests a batch of events, rejecting all of them if any is invalid.
+async fn create_events_batch(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let inputs: Vec<CreateEventInput> = parse_input(&body, "events")?;
+    if inputs.len() > MAX_BATCH_SIZE {
+        return Err(ErrorResponse::validation("events", "too_many_events"));
+    }
+
+    let mut store = lock(&store);
+    let mut events = Vec::with_capacity(inputs.len());
+    let mut details = Map::new();
+    for (index, input) in inputs.iter().enumerate() {
+        let duplicated = inputs[..index]
+            .iter()
+            .any(|other| other.transaction_id == input.transaction_id);
+        let result = if duplicated {
+            Err(ErrorResponse::validation(
+                "transaction_id",
+                "value_already_exist",
+            ))
+        } else {
+            build_event(&store, input)
+        };
+
+        match result {
+            Ok(event) => events.push(event),
+            Err(error) => {
+                let errors = error.details().cloned().unwrap_or_default();
+                details.insert(index.to_string(), errors);
+            }
+        }
+    }
+    if !details.is_empty() {
+        return Err(ErrorResponse::validation_details(Value::Object(details)));
+    }
+
+    store.events.extend(events.iter().cloned());
+    let acknowledgments: Vec<Value> = events
+        .into_iter()
+        .zip(inputs)
+        .map(|(event, input)| acknowledgment(event, input))
+        .collect();
+
+    Ok(Json(json!({ "events": acknowledgments })))
+}
+
+fn build_event(store: &Store, input: &CreateEventInput) -> Result<Event, ErrorResponse> {
+    if store
+        .events
+        .iter()
+        .any(|e| e.transaction_id == input.transaction_id)
+    {
+        return Err(ErrorResponse::validation(
+            "transaction_id",
+            "value_already_exist",
+        ));
+    }
+
+    let subscription = input
+        .external_subscription_id
+        .as_deref()
+        .and_then(|id| store.subscription(id));
+    let customer_id = input
+        .external_customer_id
+        .as_deref()
+        .and_then(|id| store.customer(id))
+        .and_then(|c| c.lago_id)
+        .or(subscription.map(|s| s.lago_customer_id));
+
+    let now = Utc::now();
+    let timestamp = match input.timestamp {
+        Some(seconds) => DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| ErrorResponse::validation("timestamp", "invalid_format"))?,
+        None => now,
+    };
+
+    Ok(Event {
+        lago_id: Uuid::new_v4().to_string(),
+        transaction_id: input.transaction_id.clone(),
+        lago_customer_id: customer_id,
+        code: input.code.clone(),
+        timestamp,
+        lago_subscription_id: subscription.map(|s| s.lago_id),
+        external_subscription_id: input.external_subscription_id.clone(),
+        created_at: now,
+        precise_total_amount_cents: input.precise_total_amount_cents.map(|c| c.to_string()),
+        properties: input.properties.clone(),
+    })
+}
+
+/// Serializes an ingested event with the external customer ID it was sent with.
+fn acknowledgment(event: Event, input: CreateEventInput) -> Value {
+    let mut value = json!(event);
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert(
+            "external_customer_id".to_string(),
+            json!(input.external_customer_id),
+        );
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::requests::event::{
+        CreateEventRequest, CreateEventsBatchRequest, GetEventRequest, ListEventsRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    fn event(transaction_id: &str) -> CreateEventInput {
+        CreateEventInput::for_customer(
+            transaction_id.to_string(),
+            "cust_1".to_string(),
+            "api_calls".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn ingests_and_lists_events() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+
+        let created = client
+            .create_event(CreateEventRequest::new(
+                event("tx_1").with_timestamp(1_700_000_000),
+            ))
+            .await
+            .unwrap()
+            .event;
+        assert_eq!(created.external_customer_id.as_deref(), Some("cust_1"));
+
+        let result = client
+            .create_event(CreateEventRequest::new(event("tx_1")))
+            .await;
+        assert!(
+            matches!(&result, Err(e) if e.field_errors("transaction_id") == ["value_already_exist"])
+        );
+
+        let fetched = client
+            .get_event(GetEventRequest::new("tx_1".to_string()))
+            .await
+            .unwrap()
+            .event;
+        assert_eq!(fetched.timestamp.timestamp(), 1_700_000_000);
+
+        let request = ListEventsRequest::new().with_timestamp_from("2024-01-01".to_string());
+        let events = client.list_events(Some(request)).await.unwrap();
+        assert!(events.events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_whole_batches() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+        client
+            .create_event(CreateEventRequest::new(event("tx_1")))
+            .await
+            .unwrap();
+
+        let request = CreateEventsBatchRequest::new(vec![event("tx_2"), event("tx_1")]);
+        let response = client.create_events_batch(request).await.unwrap();
+        assert_eq!(response.failed_transaction_ids(), ["tx_2", "tx_1"]);
+        let error = response.chunks[0].error.as_ref().unwrap();
+        assert_eq!(
+            error.field_errors("1.transaction_id"),
+            ["value_already_exist"]
+        );
+        assert_eq!(lago.events().len(), 1);
+
+        let request = CreateEventsBatchRequest::new(vec![event("tx_2"), event("tx_3")]);
+        let response = client.create_events_batch(request).await.unwrap();
+        assert!(response.is_success());
+        assert_eq!(response.events().count(), 2);
+        assert_eq!(lago.events().len(), 3);
+    }
+}
@@ -0,0 +1,377 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{
+    Fee, FeeItem, FeePaymentStatus, Invoice, InvoiceMetadata, InvoicePaymentStatus, InvoiceStatus,
+    InvoiceType,
+};
+use lago_types::requests::invoice::{CreateInvoiceInput, UpdateInvoiceInput};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/invoices", get(list_invoices).post(create_invoice))
+        .route("/invoices/{lago_id}", get(get_invoice).put(update_invoice))
+        .route("/invoices/{lago_id}/refresh", put(refresh_invoice))
+        .route("/invoices/{lago_id}/download", post(download_invoice))
+        .route("/invoices/{lago_id}/retry", post(retry_invoice))
+        .route(
+            "/invoices/{lago_id}/retry_payment",
+            post(retry_invoice_payment),
+        )
+        .route("/invoices/{lago_id}/void", post(void_invoice))
+        .route(
+            "/customers/{external_customer_id}/invoices",
+            get(list_customer_invoices),
+        )
+}
+
+async fn list_invoices(State(store): State<SharedStore>, RawQuery(query): RawQuery) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+    let customer_id = query.get("external_customer_id");
+
+    Ok(Json(
+        query.paginate(
+            "invoices",
+            store
+                .invoices
+                .iter()
+                .rev()
+                .filter(|i| customer_id.is_none_or(|id| invoice_customer_id(i) == Some(id))),
+        ),
+    ))
+}
+
+async fn list_customer_invoices(
+    State(store): State<SharedStore>,
+    Path(external_customer_id): Path<String>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let store = lock(&store);
+    if store.customer(&external_customer_id).is_none() {
+        return Err(ErrorResponse::not_found("customer"));
+    }
+    let query = Query::parse(query.as_deref());
+
+    Ok(Json(
+        query.paginate(
+            "invoices",
+            store
+                .invoices
+                .iter()
+                .rev()
+                .filter(|i| invoice_customer_id(i) == Some(external_customer_id.as_str())),
+        ),
+    ))
+}
+
+async fn get_invoice(State(store): State<SharedStore>, Path(lago_id): Path<String>) -> ApiResult {
+    let store = lock(&store);
+    let invoice = store
+        .invoice(&lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("invoice"))?;
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+/// Creates a finalized one-off invoice from add-on fees.
+async fn create_invoice(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreateInvoiceInput = parse_input(&body, "invoice")?;
+    if input.fees.is_empty() {
+        return Err(ErrorResponse::validation("fees", "value_is_mandatory"));
+    }
+
+    let mut store = lock(&store);
+    let customer = store
+        .customer(&input.external_customer_id)
+        .cloned()
+        .ok_or_else(|| ErrorResponse::not_found("customer"))?;
+
+    let lago_id = Uuid::new_v4();
+    let now = Utc::now();
+    let fees: Vec<Fee> = input
+        .fees
+        .into_iter()
+        .map(|fee| {
+            let amount_cents =
+                (fee.units * fee.unit_amount_cents.unwrap_or(0) as f64).round() as i64;
+            Fee {
+                lago_id: Uuid::new_v4(),
+                lago_charge_id: None,
+                lago_invoice_id: Some(lago_id),
+                lago_subscription_id: None,
+                lago_customer_id: customer.lago_id,
+                external_customer_id: customer.external_id.clone(),
+                external_subscription_id: None,
+                amount_cents,
+                amount_currency: input.currency.clone(),
+                precise_amount: None,
+                total_amount_cents: amount_cents,
+                total_amount_currency: input.currency.clone(),
+                precise_total_amount: None,
+                taxes_amount_cents: 0,
+                taxes_precise_amount: None,
+                taxes_rate: 0.0,
+                units: fee.units.to_string(),
+                precise_unit_amount: None,
+                total_aggregated_units: None,
+                events_count: None,
+                payment_status: FeePaymentStatus::Pending,
+                pay_in_advance: Some(false),
+                invoiceable: Some(true),
+                from_date: None,
+                to_date: None,
+                created_at: now,
+                succeeded_at: None,
+                failed_at: None,
+                refunded_at: None,
+                item: Some(FeeItem {
+                    item_type: "add_on".to_string(),
+                    name: fee.add_on_code.clone(),
+                    code: fee.add_on_code,
+                    description: fee.description,
+                }),
+            }
+        })
+        .collect();
+
+    let customer_id = customer.external_id.as_deref();
+    let sequence = store
+        .invoices
+        .iter()
+        .filter(|i| invoice_customer_id(i) == customer_id)
+        .count()
+        + 1;
+    let total: i64 = fees.iter().map(|f| f.amount_cents).sum();
+
+    let invoice = Invoice {
+        lago_id: Some(lago_id),
+        billing_entity_code: Some(customer.billing_entity_code.clone()),
+        sequential_id: Some(sequence as i32),
+        number: format!(
+            "{}-{sequence:03}",
+            customer.slug.as_deref().unwrap_or("LAG")
+        ),
+        issuing_date: now.date_naive().to_string(),
+        invoice_type: InvoiceType::OneOff,
+        status: InvoiceStatus::Finalized,
+        payment_status: InvoicePaymentStatus::Pending,
+        currency: input.currency,
+        fees_amount_cents: total,
+        coupons_amount_cents: 0,
+        credit_notes_amount_cents: 0,
+        sub_total_excluding_taxes_amount_cents: total,
+        taxes_amount_cents: 0,
+        sub_total_including_taxes_amount_cents: total,
+        prepaid_credit_amount_cents: 0,
+        progressive_billing_credit_amount_cents: 0,
+        total_amount_cents: total,
+        version_number: Some(4),
+        created_at: now,
+        updated_at: now,
+        payment_dispute_lost_at: None,
+        payment_due_date: Some(now.date_naive().to_string()),
+        payment_overdue: Some(false),
+        net_payment_term: Some(0),
+        self_billed: Some(false),
+        file_url: None,
+        customer: Some(customer),
+        billing_periods: Some(Vec::new()),
+        metadata: Some(Vec::new()),
+        applied_taxes: Vec::new(),
+        applied_usage_thresholds: Some(Vec::new()),
+        fees: Some(fees),
+    };
+    store.invoices.push(invoice.clone());
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+async fn update_invoice(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let input: UpdateInvoiceInput = parse_input(&body, "invoice")?;
+    let payment_status = input
+        .payment_status
+        .map(|status| {
+            serde_json::from_value::<InvoicePaymentStatus>(json!(status))
+                .map_err(|_| ErrorResponse::validation("payment_status", "value_is_invalid"))
+        })
+        .transpose()?;
+
+    let mut store = lock(&store);
+    let invoice = invoice_mut(&mut store.invoices, &lago_id)?;
+    if let Some(payment_status) = payment_status {
+        invoice.payment_status = payment_status;
+    }
+    if let Some(metadata) = input.metadata {
+        let existing = invoice.metadata.take().unwrap_or_default();
+        invoice.metadata = Some(
+            metadata
+                .into_iter()
+                .map(|m| {
+                    let previous = existing
+                        .iter()
+                        .find(|e| m.id.as_deref() == Some(e.lago_id.to_string().as_str()));
+                    InvoiceMetadata {
+                        lago_id: previous.map_or_else(Uuid::new_v4, |e| e.lago_id),
+                        created_at: previous.map_or_else(Utc::now, |e| e.created_at),
+                        key: m.key,
+                        value: m.value,
+                    }
+                })
+                .collect(),
+        );
+    }
+    invoice.updated_at = Utc::now();
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+async fn refresh_invoice(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let invoice = store
+        .invoice(&lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("invoice"))?;
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+async fn download_invoice(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+) -> ApiResult {
+    let mut store = lock(&store);
+    let invoice = invoice_mut(&mut store.invoices, &lago_id)?;
+    invoice.file_url = Some(format!("https://fake.getlago.com/invoices/{lago_id}.pdf"));
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+/// Retries the finalization of a failed invoice.
+async fn retry_invoice(State(store): State<SharedStore>, Path(lago_id): Path<String>) -> ApiResult {
+    let mut store = lock(&store);
+    let invoice = invoice_mut(&mut store.invoices, &lago_id)?;
+    if !matches!(invoice.status, InvoiceStatus::Failed) {
+        return Err(ErrorResponse::method_not_allowed("invalid_status"));
+    }
+    invoice.status = InvoiceStatus::Finalized;
+    invoice.updated_at = Utc::now();
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+async fn retry_invoice_payment(
+    State(store): State<SharedStore>,
+    Path(lago_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let invoice = store
+        .invoice(&lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("invoice"))?;
+    if matches!(invoice.payment_status, InvoicePaymentStatus::Succeeded) {
+        return Err(ErrorResponse::method_not_allowed("invalid_status"));
+    }
+
+    Ok(Json(json!({})))
+}
+
+async fn void_invoice(State(store): State<SharedStore>, Path(lago_id): Path<String>) -> ApiResult {
+    let mut store = lock(&store);
+    let invoice = invoice_mut(&mut store.invoices, &lago_id)?;
+    let voidable = matches!(invoice.status, InvoiceStatus::Finalized)
+        && !matches!(invoice.payment_status, InvoicePaymentStatus::Succeeded);
+    if !voidable {
+        return Err(ErrorResponse::method_not_allowed("not_voidable"));
+    }
+    invoice.status = InvoiceStatus::Voided;
+    invoice.updated_at = Utc::now();
+
+    Ok(Json(json!({ "invoice": invoice })))
+}
+
+/// Returns the external ID of the invoiced customer.
+pub(crate) fn invoice_customer_id(invoice: &Invoice) -> Option<&str> {
+    invoice
+        .customer
+        .as_ref()
+        .and_then(|c| c.external_id.as_deref())
+}
+
+pub(crate) fn invoice_mut<'a>(
+    invoices: &'a mut [Invoice],
+    lago_id: &str,
+) -> Result<&'a mut Invoice, ErrorResponse> {
+    invoices
+        .iter_mut()
+        .find(|i| i.lago_id.is_some_and(|id| id.to_string() == lago_id))
+        .ok_or_else(|| ErrorResponse::not_found("invoice"))
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::error::LagoError;
+    use lago_types::filters::invoice::InvoiceFilters;
+    use lago_types::requests::customer::{CreateCustomerInput, CreateCustomerRequest};
+    use lago_types::requests::invoice::{
+        CreateInvoiceFeeInput, CreateInvoiceRequest, ListInvoicesRequest, VoidInvoiceRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    #[tokio::test]
+    async fn creates_and_voids_one_off_invoices() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+        for id in ["cust_1", "cust_2"] {
+            client
+                .create_customer(CreateCustomerRequest::new(CreateCustomerInput::new(
+                    id.to_string(),
+                )))
+                .await
+                .unwrap();
+        }
+
+        let fees = vec![
+            CreateInvoiceFeeInput::new("setup".to_string(), 2.0).with_unit_amount_cents(1_500),
+        ];
+        let input = CreateInvoiceInput::new("cust_1".to_string(), "EUR".to_string(), fees);
+        let invoice = client
+            .create_invoice(CreateInvoiceRequest::new(input))
+            .await
+            .unwrap()
+            .invoice;
+        assert_eq!(invoice.total_amount_cents, 3_000);
+        assert_eq!(invoice.number, "LAG-TEST-001-001");
+
+        let request = ListInvoicesRequest::new()
+            .with_filters(InvoiceFilters::new().with_customer_id("cust_2".to_string()));
+        let invoices = client.list_invoices(Some(request)).await.unwrap();
+        assert!(invoices.invoices.is_empty());
+
+        let lago_id = invoice.lago_id.unwrap().to_string();
+        let invoice = client
+            .void_invoice(VoidInvoiceRequest::new(lago_id.clone()))
+            .await
+            .unwrap()
+            .invoice;
+        assert!(matches!(invoice.status, InvoiceStatus::Voided));
+
+        let result = client.void_invoice(VoidInvoiceRequest::new(lago_id)).await;
+        assert!(matches!(result, Err(LagoError::Api { status: 405, .. })));
+    }
+}
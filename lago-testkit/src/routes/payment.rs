@@ -0,0 +1,181 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{InvoicePaymentStatus, PayableType, Payment, PaymentStatus, PaymentType};
+use lago_types::requests::payment::CreatePaymentInput;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::routes::invoice::invoice_mut;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/payments", get(list_payments).post(create_payment))
+        .route("/payments/{lago_id}", get(get_payment))
+        .route(
+            "/customers/{external_customer_id}/payments",
+            get(list_customer_payments),
+        )
+}
+
+async fn list_payments(State(store): State<SharedStore>, RawQuery(query): RawQuery) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+
+    Ok(Json(paginate(&query, &store.payments)))
+}
+
+async fn list_customer_payments(
+    State(store): State<SharedStore>,
+    Path(external_customer_id): Path<String>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let store = lock(&store);
+    if store.customer(&external_customer_id).is_none() {
+        return Err(ErrorResponse::not_found("customer"));
+    }
+    let query = Query::parse(query.as_deref())
+        .without("external_customer_id")
+        .with_default("external_customer_id", &external_customer_id);
+
+    Ok(Json(paginate(&query, &store.payments)))
+}
+
+async fn get_payment(State(store): State<SharedStore>, Path(lago_id): Path<String>) -> ApiResult {
+    let store = lock(&store);
+    let payment = store
+        .payments
+        .iter()
+        .find(|p| p.lago_id.to_string() == lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("payment"))?;
+
+    Ok(Json(json!({ "payment": payment })))
+}
+
+/// Records a manual payment, marking the invoice as paid once fully covered.
+async fn create_payment(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreatePaymentInput = parse_input(&body, "payment")?;
+    if input.amount_cents <= 0 {
+        return Err(ErrorResponse::validation("amount_cents", "invalid_value"));
+    }
+
+    let mut store = lock(&store);
+    let paid: i64 = store
+        .payments
+        .iter()
+        .filter(|p| p.lago_payable_id.to_string() == input.invoice_id)
+        .map(|p| p.amount_cents)
+        .sum();
+    let invoice = invoice_mut(&mut store.invoices, &input.invoice_id)?;
+    let remaining = invoice.total_amount_cents - invoice.credit_notes_amount_cents - paid;
+    if input.amount_cents > remaining {
+        return Err(ErrorResponse::validation("amount_cents", "invalid_amount"));
+    }
+    if input.amount_cents == remaining {
+        invoice.payment_status = InvoicePaymentStatus::Succeeded;
+    }
+
+    let invoice_id = invoice.lago_id.unwrap_or_default();
+    let customer = invoice.customer.as_ref();
+    let payment = Payment {
+        lago_id: Uuid::new_v4(),
+        lago_customer_id: customer.and_then(|c| c.lago_id).unwrap_or_default(),
+        external_customer_id: customer
+            .and_then(|c| c.external_id.clone())
+            .unwrap_or_default(),
+        invoice_ids: vec![invoice_id],
+        lago_payable_id: invoice_id,
+        payable_type: PayableType::Invoice,
+        amount_cents: input.amount_cents,
+        amount_currency: invoice.currency.clone(),
+        status: "succeeded".to_string(),
+        payment_status: PaymentStatus::Succeeded,
+        payment_type: PaymentType::Manual,
+        reference: Some(input.reference),
+        payment_provider_code: None,
+        payment_provider_type: None,
+        external_payment_id: None,
+        provider_payment_id: None,
+        provider_customer_id: None,
+        next_action: None,
+        created_at: Utc::now(),
+    };
+    store.payments.push(payment.clone());
+
+    Ok(Json(json!({ "payment": payment })))
+}
+
+/// Paginates payments, matching `invoice_id` against their invoice IDs.
+fn paginate(query: &Query, payments: &[Payment]) -> serde_json::Value {
+    let invoice_id = query.get("invoice_id");
+
+    query.paginate(
+        "payments",
+        payments.iter().rev().filter(|p| {
+            invoice_id.is_none_or(|id| p.invoice_ids.iter().any(|i| i.to_string() == id))
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::requests::customer::{CreateCustomerInput, CreateCustomerRequest};
+    use lago_types::requests::invoice::{
+        CreateInvoiceFeeInput, CreateInvoiceInput, CreateInvoiceRequest,
+    };
+    use lago_types::requests::payment::{CreatePaymentRequest, ListCustomerPaymentsRequest};
+
+    use super::*;
+    use crate::FakeLago;
+
+    #[tokio::test]
+    async fn records_manual_payments() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+        client
+            .create_customer(CreateCustomerRequest::new(CreateCustomerInput::new(
+                "cust_1".to_string(),
+            )))
+            .await
+            .unwrap();
+        let fees = vec![
+            CreateInvoiceFeeInput::new("setup".to_string(), 1.0).with_unit_amount_cents(1_000),
+        ];
+        let invoice = client
+            .create_invoice(CreateInvoiceRequest::new(CreateInvoiceInput::new(
+                "cust_1".to_string(),
+                "EUR".to_string(),
+                fees,
+            )))
+            .await
+            .unwrap()
+            .invoice;
+        let invoice_id = invoice.lago_id.unwrap();
+
+        let pay = |amount| {
+            CreatePaymentRequest::new(CreatePaymentInput::new(
+                invoice_id.to_string(),
+                amount,
+                "wire".to_string(),
+            ))
+        };
+        client.create_payment(pay(400)).await.unwrap();
+        let result = client.create_payment(pay(700)).await;
+        assert!(matches!(&result, Err(e) if e.field_errors("amount_cents") == ["invalid_amount"]));
+        client.create_payment(pay(600)).await.unwrap();
+        assert!(matches!(
+            lago.invoices()[0].payment_status,
+            InvoicePaymentStatus::Succeeded
+        ));
+
+        let request =
+            ListCustomerPaymentsRequest::new("cust_1".to_string()).with_invoice_id(invoice_id);
+        let payments = client.list_customer_payments(request).await.unwrap();
+        assert_eq!(payments.payments.len(), 2);
+    }
+}
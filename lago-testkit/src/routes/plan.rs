@@ -0,0 +1,282 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{Plan, PlanCharge, PlanMinimumCommitment, UsageThreshold};
+use lago_types::requests::plan::{
+    CreateMinimumCommitmentInput, CreatePlanChargeInput, CreatePlanInput,
+    CreateUsageThresholdInput, UpdatePlanInput,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/plans", get(list_plans).post(create_plan))
+        .route(
+            "/plans/{code}",
+            get(get_plan).put(update_plan).delete(delete_plan),
+        )
+}
+
+async fn list_plans(State(store): State<SharedStore>, RawQuery(query): RawQuery) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref());
+
+    Ok(Json(query.paginate("plans", store.plans.iter().rev())))
+}
+
+async fn get_plan(State(store): State<SharedStore>, Path(code): Path<String>) -> ApiResult {
+    let store = lock(&store);
+    let plan = store
+        .plan(&code)
+        .ok_or_else(|| ErrorResponse::not_found("plan"))?;
+
+    Ok(Json(json!({ "plan": plan })))
+}
+
+async fn create_plan(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreatePlanInput = parse_input(&body, "plan")?;
+
+    let mut store = lock(&store);
+    if store.plan(&input.code).is_some() {
+        return Err(ErrorResponse::validation("code", "value_already_exist"));
+    }
+
+    let plan = Plan {
+        lago_id: Uuid::new_v4(),
+        name: input.name,
+        invoice_display_name: input.invoice_display_name,
+        created_at: Utc::now(),
+        minimum_commitment: input
+            .minimum_commitment
+            .map(|c| minimum_commitment(&input.code, c)),
+        code: input.code,
+        interval: input.interval,
+        description: input.description,
+        amount_cents: input.amount_cents,
+        amount_currency: input.amount_currency,
+        trial_period: input.trial_period,
+        pay_in_advance: input.pay_in_advance.unwrap_or(false),
+        bill_charges_monthly: input.bill_charges_monthly,
+        active_subscriptions_count: Some(0),
+        draft_invoices_count: Some(0),
+        parent_id: None,
+        charges: Some(
+            input
+                .charges
+                .unwrap_or_default()
+                .into_iter()
+                .map(charge)
+                .collect(),
+        ),
+        fixed_charges: Some(Vec::new()),
+        taxes: Some(Vec::new()),
+        usage_thresholds: Some(
+            input
+                .usage_thresholds
+                .unwrap_or_default()
+                .into_iter()
+                .map(usage_threshold)
+                .collect(),
+        ),
+    };
+    store.plans.push(plan.clone());
+
+    Ok(Json(json!({ "plan": plan })))
+}
+
+async fn update_plan(
+    State(store): State<SharedStore>,
+    Path(code): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let input: UpdatePlanInput = parse_input(&body, "plan")?;
+
+    let mut store = lock(&store);
+    if let Some(new_code) = input.code.as_deref()
+        && new_code != code
+        && store.plan(new_code).is_some()
+    {
+        return Err(ErrorResponse::validation("code", "value_already_exist"));
+    }
+    let plan = store
+        .plans
+        .iter_mut()
+        .find(|p| p.code == code)
+        .ok_or_else(|| ErrorResponse::not_found("plan"))?;
+
+    macro_rules! merge {
+        ($($field:ident),*) => {
+            $(if let Some(value) = input.$field {
+                plan.$field = value;
+            })*
+        };
+    }
+    merge!(
+        name,
+        code,
+        interval,
+        amount_cents,
+        amount_currency,
+        pay_in_advance
+    );
+
+    if input.invoice_display_name.is_some() {
+        plan.invoice_display_name = input.invoice_display_name;
+    }
+    if input.description.is_some() {
+        plan.description = input.description;
+    }
+    if input.trial_period.is_some() {
+        plan.trial_period = input.trial_period;
+    }
+    if input.bill_charges_monthly.is_some() {
+        plan.bill_charges_monthly = input.bill_charges_monthly;
+    }
+    if let Some(charges) = input.charges {
+        plan.charges = Some(charges.into_iter().map(charge).collect());
+    }
+    if let Some(commitment) = input.minimum_commitment {
+        plan.minimum_commitment = Some(minimum_commitment(&plan.code, commitment));
+    }
+    if let Some(thresholds) = input.usage_thresholds {
+        plan.usage_thresholds = Some(thresholds.into_iter().map(usage_threshold).collect());
+    }
+
+    Ok(Json(json!({ "plan": plan })))
+}
+
+async fn delete_plan(State(store): State<SharedStore>, Path(code): Path<String>) -> ApiResult {
+    let mut store = lock(&store);
+    let index = store
+        .plans
+        .iter()
+        .position(|p| p.code == code)
+        .ok_or_else(|| ErrorResponse::not_found("plan"))?;
+    let plan = store.plans.remove(index);
+
+    Ok(Json(json!({ "plan": plan })))
+}
+
+fn charge(input: CreatePlanChargeInput) -> PlanCharge {
+    let billable_metric_id = Uuid::parse_str(&input.billable_metric_id).ok();
+
+    PlanCharge {
+        lago_id: Some(Uuid::new_v4()),
+        lago_billable_metric_id: billable_metric_id,
+        billable_metric_id,
+        billable_metric_code: None,
+        code: None,
+        created_at: Some(Utc::now()),
+        charge_model: input.charge_model,
+        invoiceable: input.invoiceable,
+        invoice_display_name: input.invoice_display_name,
+        pay_in_advance: input.pay_in_advance,
+        prorated: input.prorated,
+        min_amount_cents: input.min_amount_cents,
+        properties: input.properties,
+        tax_codes: input.tax_codes,
+        taxes: None,
+        filters: None,
+        regroup_paid_fees: input.regroup_paid_fees,
+        applied_pricing_unit: None,
+        accepts_target_wallet: None,
+    }
+}
+
+fn minimum_commitment(
+    plan_code: &str,
+    input: CreateMinimumCommitmentInput,
+) -> PlanMinimumCommitment {
+    let now = Utc::now();
+
+    PlanMinimumCommitment {
+        lago_id: Some(Uuid::new_v4()),
+        plan_code: Some(plan_code.to_string()),
+        amount_cents: Some(input.amount_cents),
+        invoice_display_name: input.invoice_display_name,
+        created_at: Some(now),
+        updated_at: Some(now),
+        tax_codes: input.tax_codes,
+        taxes: None,
+    }
+}
+
+fn usage_threshold(input: CreateUsageThresholdInput) -> UsageThreshold {
+    let now = Utc::now();
+
+    UsageThreshold {
+        lago_id: Uuid::new_v4(),
+        amount_cents: input.amount_cents,
+        recurring: input.recurring.unwrap_or(false),
+        created_at: now,
+        updated_at: now,
+        threshold_display_name: input.threshold_display_name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::error::LagoError;
+    use lago_types::models::PlanInterval;
+    use lago_types::requests::plan::{
+        CreatePlanRequest, DeletePlanRequest, GetPlanRequest, UpdatePlanRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    fn plan_input(code: &str) -> CreatePlanInput {
+        CreatePlanInput::new(
+            "Startup".to_string(),
+            code.to_string(),
+            PlanInterval::Monthly,
+            10_000,
+            "USD".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn manages_plans() {
+        let lago = FakeLago::start().await;
+        let client = lago.client();
+
+        let plan = client
+            .create_plan(CreatePlanRequest::new(plan_input("startup")))
+            .await
+            .unwrap()
+            .plan;
+        assert_eq!(plan.code, "startup");
+
+        let result = client
+            .create_plan(CreatePlanRequest::new(plan_input("startup")))
+            .await;
+        assert!(
+            matches!(&result, Err(e) if e.field_errors("code") == ["value_already_exist"]),
+            "{result:?}"
+        );
+
+        let input = UpdatePlanInput::new().with_amount_cents(20_000);
+        let plan = client
+            .update_plan(UpdatePlanRequest::new("startup".to_string(), input))
+            .await
+            .unwrap()
+            .plan;
+        assert_eq!(plan.amount_cents, 20_000);
+
+        client
+            .delete_plan(DeletePlanRequest::new("startup".to_string()))
+            .await
+            .unwrap();
+        let result = client
+            .get_plan(GetPlanRequest::new("startup".to_string()))
+            .await;
+        assert!(matches!(result, Err(LagoError::NotFound { resource }) if resource == "plan"));
+    }
+}
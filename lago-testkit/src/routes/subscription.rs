@@ -0,0 +1,349 @@
+use axum::body::Bytes;
+use axum::extract::{Path, RawQuery, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::Utc;
+use lago_types::models::{
+    Plan, Subscription, SubscriptionBillingTime, SubscriptionPlan, SubscriptionStatus,
+};
+use lago_types::requests::subscription::{CreateSubscriptionInput, UpdateSubscriptionInput};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::error::{ApiResult, ErrorResponse, parse_input};
+use crate::query::Query;
+use crate::routes::parse_datetime;
+use crate::server::{SharedStore, lock};
+
+pub(crate) fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route(
+            "/subscriptions",
+            get(list_subscriptions).post(create_subscription),
+        )
+        .route(
+            "/subscriptions/{external_id}",
+            get(get_subscription)
+                .put(update_subscription)
+                .delete(delete_subscription),
+        )
+        .route(
+            "/customers/{external_customer_id}/subscriptions",
+            get(list_customer_subscriptions),
+        )
+}
+
+/// Lists subscriptions, only returning active ones unless `status[]` is set.
+async fn list_subscriptions(
+    State(store): State<SharedStore>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let store = lock(&store);
+    let query = Query::parse(query.as_deref()).with_default("status", "active");
+
+    Ok(Json(query.paginate(
+        "subscriptions",
+        store.subscriptions.iter().rev(),
+    )))
+}
+
+async fn list_customer_subscriptions(
+    State(store): State<SharedStore>,
+    Path(external_customer_id): Path<String>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let store = lock(&store);
+    if store.customer(&external_customer_id).is_none() {
+        return Err(ErrorResponse::not_found("customer"));
+    }
+    let query = Query::parse(query.as_deref()).with_default("status", "active");
+
+    Ok(Json(
+        query.paginate(
+            "subscriptions",
+            store
+                .subscriptions
+                .iter()
+                .rev()
+                .filter(|s| s.external_customer_id == external_customer_id),
+        ),
+    ))
+}
+
+async fn get_subscription(
+    State(store): State<SharedStore>,
+    Path(external_id): Path<String>,
+) -> ApiResult {
+    let store = lock(&store);
+    let subscription = store
+        .subscription(&external_id)
+        .ok_or_else(|| ErrorResponse::not_found("subscription"))?;
+
+    Ok(Json(json!({ "subscription": subscription })))
+}
+
+async fn create_subscription(State(store): State<SharedStore>, body: Bytes) -> ApiResult {
+    let input: CreateSubscriptionInput = parse_input(&body, "subscription")?;
+
+    let mut store = lock(&store);
+    let customer_id = store
+        .customer(&input.external_customer_id)
+        .and_then(|c| c.lago_id)
+        .ok_or_else(|| ErrorResponse::not_found("customer"))?;
+    let plan = store
+        .plan(&input.plan_code)
+        .ok_or_else(|| ErrorResponse::not_found("plan"))?;
+
+    let external_id = input
+        .external_id
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let already_active = store.subscriptions.iter().any(|s| {
+        s.external_id == external_id
+            && matches!(
+                s.status,
+                SubscriptionStatus::Active | SubscriptionStatus::Pending
+            )
+    });
+    if already_active {
+        return Err(ErrorResponse::validation(
+            "external_id",
+            "value_already_exist",
+        ));
+    }
+
+    let now = Utc::now();
+    let subscription_at = input
+        .subscription_at
+        .as_deref()
+        .map(|value| parse_datetime("subscription_at", value))
+        .transpose()?
+        .unwrap_or(now);
+    let ending_at = input
+        .ending_at
+        .as_deref()
+        .map(|value| parse_datetime("ending_at", value))
+        .transpose()?;
+    let status = if subscription_at > now {
+        SubscriptionStatus::Pending
+    } else {
+        SubscriptionStatus::Active
+    };
+
+    let subscription = Subscription {
+        lago_id: Uuid::new_v4(),
+        external_id,
+        lago_customer_id: customer_id,
+        external_customer_id: input.external_customer_id,
+        billing_time: input
+            .billing_time
+            .unwrap_or(SubscriptionBillingTime::Calendar),
+        name: input.name,
+        plan_code: plan.code.clone(),
+        started_at: (status == SubscriptionStatus::Active).then_some(subscription_at),
+        status,
+        created_at: now,
+        canceled_at: None,
+        ending_at,
+        subscription_at,
+        terminated_at: None,
+        previous_plan_code: None,
+        next_plan_code: None,
+        downgrade_plan_date: None,
+        trial_ended_at: None,
+        current_billing_period_started_at: None,
+        current_billing_period_ending_at: None,
+        plan: Some(subscription_plan(plan)),
+        cancellation_reason: None,
+        activated_at: None,
+        activation_rules: None,
+    };
+    store.subscriptions.push(subscription.clone());
+
+    Ok(Json(json!({ "subscription": subscription })))
+}
+
+async fn update_subscription(
+    State(store): State<SharedStore>,
+    Path(external_id): Path<String>,
+    body: Bytes,
+) -> ApiResult {
+    let input: UpdateSubscriptionInput = parse_input(&body, "subscription")?;
+
+    let mut store = lock(&store);
+    let plan = match input.plan_code.as_deref() {
+        Some(code) => Some(subscription_plan(
+            store
+                .plan(code)
+                .ok_or_else(|| ErrorResponse::not_found("plan"))?,
+        )),
+        None => None,
+    };
+    let subscription = store
+        .subscriptions
+        .iter_mut()
+        .rev()
+        .find(|s| s.external_id == external_id)
+        .ok_or_else(|| ErrorResponse::not_found("subscription"))?;
+
+    if input.name.is_some() {
+        subscription.name = input.name;
+    }
+    if let Some(ending_at) = input.ending_at.as_deref() {
+        subscription.ending_at = Some(parse_datetime("ending_at", ending_at)?);
+    }
+    if let Some(subscription_at) = input.subscription_at.as_deref() {
+        subscription.subscription_at = parse_datetime("subscription_at", subscription_at)?;
+    }
+    if let Some(plan) = plan
+        && plan.code != subscription.plan_code
+    {
+        subscription.previous_plan_code = Some(subscription.plan_code.clone());
+        subscription.plan_code = plan.code.clone();
+        subscription.plan = Some(plan);
+    }
+
+    Ok(Json(json!({ "subscription": subscription })))
+}
+
+/// Terminates the subscription, or cancels it when `status=pending` is given.
+async fn delete_subscription(
+    State(store): State<SharedStore>,
+    Path(external_id): Path<String>,
+    RawQuery(query): RawQuery,
+) -> ApiResult {
+    let query = Query::parse(query.as_deref());
+
+    let mut store = lock(&store);
+    let subscription = store
+        .subscriptions
+        .iter_mut()
+        .rev()
+        .find(|s| s.external_id == external_id)
+        .ok_or_else(|| ErrorResponse::not_found("subscription"))?;
+
+    let now = Utc::now();
+    if query.get("status") == Some("pending") {
+        if subscription.status != SubscriptionStatus::Pending {
+            return Err(ErrorResponse::not_found("subscription"));
+        }
+        subscription.status = SubscriptionStatus::Canceled;
+        subscription.canceled_at = Some(now);
+    } else {
+        subscription.status = SubscriptionStatus::Terminated;
+        subscription.terminated_at = Some(now);
+    }
+
+    Ok(Json(json!({ "subscription": subscription })))
+}
+
+fn subscription_plan(plan: &Plan) -> SubscriptionPlan {
+    let interval = match serde_json::to_value(&plan.interval) {
+        Ok(Value::String(interval)) => interval,
+        _ => String::new(),
+    };
+
+    SubscriptionPlan {
+        lago_id: plan.lago_id,
+        name: plan.name.clone(),
+        invoice_display_name: plan.invoice_display_name.clone(),
+        created_at: plan.created_at,
+        code: plan.code.clone(),
+        interval,
+        description: plan.description.clone(),
+        amount_cents: plan.amount_cents,
+        amount_currency: plan.amount_currency.clone(),
+        trial_period: plan.trial_period,
+        pay_in_advance: plan.pay_in_advance,
+        bill_charges_monthly: plan.bill_charges_monthly,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lago_types::error::LagoError;
+    use lago_types::filters::subscription::SubscriptionFilters;
+    use lago_types::models::PlanInterval;
+    use lago_types::requests::customer::{CreateCustomerInput, CreateCustomerRequest};
+    use lago_types::requests::plan::{CreatePlanInput, CreatePlanRequest};
+    use lago_types::requests::subscription::{
+        CreateSubscriptionRequest, DeleteSubscriptionRequest, ListSubscriptionsRequest,
+        UpdateSubscriptionRequest,
+    };
+
+    use super::*;
+    use crate::FakeLago;
+
+    async fn seed(lago: &FakeLago) {
+        let client = lago.client();
+        client
+            .create_customer(CreateCustomerRequest::new(CreateCustomerInput::new(
+                "cust_1".to_string(),
+            )))
+            .await
+            .unwrap();
+        for code in ["basic", "premium"] {
+            let input = CreatePlanInput::new(
+                code.to_string(),
+                code.to_string(),
+                PlanInterval::Monthly,
+                1_000,
+                "EUR".to_string(),
+            );
+            client
+                .create_plan(CreatePlanRequest::new(input))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn manages_the_subscription_lifecycle() {
+        let lago = FakeLago::start().await;
+        seed(&lago).await;
+        let client = lago.client();
+
+        let input = CreateSubscriptionInput::new("cust_1".to_string(), "basic".to_string())
+            .with_external_id("sub_1".to_string());
+        let subscription = client
+            .create_subscription(CreateSubscriptionRequest::new(input))
+            .await
+            .unwrap()
+            .subscription;
+        assert_eq!(subscription.status, SubscriptionStatus::Active);
+        assert_eq!(subscription.plan.unwrap().interval, "monthly");
+
+        let input = UpdateSubscriptionInput::new().with_plan_code("premium".to_string());
+        let subscription = client
+            .update_subscription(UpdateSubscriptionRequest::new("sub_1".to_string(), input))
+            .await
+            .unwrap()
+            .subscription;
+        assert_eq!(subscription.plan_code, "premium");
+        assert_eq!(subscription.previous_plan_code.as_deref(), Some("basic"));
+
+        client
+            .delete_subscription(DeleteSubscriptionRequest::new("sub_1".to_string()))
+            .await
+            .unwrap();
+        let active = client.list_subscriptions(None).await.unwrap();
+        assert!(active.subscriptions.is_empty());
+
+        let request = ListSubscriptionsRequest::new()
+            .with_filters(SubscriptionFilters::new().with_status(SubscriptionStatus::Terminated));
+        let terminated = client.list_subscriptions(Some(request)).await.unwrap();
+        assert_eq!(terminated.subscriptions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_plans() {
+        let lago = FakeLago::start().await;
+        seed(&lago).await;
+
+        let input = CreateSubscriptionInput::new("cust_1".to_string(), "missing".to_string());
+        let result = lago
+            .client()
+            .create_subscription(CreateSubscriptionRequest::new(input))
+            .await;
+        assert!(matches!(result, Err(LagoError::NotFound { resource }) if resource == "plan"));
+    }
+}
@@ -0,0 +1,202 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use axum::Router;
+use axum::extract::Request;
+use axum::http::header::AUTHORIZATION;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use lago_client::{Config, ConfigBuilder, Credentials, LagoClient, Region};
+use lago_types::models::{
+    AppliedCoupon, Coupon, CreditNote, Customer, Event, Invoice, Payment, Plan, Subscription,
+};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+use crate::error::ErrorResponse;
+use crate::routes;
+use crate::store::Store;
+
+/// API key accepted by a server started with [`FakeLago::start`].
+pub const DEFAULT_API_KEY: &str = "lago-testkit";
+
+/// Store shared between the server and the [`FakeLago`] handle.
+pub(crate) type SharedStore = Arc<Mutex<Store>>;
+
+/// A fake Lago API server running in the current Tokio runtime.
+///
+/// The API is served under `/api/v1` on a random local port. The server stops
+/// when the handle is dropped.
+pub struct FakeLago {
+    addr: SocketAddr,
+    api_key: String,
+    store: SharedStore,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl FakeLago {
+    /// Starts a server accepting [`DEFAULT_API_KEY`]
+    ///
+    /// # Returns
+    /// A handle to the running server
+    pub async fn start() -> Self {
+        Self::start_with_api_key(DEFAULT_API_KEY).await
+    }
+
+    /// Starts a server accepting the given API key
+    ///
+    /// Requests with another key are rejected with a 401 response.
+    ///
+    /// # Arguments
+    /// * `api_key` - The API key expected in the `Authorization` header
+    ///
+    /// # Returns
+    /// A handle to the running server
+    pub async fn start_with_api_key(api_key: impl Into<String>) -> Self {
+        let api_key = api_key.into();
+        let store = SharedStore::default();
+
+        let expected = format!("Bearer {api_key}");
+        let app = Router::new()
+            .nest("/api/v1", routes::router(store.clone()))
+            .layer(middleware::from_fn(move |request: Request, next: Next| {
+                let authorized = request
+                    .headers()
+                    .get(AUTHORIZATION)
+                    .is_some_and(|value| value.as_bytes() == expected.as_bytes());
+                async move { authorize(authorized, request, next).await }
+            }));
+
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind fake Lago server");
+        let addr = listener
+            .local_addr()
+            .expect("Failed to read fake Lago server address");
+
+        let (shutdown, signal) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app)
+                .with_graceful_shutdown(async move {
+                    let _ = signal.await;
+                })
+                .await;
+        });
+
+        Self {
+            addr,
+            api_key,
+            store,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// Returns the API endpoint, to use with `Region::Custom`.
+    pub fn url(&self) -> String {
+        format!("http://{}/api/v1", self.addr)
+    }
+
+    /// Returns the API key accepted by the server.
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Returns a configuration builder pointing at the server
+    ///
+    /// Use it to customize the client (retries, transport, ...) before building it.
+    ///
+    /// # Returns
+    /// A `ConfigBuilder` with the region and credentials set
+    pub fn config(&self) -> ConfigBuilder {
+        Config::builder()
+            .region(Region::Custom(self.url()))
+            .credentials(Credentials::new(self.api_key.clone()))
+    }
+
+    /// Returns a client talking to the server.
+    pub fn client(&self) -> LagoClient {
+        LagoClient::new(self.config().build())
+    }
+
+    /// Runs `f` with exclusive access to the store
+    ///
+    /// # Arguments
+    /// * `f` - Closure inspecting or seeding the store
+    ///
+    /// # Returns
+    /// The value returned by `f`
+    pub fn with_store<R>(&self, f: impl FnOnce(&mut Store) -> R) -> R {
+        f(&mut lock(&self.store))
+    }
+
+    /// Clears every collection of the store.
+    pub fn reset(&self) {
+        *lock(&self.store) = Store::default();
+    }
+
+    /// Returns the customers, in creation order.
+    pub fn customers(&self) -> Vec<Customer> {
+        lock(&self.store).customers.clone()
+    }
+
+    /// Returns the plans, in creation order.
+    pub fn plans(&self) -> Vec<Plan> {
+        lock(&self.store).plans.clone()
+    }
+
+    /// Returns the subscriptions, in creation order.
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        lock(&self.store).subscriptions.clone()
+    }
+
+    /// Returns the ingested events, in creation order.
+    pub fn events(&self) -> Vec<Event> {
+        lock(&self.store).events.clone()
+    }
+
+    /// Returns the invoices, in creation order.
+    pub fn invoices(&self) -> Vec<Invoice> {
+        lock(&self.store).invoices.clone()
+    }
+
+    /// Returns the coupons, in creation order.
+    pub fn coupons(&self) -> Vec<Coupon> {
+        lock(&self.store).coupons.clone()
+    }
+
+    /// Returns the applied coupons, in creation order.
+    pub fn applied_coupons(&self) -> Vec<AppliedCoupon> {
+        lock(&self.store).applied_coupons.clone()
+    }
+
+    /// Returns the credit notes, in creation order.
+    pub fn credit_notes(&self) -> Vec<CreditNote> {
+        lock(&self.store).credit_notes.clone()
+    }
+
+    /// Returns the payments, in creation order.
+    pub fn payments(&self) -> Vec<Payment> {
+        lock(&self.store).payments.clone()
+    }
+}
+
+impl Drop for FakeLago {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+async fn authorize(authorized: bool, request: Request, next: Next) -> Response {
+    if authorized {
+        next.run(request).await
+    } else {
+        ErrorResponse::unauthorized().into_response()
+    }
+}
+
+/// Locks the store, recovering it if a handler panicked while holding it.
+pub(crate) fn lock(store: &SharedStore) -> MutexGuard<'_, Store> {
+    store.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,55 @@
+use lago_types::models::{
+    AppliedCoupon, Coupon, CreditNote, Customer, Event, Invoice, Payment, Plan, Subscription,
+};
+
+/// State of a [`FakeLago`](crate::FakeLago) server.
+///
+/// Collections are kept in creation order; list endpoints return the most
+/// recent items first, like Lago. Tests can inspect or seed the store through
+/// [`FakeLago::with_store`](crate::FakeLago::with_store).
+#[derive(Debug, Clone, Default)]
+pub struct Store {
+    pub customers: Vec<Customer>,
+    pub plans: Vec<Plan>,
+    pub subscriptions: Vec<Subscription>,
+    pub events: Vec<Event>,
+    pub invoices: Vec<Invoice>,
+    pub coupons: Vec<Coupon>,
+    pub applied_coupons: Vec<AppliedCoupon>,
+    pub credit_notes: Vec<CreditNote>,
+    pub payments: Vec<Payment>,
+}
+
+impl Store {
+    /// Finds a customer by external ID.
+    pub fn customer(&self, external_id: &str) -> Option<&Customer> {
+        self.customers
+            .iter()
+            .find(|c| c.external_id.as_deref() == Some(external_id))
+    }
+
+    /// Finds a plan by code.
+    pub fn plan(&self, code: &str) -> Option<&Plan> {
+        self.plans.iter().find(|p| p.code == code)
+    }
+
+    /// Finds the most recent subscription with the given external ID.
+    pub fn subscription(&self, external_id: &str) -> Option<&Subscription> {
+        self.subscriptions
+            .iter()
+            .rev()
+            .find(|s| s.external_id == external_id)
+    }
+
+    /// Finds an invoice by Lago ID.
+    pub fn invoice(&self, lago_id: &str) -> Option<&Invoice> {
+        self.invoices
+            .iter()
+            .find(|i| i.lago_id.is_some_and(|id| id.to_string() == lago_id))
+    }
+
+    /// Finds a coupon by code.
+    pub fn coupon(&self, code: &str) -> Option<&Coupon> {
+        self.coupons.iter().find(|c| c.code == code)
+    }
+}
@@ -0,0 +1,585 @@
+//! Local billable metric aggregation engine.
+//!
+//! Aggregates usage events against a [`BillableMetric`] the way Lago does,
+//! so usage counted on your side can be reconciled with the units reported by
+//! the customer usage endpoints.
+//!
+//! # Example
+//! ```
+//! use chrono::{TimeZone, Utc};
+//! use lago_types::aggregation::{AggregationQuery, aggregate};
+//! use lago_types::models::BillableMetric;
+//! use lago_types::requests::event::CreateEventInput;
+//! use serde_json::json;
+//!
+//! # fn example(metric: &BillableMetric) -> lago_types::error::Result<()> {
+//! let events = vec![
+//!     CreateEventInput::for_subscription("tx_1".to_string(), "sub_1".to_string(), metric.code.clone())
+//!         .with_properties(json!({"gb": 10, "region": "eu"}))
+//!         .with_timestamp(1_767_225_600),
+//! ];
+//!
+//! let query = AggregationQuery::new(
+//!     Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+//!     Utc.with_ymd_and_hms(2026, 1, 31, 23, 59, 59).unwrap(),
+//! )
+//! .with_filter("region".to_string(), vec!["eu".to_string()]);
+//!
+//! let aggregation = aggregate(metric, &events, &query)?;
+//! println!("{} units", aggregation.units);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::Value;
+
+use crate::error::{LagoError, Result};
+use crate::models::{
+    BillableMetric, BillableMetricAggregationType, BillableMetricFilter,
+    BillableMetricRoundingFunction, Event,
+};
+use crate::pricing::ChargeUsage;
+use crate::requests::event::CreateEventInput;
+
+/// An event that can be aggregated against a billable metric.
+pub trait UsageEvent {
+    /// The unique transaction ID of the event.
+    fn transaction_id(&self) -> &str;
+    /// The billable metric code of the event.
+    fn code(&self) -> &str;
+    /// When the event happened, `None` if the API should timestamp it on receipt.
+    fn timestamp(&self) -> Option<DateTime<Utc>>;
+    /// The external ID of the subscription the event belongs to, if any.
+    fn external_subscription_id(&self) -> Option<&str>;
+    /// The custom properties of the event.
+    fn properties(&self) -> Option<&Value>;
+}
+
+impl UsageEvent for Event {
+    fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        Some(self.timestamp)
+    }
+
+    fn external_subscription_id(&self) -> Option<&str> {
+        self.external_subscription_id.as_deref()
+    }
+
+    fn properties(&self) -> Option<&Value> {
+        self.properties.as_ref()
+    }
+}
+
+impl UsageEvent for CreateEventInput {
+    fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.timestamp
+            .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single())
+    }
+
+    fn external_subscription_id(&self) -> Option<&str> {
+        self.external_subscription_id.as_deref()
+    }
+
+    fn properties(&self) -> Option<&Value> {
+        self.properties.as_ref()
+    }
+}
+
+/// Selects the events to aggregate.
+#[derive(Debug, Clone)]
+pub struct AggregationQuery {
+    /// Start of the billing window, inclusive.
+    pub from: DateTime<Utc>,
+    /// End of the billing window, inclusive.
+    pub to: DateTime<Utc>,
+    /// Only aggregate events of this subscription.
+    pub external_subscription_id: Option<String>,
+    /// Only aggregate events whose properties match every filter.
+    pub filters: Vec<BillableMetricFilter>,
+    /// Value carried over from the previous period, for weighted sums.
+    pub initial_value: f64,
+}
+
+impl AggregationQuery {
+    /// Creates a query for a billing window.
+    ///
+    /// Events without a timestamp are considered part of the window.
+    ///
+    /// # Arguments
+    /// * `from` - Start of the billing window, inclusive
+    /// * `to` - End of the billing window, inclusive
+    ///
+    /// # Returns
+    /// A new `AggregationQuery` instance
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        Self {
+            from,
+            to,
+            external_subscription_id: None,
+            filters: Vec::new(),
+            initial_value: 0.0,
+        }
+    }
+
+    /// Only aggregates events of a subscription.
+    ///
+    /// # Arguments
+    /// * `external_subscription_id` - The external ID of the subscription
+    ///
+    /// # Returns
+    /// The modified query instance for method chaining
+    pub fn with_external_subscription_id(mut self, external_subscription_id: String) -> Self {
+        self.external_subscription_id = Some(external_subscription_id);
+        self
+    }
+
+    /// Only aggregates events whose `key` property is one of `values`.
+    ///
+    /// # Arguments
+    /// * `key` - The event property to filter on
+    /// * `values` - The accepted values
+    ///
+    /// # Returns
+    /// The modified query instance for method chaining
+    pub fn with_filter(mut self, key: String, values: Vec<String>) -> Self {
+        self.filters.push(BillableMetricFilter::new(key, values));
+        self
+    }
+
+    /// Sets the value carried over from the previous period.
+    ///
+    /// Only used by weighted sums, where events are increments of a running total.
+    ///
+    /// # Arguments
+    /// * `initial_value` - The running total at the start of the window
+    ///
+    /// # Returns
+    /// The modified query instance for method chaining
+    pub fn with_initial_value(mut self, initial_value: f64) -> Self {
+        self.initial_value = initial_value;
+        self
+    }
+
+    fn matches<E: UsageEvent>(&self, metric: &BillableMetric, event: &E) -> bool {
+        if event.code() != metric.code {
+            return false;
+        }
+
+        if let Some(timestamp) = event.timestamp()
+            && (timestamp < self.from || timestamp > self.to)
+        {
+            return false;
+        }
+
+        if let Some(ref external_subscription_id) = self.external_subscription_id
+            && event.external_subscription_id() != Some(external_subscription_id.as_str())
+        {
+            return false;
+        }
+
+        self.filters.iter().all(|filter| {
+            event
+                .properties()
+                .and_then(|properties| properties.get(&filter.key))
+                .and_then(property_to_string)
+                .is_some_and(|value| filter.values.contains(&value))
+        })
+    }
+}
+
+/// Result of aggregating events against a billable metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aggregation {
+    /// Aggregated units, rounded as configured on the metric.
+    pub units: f64,
+    /// Number of events that matched the query.
+    pub events_count: u64,
+    /// Value of the metric's field for each matching event, in timestamp order.
+    pub event_units: Vec<f64>,
+}
+
+impl Aggregation {
+    /// Returns whether the units match units reported by the API.
+    ///
+    /// # Arguments
+    /// * `units` - Units as reported in `ChargeUsage::units`
+    ///
+    /// # Returns
+    /// `true` if both amounts are equal, up to floating point noise
+    pub fn matches_units(&self, units: &str) -> bool {
+        units
+            .trim()
+            .parse::<f64>()
+            .is_ok_and(|units| (self.units - units).abs() < 1e-9)
+    }
+
+    /// Converts the aggregation into usage for the pricing engine.
+    pub fn into_usage(self) -> ChargeUsage {
+        ChargeUsage {
+            units: self.units,
+            events_count: self.events_count,
+            event_units: Some(self.event_units),
+        }
+    }
+}
+
+/// Aggregates events against a billable metric.
+///
+/// Events of other metrics, outside the billing window or not matching the
+/// query filters are ignored. Like Lago, only the earliest event of each
+/// transaction ID (per subscription) is counted; events sharing a timestamp
+/// are ordered as in `events`. Field values may be JSON
+/// numbers or numeric strings; events missing the field count as 0 in sums.
+///
+/// # Arguments
+/// * `metric` - The billable metric defining the aggregation
+/// * `events` - The events to aggregate, in any order
+/// * `query` - The billing window and filters
+///
+/// # Returns
+/// A `Result` containing the aggregation, or an error if the metric can't be
+/// evaluated locally
+pub fn aggregate<E: UsageEvent>(
+    metric: &BillableMetric,
+    events: &[E],
+    query: &AggregationQuery,
+) -> Result<Aggregation> {
+    if metric.expression.as_deref().is_some_and(|e| !e.is_empty()) {
+        return Err(LagoError::InvalidInput(format!(
+            "billable metric {} uses an expression, which can't be evaluated locally",
+            metric.code
+        )));
+    }
+
+    let mut sorted: Vec<&E> = events.iter().collect();
+    // Stable sort: events sharing a timestamp keep their order.
+    sorted.sort_by_key(|event| event.timestamp());
+    let mut transactions = HashSet::new();
+    let matching: Vec<&E> = sorted
+        .into_iter()
+        .filter(|event| {
+            transactions.insert((event.external_subscription_id(), event.transaction_id()))
+        })
+        .filter(|event| query.matches(metric, *event))
+        .collect();
+
+    let field_values: Vec<Option<&Value>> = matching
+        .iter()
+        .map(|event| field_value(metric, *event))
+        .collect::<Result<_>>()?;
+    let event_units: Vec<f64> = match metric.aggregation_type {
+        BillableMetricAggregationType::CountAgg => vec![1.0; matching.len()],
+        _ => field_values
+            .iter()
+            .map(|value| value.and_then(property_to_f64).unwrap_or(0.0))
+            .collect(),
+    };
+
+    let units = match metric.aggregation_type {
+        BillableMetricAggregationType::CountAgg => matching.len() as f64,
+        BillableMetricAggregationType::SumAgg => event_units.iter().sum(),
+        BillableMetricAggregationType::MaxAgg => {
+            event_units.iter().copied().reduce(f64::max).unwrap_or(0.0)
+        }
+        BillableMetricAggregationType::LatestAgg => event_units.last().copied().unwrap_or(0.0),
+        BillableMetricAggregationType::UniqueCountAgg => field_values
+            .iter()
+            .filter_map(|value| value.and_then(property_to_string))
+            .collect::<HashSet<_>>()
+            .len() as f64,
+        BillableMetricAggregationType::WeightedSumAgg => {
+            weighted_sum(&matching, &event_units, query)
+        }
+    };
+
+    Ok(Aggregation {
+        units: round(
+            units,
+            metric.rounding_function.as_ref(),
+            metric.rounding_precision,
+        ),
+        events_count: matching.len() as u64,
+        event_units,
+    })
+}
+
+fn field_value<'a, E: UsageEvent>(
+    metric: &BillableMetric,
+    event: &'a E,
+) -> Result<Option<&'a Value>> {
+    if let BillableMetricAggregationType::CountAgg = metric.aggregation_type {
+        return Ok(None);
+    }
+
+    let field_name = metric.field_name.as_deref().ok_or_else(|| {
+        LagoError::InvalidInput(format!(
+            "billable metric {} has no field_name to aggregate",
+            metric.code
+        ))
+    })?;
+
+    Ok(event
+        .properties()
+        .and_then(|properties| properties.get(field_name)))
+}
+
+/// Time-weighted average of a running total over the window.
+///
+/// Each event adds its value to the running total, which is weighted by the
+/// time it held until the next event or the end of the window.
+fn weighted_sum<E: UsageEvent>(events: &[&E], increments: &[f64], query: &AggregationQuery) -> f64 {
+    let window = (query.to - query.from).num_seconds() as f64;
+    if window <= 0.0 {
+        return 0.0;
+    }
+
+    // Events are keyed by the second they happened at, starting at the window.
+    let mut changes: BTreeMap<DateTime<Utc>, f64> = BTreeMap::new();
+    for (event, increment) in events.iter().zip(increments) {
+        let timestamp = event.timestamp().unwrap_or(query.from).max(query.from);
+        *changes.entry(timestamp).or_insert(0.0) += increment;
+    }
+
+    let mut total = query.initial_value;
+    let mut since = query.from;
+    let mut weighted = 0.0;
+    for (timestamp, increment) in changes {
+        weighted += total * (timestamp - since).num_seconds() as f64;
+        total += increment;
+        since = timestamp;
+    }
+    weighted += total * (query.to - since).num_seconds() as f64;
+
+    weighted / window
+}
+
+fn round(
+    value: f64,
+    function: Option<&BillableMetricRoundingFunction>,
+    precision: Option<i32>,
+) -> f64 {
+    let Some(function) = function else {
+        return value;
+    };
+
+    let factor = 10f64.powi(precision.unwrap_or(0));
+    let scaled = value * factor;
+    let rounded = match function {
+        BillableMetricRoundingFunction::Ceil => scaled.ceil(),
+        BillableMetricRoundingFunction::Floor => scaled.floor(),
+        BillableMetricRoundingFunction::Round => scaled.round(),
+    };
+    rounded / factor
+}
+
+fn property_to_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(string) => string.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn property_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn metric(
+        aggregation_type: BillableMetricAggregationType,
+        field_name: Option<&str>,
+    ) -> BillableMetric {
+        BillableMetric {
+            lago_id: Uuid::new_v4(),
+            name: "Storage".to_string(),
+            code: "storage".to_string(),
+            description: None,
+            aggregation_type,
+            recurring: false,
+            rounding_function: None,
+            rounding_precision: None,
+            created_at: Utc::now(),
+            expression: None,
+            field_name: field_name.map(str::to_string),
+            weighted_interval: None,
+            filters: Vec::new(),
+        }
+    }
+
+    fn event(transaction_id: &str, day: u32, properties: Value) -> CreateEventInput {
+        CreateEventInput::for_subscription(
+            transaction_id.to_string(),
+            "sub_1".to_string(),
+            "storage".to_string(),
+        )
+        .with_properties(properties)
+        .with_timestamp(
+            Utc.with_ymd_and_hms(2026, 1, day, 0, 0, 0)
+                .unwrap()
+                .timestamp(),
+        )
+    }
+
+    fn january() -> AggregationQuery {
+        AggregationQuery::new(
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap(),
+        )
+    }
+
+    fn events() -> Vec<CreateEventInput> {
+        vec![
+            event("tx_1", 1, json!({"gb": 10, "region": "eu", "user": "a"})),
+            event(
+                "tx_2",
+                11,
+                json!({"gb": "2.5", "region": "us", "user": "b"}),
+            ),
+            event("tx_3", 21, json!({"gb": 5, "region": "eu", "user": "a"})),
+        ]
+    }
+
+    #[test]
+    fn aggregates_each_type() {
+        let events = events();
+        let units = |aggregation_type, field_name| {
+            aggregate(&metric(aggregation_type, field_name), &events, &january())
+                .unwrap()
+                .units
+        };
+
+        assert_eq!(units(BillableMetricAggregationType::CountAgg, None), 3.0);
+        assert_eq!(
+            units(BillableMetricAggregationType::SumAgg, Some("gb")),
+            17.5
+        );
+        assert_eq!(
+            units(BillableMetricAggregationType::MaxAgg, Some("gb")),
+            10.0
+        );
+        assert_eq!(
+            units(BillableMetricAggregationType::LatestAgg, Some("gb")),
+            5.0
+        );
+        assert_eq!(
+            units(BillableMetricAggregationType::UniqueCountAgg, Some("user")),
+            2.0
+        );
+    }
+
+    #[test]
+    fn counts_each_transaction_once() {
+        let mut events = events();
+        events.push(event("tx_1", 2, json!({"gb": 100, "region": "eu"})));
+        // Earlier than the tx_3 event listed before it, so it wins.
+        events.push(event("tx_3", 15, json!({"gb": 1, "region": "eu"})));
+        let sum = metric(BillableMetricAggregationType::SumAgg, Some("gb"));
+
+        let aggregation = aggregate(&sum, &events, &january()).unwrap();
+        assert_eq!(aggregation.units, 13.5);
+        assert_eq!(aggregation.events_count, 3);
+    }
+
+    #[test]
+    fn keeps_negative_max() {
+        let events = vec![
+            event("tx_1", 1, json!({"gb": -4})),
+            event("tx_2", 2, json!({"gb": -2})),
+        ];
+        let max = metric(BillableMetricAggregationType::MaxAgg, Some("gb"));
+
+        assert_eq!(aggregate(&max, &events, &january()).unwrap().units, -2.0);
+        assert_eq!(
+            aggregate(&max, &Vec::<CreateEventInput>::new(), &january())
+                .unwrap()
+                .units,
+            0.0
+        );
+    }
+
+    #[test]
+    fn aggregates_weighted_sum() {
+        let events = events();
+        let metric = metric(BillableMetricAggregationType::WeightedSumAgg, Some("gb"));
+
+        // 10 for 10 days, 12.5 for 10 days, 17.5 for 10 days over a 30 days window
+        let aggregation = aggregate(&metric, &events, &january()).unwrap();
+        assert!((aggregation.units - 13.333_333_333).abs() < 1e-6);
+    }
+
+    #[test]
+    fn applies_window_code_and_filters() {
+        let mut events = events();
+        events.push(event("tx_4", 31, json!({"gb": 100, "region": "eu"})));
+        let mut other_metric = event("tx_5", 2, json!({"gb": 100, "region": "eu"}));
+        other_metric.code = "other".to_string();
+        events.push(other_metric);
+
+        let metric = metric(BillableMetricAggregationType::SumAgg, Some("gb"));
+        let query = AggregationQuery::new(
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 30, 23, 59, 59).unwrap(),
+        )
+        .with_filter("region".to_string(), vec!["eu".to_string()]);
+
+        let aggregation = aggregate(&metric, &events, &query).unwrap();
+        assert_eq!(aggregation.units, 15.0);
+        assert_eq!(aggregation.events_count, 2);
+        assert_eq!(aggregation.event_units, vec![10.0, 5.0]);
+    }
+
+    #[test]
+    fn applies_rounding() {
+        let mut metric = metric(BillableMetricAggregationType::SumAgg, Some("gb"));
+        metric.rounding_function = Some(BillableMetricRoundingFunction::Ceil);
+
+        let aggregation = aggregate(&metric, &events(), &january()).unwrap();
+        assert_eq!(aggregation.units, 18.0);
+        assert!(aggregation.matches_units("18.0"));
+
+        metric.rounding_function = Some(BillableMetricRoundingFunction::Floor);
+        metric.rounding_precision = Some(-1);
+        assert_eq!(
+            aggregate(&metric, &events(), &january()).unwrap().units,
+            10.0
+        );
+    }
+
+    #[test]
+    fn requires_field_name() {
+        let metric = metric(BillableMetricAggregationType::SumAgg, None);
+
+        assert!(matches!(
+            aggregate(&metric, &events(), &january()),
+            Err(LagoError::InvalidInput(_))
+        ));
+    }
+}
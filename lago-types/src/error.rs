@@ -1,24 +1,234 @@
+use std::collections::HashMap;
+
 use thiserror::Error;
 
+use crate::models::ApiError;
+
 #[derive(Error, Debug)]
 pub enum LagoError {
     #[error("HTTP request failed: {0}")]
     Http(#[from] reqwest::Error),
 
+    #[error("Transport error: {0}")]
+    Transport(String),
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("API error: {status} - {message}")]
-    Api { status: u16, message: String },
+    Api {
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+
+    #[error("Not found: {resource}")]
+    NotFound { resource: String },
+
+    #[error("Validation error: {code} {details:?}")]
+    Validation {
+        code: String,
+        details: HashMap<String, Vec<String>>,
+    },
+
+    #[error("Forbidden: {code}")]
+    Forbidden { code: String },
+
+    #[error("Conflict: {code} - {message}")]
+    Conflict { code: String, message: String },
+
+    #[error("Server error: {status} - {message}")]
+    ServerError { status: u16, message: String },
 
     #[error("Invalid configuration: {0}")]
     Configuration(String),
 
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
     #[error("Unauthorized: invalid API key")]
     Unauthorized,
 
     #[error("Rate limit exceeded")]
     RateLimit,
+
+    #[error("Invalid webhook signature: {0}")]
+    InvalidWebhookSignature(String),
+
+    #[error("Event batcher is closed")]
+    BatcherClosed,
+
+    #[error("Event batcher is full")]
+    BatcherFull,
+
+    #[error("Circuit breaker is open")]
+    CircuitOpen,
+}
+
+impl LagoError {
+    /// Builds the error matching a failed API response.
+    ///
+    /// Lago error payloads (`{status, error, code, error_details}`) are decoded
+    /// into [`LagoError::NotFound`], [`LagoError::Validation`],
+    /// [`LagoError::Forbidden`], [`LagoError::Conflict`] or
+    /// [`LagoError::ServerError`], and other statuses into [`LagoError::Api`]
+    /// with the error code. Bodies that can't be decoded keep their raw text as
+    /// the message, including for a 404 returned by e.g. a proxy, which gives a
+    /// [`LagoError::Api`] rather than a [`LagoError::NotFound`].
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code of the response
+    /// * `body` - The raw body of the response
+    ///
+    /// # Returns
+    /// The error describing the response
+    pub fn from_response(status: u16, body: &str) -> Self {
+        match ApiError::from_body(status, body) {
+            Some(error) => error.into(),
+            None if status == 404 => LagoError::Api {
+                status,
+                code: None,
+                message: body.to_string(),
+            },
+            None => ApiError {
+                status,
+                error: body.to_string(),
+                ..Default::default()
+            }
+            .into(),
+        }
+    }
+
+    /// Returns the HTTP status of errors returned by the API.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            LagoError::Api { status, .. } | LagoError::ServerError { status, .. } => Some(*status),
+            LagoError::NotFound { .. } => Some(404),
+            LagoError::Validation { .. } => Some(422),
+            LagoError::Forbidden { .. } => Some(403),
+            LagoError::Conflict { .. } => Some(409),
+            LagoError::Unauthorized => Some(401),
+            LagoError::RateLimit => Some(429),
+            _ => None,
+        }
+    }
+
+    /// Returns the error codes reported for `field` by a validation error.
+    pub fn field_errors(&self, field: &str) -> &[String] {
+        match self {
+            LagoError::Validation { details, .. } => {
+                details.get(field).map(Vec::as_slice).unwrap_or_default()
+            }
+            _ => &[],
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, LagoError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_validation_error() {
+        let body = r#"{
+            "status": 422,
+            "error": "Unprocessable Entity",
+            "code": "validation_errors",
+            "error_details": {"external_customer_id": ["value_already_exist"]}
+        }"#;
+
+        let error = LagoError::from_response(422, body);
+        match &error {
+            LagoError::Validation { code, details } => {
+                assert_eq!(code, "validation_errors");
+                assert_eq!(details["external_customer_id"], vec!["value_already_exist"]);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(
+            error.field_errors("external_customer_id"),
+            ["value_already_exist"]
+        );
+        assert!(error.field_errors("code").is_empty());
+    }
+
+    #[test]
+    fn flattens_nested_error_details() {
+        let body = r#"{
+            "status": 422,
+            "error": "Unprocessable Entity",
+            "code": "validation_errors",
+            "error_details": {"0": {"transaction_id": ["value_already_exist"]}, "plan": "invalid"}
+        }"#;
+
+        let error = LagoError::from_response(422, body);
+        assert_eq!(
+            error.field_errors("0.transaction_id"),
+            ["value_already_exist"]
+        );
+        assert_eq!(error.field_errors("plan"), ["invalid"]);
+    }
+
+    #[test]
+    fn decodes_not_found_resource() {
+        let body = r#"{"status": 404, "error": "Not Found", "code": "customer_not_found"}"#;
+
+        assert!(matches!(
+            LagoError::from_response(404, body),
+            LagoError::NotFound { resource } if resource == "customer"
+        ));
+    }
+
+    #[test]
+    fn decodes_forbidden_and_server_errors() {
+        let body = r#"{"status": 403, "error": "Forbidden", "code": "feature_unavailable"}"#;
+        assert!(matches!(
+            LagoError::from_response(403, body),
+            LagoError::Forbidden { code } if code == "feature_unavailable"
+        ));
+
+        let error = LagoError::from_response(502, "<html>Bad Gateway</html>");
+        assert!(matches!(
+            &error,
+            LagoError::ServerError { status: 502, message } if message == "<html>Bad Gateway</html>"
+        ));
+        assert_eq!(error.status(), Some(502));
+    }
+
+    #[test]
+    fn falls_back_to_raw_api_error() {
+        let body = r#"{"status": 400, "error": "BadRequest: param is missing"}"#;
+        assert!(matches!(
+            LagoError::from_response(400, body),
+            LagoError::Api { status: 400, code: None, message } if message == "BadRequest: param is missing"
+        ));
+
+        assert!(matches!(
+            LagoError::from_response(405, "Method Not Allowed"),
+            LagoError::Api { status: 405, message, .. } if message == "Method Not Allowed"
+        ));
+
+        assert!(matches!(
+            LagoError::from_response(404, "<html>Not Found</html>"),
+            LagoError::Api { status: 404, code: None, message } if message == "<html>Not Found</html>"
+        ));
+    }
+
+    #[test]
+    fn keeps_code_of_unmapped_statuses() {
+        let body =
+            r#"{"status": 405, "error": "Method Not Allowed", "code": "no_voidable_amount"}"#;
+        let error = LagoError::from_response(405, body);
+
+        assert!(matches!(
+            &error,
+            LagoError::Api { status: 405, code: Some(code), .. } if code == "no_voidable_amount"
+        ));
+        assert_eq!(error.status(), Some(405));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod activity_log;
+pub mod add_on;
 pub mod api_log;
 pub mod applied_coupon;
 pub mod billable_metric;
@@ -11,3 +12,6 @@ pub mod fee;
 pub mod invoice;
 pub mod plan;
 pub mod subscription;
+pub mod tax;
+pub mod wallet;
+pub mod webhook_endpoint;
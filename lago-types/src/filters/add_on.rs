@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::common::ListFilters;
+
+/// Filter parameters for add-on list operations.
+///
+/// This struct represents the available filters that can be applied when
+/// querying add-on lists from the API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddOnFilter {
+    // Currently the Lago API does not expose specific filters for add-ons
+    // beyond pagination. This struct is kept for consistency and future extensibility.
+}
+
+impl AddOnFilter {
+    /// Create a new empty add-on filter.
+    ///
+    /// # Returns
+    /// A new `AddOnFilter` instance with no filters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ListFilters for AddOnFilter {
+    /// Converts the add-on filter into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing the filter criteria.
+    fn to_query_params(&self) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::common::ListFilters;
+
+/// Filter parameters for tax list operations.
+///
+/// This struct represents the available filters that can be applied when
+/// querying tax lists from the API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaxFilter {
+    // Currently the Lago API does not expose specific filters for taxes
+    // beyond pagination. This struct is kept for consistency and future extensibility.
+}
+
+impl TaxFilter {
+    /// Create a new empty tax filter.
+    ///
+    /// # Returns
+    /// A new `TaxFilter` instance with no filters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ListFilters for TaxFilter {
+    /// Converts the tax filter into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing the filter criteria.
+    fn to_query_params(&self) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+}
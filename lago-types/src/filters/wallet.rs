@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::common::ListFilters;
+use crate::models::{
+    WalletTransactionStatus, WalletTransactionTransactionStatus, WalletTransactionType,
+};
+
+/// Filter parameters for wallet transaction list operations.
+///
+/// This struct represents the available filters that can be applied when
+/// querying the transactions of a wallet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WalletTransactionFilter {
+    pub status: Option<WalletTransactionStatus>,
+    pub transaction_status: Option<WalletTransactionTransactionStatus>,
+    pub transaction_type: Option<WalletTransactionType>,
+}
+
+impl WalletTransactionFilter {
+    /// Creates a new empty wallet transaction filter.
+    ///
+    /// # Returns
+    /// A new `WalletTransactionFilter` instance with no filters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the status filter.
+    ///
+    /// # Arguments
+    /// * `status` - The settlement status to filter by (pending, settled or failed)
+    ///
+    /// # Returns
+    /// The modified filter instance for method chaining.
+    pub fn with_status(mut self, status: WalletTransactionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the transaction status filter.
+    ///
+    /// # Arguments
+    /// * `transaction_status` - The transaction status to filter by (purchased, granted, voided or invoiced)
+    ///
+    /// # Returns
+    /// The modified filter instance for method chaining.
+    pub fn with_transaction_status(
+        mut self,
+        transaction_status: WalletTransactionTransactionStatus,
+    ) -> Self {
+        self.transaction_status = Some(transaction_status);
+        self
+    }
+
+    /// Sets the transaction type filter.
+    ///
+    /// # Arguments
+    /// * `transaction_type` - The transaction type to filter by (inbound or outbound)
+    ///
+    /// # Returns
+    /// The modified filter instance for method chaining.
+    pub fn with_transaction_type(mut self, transaction_type: WalletTransactionType) -> Self {
+        self.transaction_type = Some(transaction_type);
+        self
+    }
+}
+
+impl ListFilters for WalletTransactionFilter {
+    /// Converts the wallet transaction filter into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing the filter criteria.
+    fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params: Vec<(&str, String)> = Vec::new();
+
+        if let Some(ref status) = self.status {
+            params.push(("status", status.to_string()));
+        }
+
+        if let Some(ref transaction_status) = self.transaction_status {
+            params.push(("transaction_status", transaction_status.to_string()));
+        }
+
+        if let Some(ref transaction_type) = self.transaction_type {
+            params.push(("transaction_type", transaction_type.to_string()));
+        }
+
+        params
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::common::ListFilters;
+
+/// Filter parameters for webhook endpoint list operations.
+///
+/// This struct represents the available filters that can be applied when
+/// querying webhook endpoint lists from the API.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookEndpointFilter {
+    // Currently the Lago API does not expose specific filters for webhook endpoints
+    // beyond pagination. This struct is kept for consistency and future extensibility.
+}
+
+impl WebhookEndpointFilter {
+    /// Create a new empty webhook endpoint filter.
+    ///
+    /// # Returns
+    /// A new `WebhookEndpointFilter` instance with no filters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ListFilters for WebhookEndpointFilter {
+    /// Converts the webhook endpoint filter into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing the filter criteria.
+    fn to_query_params(&self) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+}
@@ -1,5 +1,7 @@
+pub mod aggregation;
 pub mod error;
 pub mod filters;
 pub mod models;
+pub mod pricing;
 pub mod requests;
 pub mod responses;
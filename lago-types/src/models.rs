@@ -1,4 +1,5 @@
 pub mod activity_log;
+pub mod add_on;
 pub mod api_log;
 pub mod applied_coupon;
 pub mod billable_metric;
@@ -15,9 +16,14 @@ pub mod pagination;
 pub mod payment;
 pub mod plan;
 pub mod subscription;
+pub mod tax;
 pub mod usage_threshold;
+pub mod wallet;
+pub mod webhook;
+pub mod webhook_endpoint;
 
 pub use activity_log::*;
+pub use add_on::*;
 pub use api_log::*;
 pub use applied_coupon::*;
 pub use billable_metric::*;
@@ -34,4 +40,8 @@ pub use pagination::*;
 pub use payment::*;
 pub use plan::*;
 pub use subscription::*;
+pub use tax::*;
 pub use usage_threshold::*;
+pub use wallet::*;
+pub use webhook::*;
+pub use webhook_endpoint::*;
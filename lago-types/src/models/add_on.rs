@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::PlanTax;
+
+/// Represents an add-on in the Lago billing system.
+///
+/// An add-on is a one-off charge, billed through one-off invoices or used as
+/// the product of a fixed charge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddOn {
+    /// Unique identifier for the add-on in Lago.
+    pub lago_id: Uuid,
+    /// Name of the add-on.
+    pub name: String,
+    /// Display name for the add-on on invoices.
+    pub invoice_display_name: Option<String>,
+    /// Unique code for the add-on.
+    pub code: String,
+    /// Default price of the add-on in cents.
+    pub amount_cents: i64,
+    /// Currency of the price (ISO 4217 code).
+    pub amount_currency: String,
+    /// Description of the add-on.
+    pub description: Option<String>,
+    /// When the add-on was created.
+    pub created_at: DateTime<Utc>,
+    /// Taxes applied to the add-on.
+    pub taxes: Option<Vec<PlanTax>>,
+}
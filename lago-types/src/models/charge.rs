@@ -1,7 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
 use uuid::Uuid;
 
+use crate::error::{LagoError, Result};
+use crate::models::{ChargeModel, FixedChargeModel};
+
 /// Represents a standalone charge filter response from the API.
 ///
 /// This is used when charge filters are retrieved via standalone endpoints
@@ -20,3 +24,516 @@ pub struct ChargeFilterResponse {
     /// When the filter was created.
     pub created_at: DateTime<Utc>,
 }
+
+impl ChargeFilterResponse {
+    /// Returns the typed properties of the filter.
+    ///
+    /// # Arguments
+    /// * `charge_model` - The charge model of the charge the filter belongs to
+    ///
+    /// # Returns
+    /// A `Result` containing the typed properties or a serialization error
+    pub fn charge_properties(&self, charge_model: &ChargeModel) -> Result<ChargeProperties> {
+        ChargeProperties::from_parts(charge_model, self.properties.as_ref())
+    }
+}
+
+/// Typed properties of a charge, keyed by its charge model.
+///
+/// Serializes to the `charge_model` and `properties` pair used by the API, so
+/// it can be built from (and turned back into) the untyped `properties` of
+/// [`PlanCharge`](crate::models::PlanCharge),
+/// [`FixedCharge`](crate::models::FixedCharge) and charge filters with
+/// [`ChargeProperties::from_parts`] and [`ChargeProperties::to_value`].
+///
+/// Amounts are decimal strings, as returned by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "charge_model",
+    content = "properties",
+    rename_all = "snake_case"
+)]
+pub enum ChargeProperties {
+    Standard(StandardProperties),
+    Graduated(GraduatedProperties),
+    Volume(VolumeProperties),
+    Package(PackageProperties),
+    Percentage(PercentageProperties),
+    GraduatedPercentage(GraduatedPercentageProperties),
+    Dynamic(DynamicProperties),
+}
+
+/// Properties of the standard charge model: a flat price per unit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StandardProperties {
+    /// Price of one unit.
+    pub amount: String,
+    /// Event properties used to group fees (deprecated in favor of `pricing_group_keys`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    /// Event properties used to group fees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+/// A tier of graduated or volume pricing.
+///
+/// `to_value` is `None` for the last, open-ended range.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChargeRange {
+    pub from_value: u64,
+    pub to_value: Option<u64>,
+    /// Price of one unit within the range.
+    pub per_unit_amount: String,
+    /// Flat fee applied once the range is reached.
+    pub flat_amount: String,
+}
+
+/// Properties of the graduated charge model: each unit is priced by the range it falls in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraduatedProperties {
+    pub graduated_ranges: Vec<ChargeRange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+/// Properties of the volume charge model: all units are priced by the range of the total.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProperties {
+    pub volume_ranges: Vec<ChargeRange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+/// Properties of the package charge model: units are billed by started packages.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PackageProperties {
+    /// Price of one package.
+    pub amount: String,
+    /// Number of units included for free.
+    pub free_units: u64,
+    /// Number of units in a package.
+    pub package_size: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+/// Properties of the percentage charge model: a rate applied to the aggregated amount.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PercentageProperties {
+    /// Percentage applied to the units, e.g. `"1.5"` for 1.5%.
+    pub rate: String,
+    /// Fixed fee applied to each event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_amount: Option<String>,
+    /// Number of events free of the fixed fee.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub free_units_per_events: Option<u64>,
+    /// Amount free of the percentage rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub free_units_per_total_aggregation: Option<String>,
+    /// Minimum amount charged per event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_transaction_min_amount: Option<String>,
+    /// Maximum amount charged per event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_transaction_max_amount: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+/// A tier of graduated percentage pricing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraduatedPercentageRange {
+    pub from_value: u64,
+    pub to_value: Option<u64>,
+    /// Percentage applied to the units within the range.
+    pub rate: String,
+    /// Flat fee applied once the range is reached.
+    pub flat_amount: String,
+}
+
+/// Properties of the graduated percentage charge model.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraduatedPercentageProperties {
+    pub graduated_percentage_ranges: Vec<GraduatedPercentageRange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+/// Properties of the dynamic charge model, priced from the amounts sent with events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DynamicProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_by: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing_group_keys: Option<Vec<String>>,
+}
+
+impl ChargeProperties {
+    /// Builds typed properties from a charge model and its untyped properties.
+    ///
+    /// # Arguments
+    /// * `charge_model` - The charge model the properties belong to
+    /// * `properties` - The untyped properties, `None` being treated as empty
+    ///
+    /// # Returns
+    /// A `Result` containing the typed properties or a serialization error
+    pub fn from_parts(charge_model: &ChargeModel, properties: Option<&Value>) -> Result<Self> {
+        let properties = properties
+            .cloned()
+            .unwrap_or_else(|| Value::Object(Default::default()));
+
+        serde_json::from_value(json!({
+            "charge_model": charge_model,
+            "properties": properties,
+        }))
+        .map_err(LagoError::Serialization)
+    }
+
+    /// Builds typed properties from a fixed charge model and its untyped properties.
+    ///
+    /// # Arguments
+    /// * `charge_model` - The fixed charge model the properties belong to
+    /// * `properties` - The untyped properties, `None` being treated as empty
+    ///
+    /// # Returns
+    /// A `Result` containing the typed properties or a serialization error
+    pub fn from_fixed_parts(
+        charge_model: &FixedChargeModel,
+        properties: Option<&Value>,
+    ) -> Result<Self> {
+        let charge_model = match charge_model {
+            FixedChargeModel::Standard => ChargeModel::Standard,
+            FixedChargeModel::Graduated => ChargeModel::Graduated,
+            FixedChargeModel::Volume => ChargeModel::Volume,
+        };
+        Self::from_parts(&charge_model, properties)
+    }
+
+    /// Returns the charge model of these properties.
+    pub fn charge_model(&self) -> ChargeModel {
+        match self {
+            ChargeProperties::Standard(_) => ChargeModel::Standard,
+            ChargeProperties::Graduated(_) => ChargeModel::Graduated,
+            ChargeProperties::Volume(_) => ChargeModel::Volume,
+            ChargeProperties::Package(_) => ChargeModel::Package,
+            ChargeProperties::Percentage(_) => ChargeModel::Percentage,
+            ChargeProperties::GraduatedPercentage(_) => ChargeModel::GraduatedPercentage,
+            ChargeProperties::Dynamic(_) => ChargeModel::Dynamic,
+        }
+    }
+
+    /// Returns the fixed charge model of these properties, if fixed charges support it.
+    pub fn fixed_charge_model(&self) -> Option<FixedChargeModel> {
+        match self {
+            ChargeProperties::Standard(_) => Some(FixedChargeModel::Standard),
+            ChargeProperties::Graduated(_) => Some(FixedChargeModel::Graduated),
+            ChargeProperties::Volume(_) => Some(FixedChargeModel::Volume),
+            _ => None,
+        }
+    }
+
+    /// Returns the untyped `properties` object sent to the API.
+    pub fn to_value(&self) -> Value {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(mut map)) => map.remove("properties").unwrap_or(Value::Null),
+            _ => Value::Null,
+        }
+    }
+
+    /// Checks the properties the way the API would before accepting them.
+    ///
+    /// Amounts and rates must be non-negative decimals, ranges must start at 0,
+    /// follow each other without gaps or overlaps and end with an open range,
+    /// and packages must hold at least one unit.
+    ///
+    /// # Returns
+    /// `Ok(())` if the properties are valid, `LagoError::InvalidInput` otherwise
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ChargeProperties::Standard(properties) => validate_amount("amount", &properties.amount),
+            ChargeProperties::Graduated(properties) => {
+                validate_ranges(
+                    "graduated_ranges",
+                    properties
+                        .graduated_ranges
+                        .iter()
+                        .map(|r| (r.from_value, r.to_value)),
+                )?;
+                for range in &properties.graduated_ranges {
+                    validate_amount("per_unit_amount", &range.per_unit_amount)?;
+                    validate_amount("flat_amount", &range.flat_amount)?;
+                }
+                Ok(())
+            }
+            ChargeProperties::Volume(properties) => {
+                validate_ranges(
+                    "volume_ranges",
+                    properties
+                        .volume_ranges
+                        .iter()
+                        .map(|r| (r.from_value, r.to_value)),
+                )?;
+                for range in &properties.volume_ranges {
+                    validate_amount("per_unit_amount", &range.per_unit_amount)?;
+                    validate_amount("flat_amount", &range.flat_amount)?;
+                }
+                Ok(())
+            }
+            ChargeProperties::Package(properties) => {
+                validate_amount("amount", &properties.amount)?;
+                if properties.package_size == 0 {
+                    return Err(LagoError::InvalidInput(
+                        "package_size must be at least 1".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            ChargeProperties::Percentage(properties) => {
+                validate_amount("rate", &properties.rate)?;
+                let optional_amounts = [
+                    ("fixed_amount", &properties.fixed_amount),
+                    (
+                        "free_units_per_total_aggregation",
+                        &properties.free_units_per_total_aggregation,
+                    ),
+                    (
+                        "per_transaction_min_amount",
+                        &properties.per_transaction_min_amount,
+                    ),
+                    (
+                        "per_transaction_max_amount",
+                        &properties.per_transaction_max_amount,
+                    ),
+                ];
+                for (field, amount) in optional_amounts {
+                    if let Some(amount) = amount {
+                        validate_amount(field, amount)?;
+                    }
+                }
+
+                if let (Some(min), Some(max)) = (
+                    &properties.per_transaction_min_amount,
+                    &properties.per_transaction_max_amount,
+                ) && parse_amount(min) > parse_amount(max)
+                {
+                    return Err(LagoError::InvalidInput(
+                        "per_transaction_min_amount must not exceed per_transaction_max_amount"
+                            .to_string(),
+                    ));
+                }
+                Ok(())
+            }
+            ChargeProperties::GraduatedPercentage(properties) => {
+                validate_ranges(
+                    "graduated_percentage_ranges",
+                    properties
+                        .graduated_percentage_ranges
+                        .iter()
+                        .map(|r| (r.from_value, r.to_value)),
+                )?;
+                for range in &properties.graduated_percentage_ranges {
+                    validate_amount("rate", &range.rate)?;
+                    validate_amount("flat_amount", &range.flat_amount)?;
+                }
+                Ok(())
+            }
+            ChargeProperties::Dynamic(_) => Ok(()),
+        }
+    }
+}
+
+fn parse_amount(amount: &str) -> Option<f64> {
+    amount
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+fn validate_amount(field: &str, amount: &str) -> Result<()> {
+    match parse_amount(amount) {
+        Some(_) => Ok(()),
+        None => Err(LagoError::InvalidInput(format!(
+            "{field} must be a non-negative decimal, got {amount:?}"
+        ))),
+    }
+}
+
+/// Checks that ranges start at 0, that each range starts right after the
+/// previous one ends, and that only the last range is open-ended.
+fn validate_ranges(
+    field: &str,
+    ranges: impl ExactSizeIterator<Item = (u64, Option<u64>)>,
+) -> Result<()> {
+    let count = ranges.len();
+    if count == 0 {
+        return Err(LagoError::InvalidInput(format!(
+            "{field} must not be empty"
+        )));
+    }
+
+    let mut expected_from = 0;
+    for (index, (from_value, to_value)) in ranges.enumerate() {
+        if from_value != expected_from {
+            return Err(LagoError::InvalidInput(format!(
+                "{field}[{index}].from_value must be {expected_from}, got {from_value}"
+            )));
+        }
+
+        let is_last = index == count - 1;
+        match to_value {
+            None if is_last => {}
+            None => {
+                return Err(LagoError::InvalidInput(format!(
+                    "{field}[{index}].to_value is required except on the last range"
+                )));
+            }
+            Some(_) if is_last => {
+                return Err(LagoError::InvalidInput(format!(
+                    "{field}[{index}].to_value must be null on the last range"
+                )));
+            }
+            Some(to_value) if to_value <= from_value => {
+                return Err(LagoError::InvalidInput(format!(
+                    "{field}[{index}].to_value must be greater than from_value"
+                )));
+            }
+            Some(to_value) => expected_from = to_value + 1,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(from_value: u64, to_value: Option<u64>) -> ChargeRange {
+        ChargeRange {
+            from_value,
+            to_value,
+            per_unit_amount: "1.5".to_string(),
+            flat_amount: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn deserializes_from_charge_model_and_properties() {
+        let properties = json!({
+            "graduated_ranges": [
+                {"from_value": 0, "to_value": 10, "per_unit_amount": "2", "flat_amount": "1"},
+                {"from_value": 11, "to_value": null, "per_unit_amount": "1", "flat_amount": "0"}
+            ]
+        });
+
+        let typed =
+            ChargeProperties::from_parts(&ChargeModel::Graduated, Some(&properties)).unwrap();
+        match &typed {
+            ChargeProperties::Graduated(graduated) => {
+                assert_eq!(graduated.graduated_ranges.len(), 2);
+                assert_eq!(graduated.graduated_ranges[1].to_value, None);
+            }
+            other => panic!("unexpected properties: {other:?}"),
+        }
+        assert!(typed.validate().is_ok());
+        assert_eq!(typed.to_value(), properties);
+    }
+
+    #[test]
+    fn serializes_as_charge_model_and_properties_pair() {
+        let typed = ChargeProperties::Package(PackageProperties {
+            amount: "10".to_string(),
+            free_units: 100,
+            package_size: 1000,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            serde_json::to_value(&typed).unwrap(),
+            json!({
+                "charge_model": "package",
+                "properties": {"amount": "10", "free_units": 100, "package_size": 1000}
+            })
+        );
+    }
+
+    #[test]
+    fn reads_dynamic_and_fixed_charge_properties() {
+        assert_eq!(
+            ChargeProperties::from_parts(&ChargeModel::Dynamic, None).unwrap(),
+            ChargeProperties::Dynamic(DynamicProperties::default())
+        );
+
+        let typed = ChargeProperties::from_fixed_parts(
+            &FixedChargeModel::Standard,
+            Some(&json!({"amount": "5"})),
+        )
+        .unwrap();
+        assert_eq!(typed.fixed_charge_model(), Some(FixedChargeModel::Standard));
+    }
+
+    #[test]
+    fn rejects_properties_of_another_model() {
+        assert!(
+            ChargeProperties::from_parts(&ChargeModel::Package, Some(&json!({"rate": "1"})))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_non_contiguous_ranges() {
+        let validate = |ranges| {
+            ChargeProperties::Volume(VolumeProperties {
+                volume_ranges: ranges,
+                ..Default::default()
+            })
+            .validate()
+        };
+
+        assert!(validate(vec![range(0, Some(10)), range(11, None)]).is_ok());
+        assert!(validate(vec![range(1, Some(10)), range(11, None)]).is_err());
+        assert!(validate(vec![range(0, Some(10)), range(12, None)]).is_err());
+        assert!(validate(vec![range(0, Some(10)), range(11, Some(20))]).is_err());
+        assert!(validate(vec![range(0, None), range(1, None)]).is_err());
+        assert!(validate(vec![]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        let standard = ChargeProperties::Standard(StandardProperties {
+            amount: "-1".to_string(),
+            ..Default::default()
+        });
+        assert!(matches!(
+            standard.validate(),
+            Err(LagoError::InvalidInput(_))
+        ));
+
+        let package = ChargeProperties::Package(PackageProperties {
+            amount: "1".to_string(),
+            ..Default::default()
+        });
+        assert!(package.validate().is_err());
+
+        let percentage = ChargeProperties::Percentage(PercentageProperties {
+            rate: "1".to_string(),
+            per_transaction_min_amount: Some("5".to_string()),
+            per_transaction_max_amount: Some("2".to_string()),
+            ..Default::default()
+        });
+        assert!(percentage.validate().is_err());
+    }
+}
@@ -1,13 +1,135 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::error::LagoError;
 
 /// Represents an error response from the API.
 ///
 /// This struct contains the standard error information returned by the API
-/// when a request fails, including the HTTP status code, error type, and
-/// a descriptive message.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// when a request fails, including the HTTP status code, error type, the
+/// machine-readable error code and, for validation failures, the error codes
+/// reported for each invalid field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ApiError {
     pub status: u16,
+    #[serde(default)]
     pub error: String,
-    pub message: String,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_error_details")]
+    pub error_details: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl ApiError {
+    /// Parses the body of a failed API response.
+    ///
+    /// Returns `None` when the body is not a Lago error payload, e.g. an HTML
+    /// page returned by a proxy.
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code of the response
+    /// * `body` - The raw body of the response
+    ///
+    /// # Returns
+    /// The decoded error, with `status` taken from the response when the body omits it
+    pub fn from_body(status: u16, body: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(body).ok()?;
+        let object = value.as_object()?;
+        if !object.contains_key("error") && !object.contains_key("code") {
+            return None;
+        }
+
+        let mut error: ApiError = serde_json::from_value(value).ok()?;
+        if error.status == 0 {
+            error.status = status;
+        }
+        Some(error)
+    }
+
+    /// Returns the name of the missing resource for `*_not_found` codes
+    /// (e.g. `customer` for `customer_not_found`).
+    pub fn resource(&self) -> Option<&str> {
+        self.code.as_deref()?.strip_suffix("_not_found")
+    }
+}
+
+impl From<ApiError> for LagoError {
+    fn from(error: ApiError) -> Self {
+        let message = error.message.clone().unwrap_or_else(|| error.error.clone());
+        let code = error.code.clone().unwrap_or_default();
+
+        match error.status {
+            401 => LagoError::Unauthorized,
+            403 => LagoError::Forbidden { code },
+            404 => LagoError::NotFound {
+                resource: error.resource().unwrap_or("resource").to_string(),
+            },
+            409 => LagoError::Conflict { code, message },
+            422 => LagoError::Validation {
+                code,
+                details: error.error_details,
+            },
+            429 => LagoError::RateLimit,
+            status @ 500..=599 => LagoError::ServerError { status, message },
+            status => LagoError::Api {
+                status,
+                code: error.code,
+                message,
+            },
+        }
+    }
+}
+
+/// Accepts `error_details` as `{field: [codes]}`, `{field: code}` or nested
+/// objects such as the per-index details of batch requests, which are
+/// flattened into dotted keys (`0.transaction_id`).
+fn deserialize_error_details<'de, D>(
+    deserializer: D,
+) -> std::result::Result<HashMap<String, Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<Value>::deserialize(deserializer)?;
+    let mut details = HashMap::new();
+    if let Some(value) = value {
+        flatten_error_details(None, value, &mut details);
+    }
+    Ok(details)
+}
+
+fn flatten_error_details(
+    prefix: Option<String>,
+    value: Value,
+    details: &mut HashMap<String, Vec<String>>,
+) {
+    let key = || prefix.clone().unwrap_or_default();
+
+    match value {
+        Value::Object(map) => {
+            for (field, value) in map {
+                let field = match &prefix {
+                    Some(prefix) => format!("{prefix}.{field}"),
+                    None => field,
+                };
+                flatten_error_details(Some(field), value, details);
+            }
+        }
+        Value::Array(values) => {
+            let codes = details.entry(key()).or_default();
+            for value in values {
+                match value {
+                    Value::String(code) => codes.push(code),
+                    Value::Null => {}
+                    other => codes.push(other.to_string()),
+                }
+            }
+        }
+        Value::String(code) => details.entry(key()).or_default().push(code),
+        Value::Null => {}
+        other => details.entry(key()).or_default().push(other.to_string()),
+    }
 }
@@ -134,3 +134,42 @@ pub struct CreditNoteAppliedTax {
     /// Creation timestamp
     pub created_at: Option<DateTime<Utc>>,
 }
+
+/// Amounts of a credit note computed by Lago before it is created.
+///
+/// Returned by the estimate endpoint to show how much of a set of fees can
+/// be credited or refunded, and the taxes that apply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatedCreditNote {
+    /// Lago ID of the related invoice
+    pub lago_invoice_id: Uuid,
+    /// Number of the related invoice
+    pub invoice_number: String,
+    /// Currency code (ISO 4217)
+    pub currency: String,
+    /// Tax amount in cents
+    pub taxes_amount_cents: i64,
+    /// Tax rate percentage
+    pub taxes_rate: f64,
+    /// Subtotal excluding taxes in cents
+    pub sub_total_excluding_taxes_amount_cents: i64,
+    /// Maximum amount that can be credited in cents
+    pub max_creditable_amount_cents: i64,
+    /// Maximum amount that can be refunded in cents
+    pub max_refundable_amount_cents: i64,
+    /// Coupon adjustment amount in cents
+    pub coupons_adjustment_amount_cents: i64,
+    /// Estimated line items
+    pub items: Vec<EstimatedCreditNoteItem>,
+    /// Estimated applied taxes
+    pub applied_taxes: Vec<CreditNoteAppliedTax>,
+}
+
+/// A line item of an estimated credit note
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatedCreditNoteItem {
+    /// Lago ID of the credited fee
+    pub lago_fee_id: Uuid,
+    /// Amount in cents for this item
+    pub amount_cents: i64,
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 use uuid::Uuid;
 
+use crate::models::Tax;
+
 /// Represents a customer in the Lago billing system.
 ///
 /// This struct contains all the information about a customer, including
@@ -45,6 +47,7 @@ pub struct Customer {
     pub shipping_address: CustomerShippingAddress,
     pub metadata: Vec<CustomerMetadata>,
     pub integration_customers: Vec<CustomerIntegration>,
+    pub taxes: Option<Vec<Tax>>,
 }
 
 /// Defines the type of customer account.
@@ -129,13 +132,35 @@ pub struct CustomerIntegration {
     pub external_customer_id: Option<String>,
     pub sync_with_provider: bool,
     pub subsidiary_id: Option<String>,
+    pub targeted_object: Option<String>,
 }
 
 /// Supported integration types.
-#[derive(Debug, Clone, Serialize, Deserialize, EnumString)]
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum CustomerIntegrationType {
     Netsuite,
     Anrok,
+    Xero,
+    Hubspot,
+    Salesforce,
+    Avalara,
+}
+
+/// URL of the customer portal, where a customer can see their invoices and usage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerPortalUrl {
+    pub portal_url: String,
+}
+
+/// Checkout URL generated by the payment provider of a customer to collect
+/// their payment method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerCheckoutUrl {
+    pub lago_customer_id: Uuid,
+    pub external_customer_id: String,
+    pub payment_provider: Option<CustomerPaymentProvider>,
+    pub payment_provider_code: Option<String>,
+    pub checkout_url: String,
 }
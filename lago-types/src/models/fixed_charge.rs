@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 use uuid::Uuid;
 
-use crate::models::PlanTax;
+use crate::error::Result;
+use crate::models::{ChargeProperties, PlanTax};
 
 /// Fixed charge model types.
 #[derive(Debug, Clone, Serialize, Deserialize, EnumString, PartialEq, Eq)]
@@ -48,3 +49,13 @@ pub struct FixedCharge {
     /// Taxes applied to this fixed charge.
     pub taxes: Option<Vec<PlanTax>>,
 }
+
+impl FixedCharge {
+    /// Returns the typed properties of the fixed charge.
+    ///
+    /// # Returns
+    /// A `Result` containing the typed properties or a serialization error
+    pub fn charge_properties(&self) -> Result<ChargeProperties> {
+        ChargeProperties::from_fixed_parts(&self.charge_model, self.properties.as_ref())
+    }
+}
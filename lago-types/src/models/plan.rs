@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 use uuid::Uuid;
 
-use crate::models::{FixedCharge, UsageThreshold};
+use crate::error::Result;
+use crate::models::{ChargeProperties, FixedCharge, UsageThreshold};
 
 /// Represents a plan in the Lago billing system.
 ///
@@ -134,6 +135,16 @@ pub struct PlanCharge {
     pub accepts_target_wallet: Option<bool>,
 }
 
+impl PlanCharge {
+    /// Returns the typed properties of the charge.
+    ///
+    /// # Returns
+    /// A `Result` containing the typed properties or a serialization error
+    pub fn charge_properties(&self) -> Result<ChargeProperties> {
+        ChargeProperties::from_parts(&self.charge_model, self.properties.as_ref())
+    }
+}
+
 /// Represents a charge filter for differentiated pricing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChargeFilter {
@@ -145,6 +156,19 @@ pub struct ChargeFilter {
     pub values: Option<serde_json::Value>,
 }
 
+impl ChargeFilter {
+    /// Returns the typed properties of the filter.
+    ///
+    /// # Arguments
+    /// * `charge_model` - The charge model of the charge the filter belongs to
+    ///
+    /// # Returns
+    /// A `Result` containing the typed properties or a serialization error
+    pub fn charge_properties(&self, charge_model: &ChargeModel) -> Result<ChargeProperties> {
+        ChargeProperties::from_parts(charge_model, self.properties.as_ref())
+    }
+}
+
 /// Represents a tax applied to a plan or charge.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlanTax {
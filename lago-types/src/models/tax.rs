@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Represents a tax in the Lago billing system.
+///
+/// Taxes are applied to fees through their code, either on the whole
+/// organization or on specific customers, plans, charges and add-ons.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tax {
+    /// Unique identifier for the tax in Lago.
+    pub lago_id: Uuid,
+    /// Name of the tax.
+    pub name: String,
+    /// Unique code for the tax.
+    pub code: String,
+    /// Tax rate (percentage).
+    pub rate: f64,
+    /// Description of the tax.
+    pub description: Option<String>,
+    /// Whether the tax applies to every customer of the organization by default.
+    pub applied_to_organization: bool,
+    /// Number of add-ons the tax is applied to.
+    pub add_ons_count: Option<i32>,
+    /// Number of charges the tax is applied to.
+    pub charges_count: Option<i32>,
+    /// Number of customers the tax is applied to.
+    pub customers_count: Option<i32>,
+    /// Number of plans the tax is applied to.
+    pub plans_count: Option<i32>,
+    /// When the tax was created.
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use uuid::Uuid;
+
+/// Represents a wallet in the Lago billing system.
+///
+/// A wallet holds prepaid credits for a customer. Credits are converted
+/// to the wallet currency using `rate_amount` and are consumed by invoices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Wallet {
+    pub lago_id: Uuid,
+    pub lago_customer_id: Uuid,
+    pub external_customer_id: String,
+    pub status: WalletStatus,
+    pub currency: String,
+    pub name: Option<String>,
+    pub rate_amount: String,
+    pub credits_balance: String,
+    pub balance_cents: i64,
+    pub consumed_credits: String,
+    pub ongoing_balance_cents: Option<i64>,
+    pub ongoing_usage_balance_cents: Option<i64>,
+    pub credits_ongoing_balance: Option<String>,
+    pub credits_ongoing_usage_balance: Option<String>,
+    pub invoice_requires_successful_payment: Option<bool>,
+    pub recurring_transaction_rules: Option<Vec<RecurringTransactionRule>>,
+    pub created_at: DateTime<Utc>,
+    pub expiration_at: Option<DateTime<Utc>>,
+    pub last_balance_sync_at: Option<DateTime<Utc>>,
+    pub last_consumed_credit_at: Option<DateTime<Utc>>,
+    pub terminated_at: Option<DateTime<Utc>>,
+}
+
+/// Defines the status of a wallet.
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WalletStatus {
+    Active,
+    Terminated,
+}
+
+/// A rule automatically topping up a wallet.
+///
+/// Rules are triggered either on a fixed interval or when the ongoing
+/// balance drops below a credit threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringTransactionRule {
+    pub lago_id: Uuid,
+    pub trigger: RecurringTransactionTrigger,
+    pub interval: Option<RecurringTransactionInterval>,
+    pub threshold_credits: Option<String>,
+    pub method: Option<RecurringTransactionMethod>,
+    pub paid_credits: Option<String>,
+    pub granted_credits: Option<String>,
+    pub target_ongoing_balance: Option<String>,
+    pub invoice_requires_successful_payment: Option<bool>,
+    pub transaction_metadata: Option<Vec<WalletTransactionMetadata>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub expiration_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Defines what triggers a recurring transaction rule.
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecurringTransactionTrigger {
+    Interval,
+    Threshold,
+}
+
+/// Defines the interval of an interval-triggered recurring transaction rule.
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecurringTransactionInterval {
+    Weekly,
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+/// Defines how the amount of a recurring transaction is computed.
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecurringTransactionMethod {
+    /// Tops up the configured paid and granted credits.
+    Fixed,
+    /// Tops up the credits needed to reach `target_ongoing_balance`.
+    Target,
+}
+
+/// Represents a transaction on a wallet.
+///
+/// Transactions are inbound (top-ups and grants) or outbound (voided or
+/// invoiced credits).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletTransaction {
+    pub lago_id: Uuid,
+    pub lago_wallet_id: Uuid,
+    pub status: WalletTransactionStatus,
+    pub source: Option<WalletTransactionSource>,
+    pub transaction_status: WalletTransactionTransactionStatus,
+    pub transaction_type: WalletTransactionType,
+    pub amount: String,
+    pub credit_amount: String,
+    pub invoice_requires_successful_payment: Option<bool>,
+    pub metadata: Option<Vec<WalletTransactionMetadata>>,
+    pub settled_at: Option<DateTime<Utc>>,
+    pub failed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Defines the settlement status of a wallet transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WalletTransactionStatus {
+    Pending,
+    Settled,
+    Failed,
+}
+
+/// Defines what created a wallet transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WalletTransactionSource {
+    Manual,
+    Interval,
+    Threshold,
+}
+
+/// Defines the nature of the credits moved by a wallet transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WalletTransactionTransactionStatus {
+    Purchased,
+    Granted,
+    Voided,
+    Invoiced,
+}
+
+/// Defines the direction of a wallet transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, Display, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WalletTransactionType {
+    Inbound,
+    Outbound,
+}
+
+/// Key/value metadata attached to a wallet transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WalletTransactionMetadata {
+    pub key: String,
+    pub value: String,
+}
+
+impl WalletTransactionMetadata {
+    /// Creates a new metadata entry.
+    ///
+    /// # Arguments
+    /// * `key` - The metadata key
+    /// * `value` - The metadata value
+    ///
+    /// # Returns
+    /// A new `WalletTransactionMetadata` instance
+    pub fn new(key: String, value: String) -> Self {
+        Self { key, value }
+    }
+}
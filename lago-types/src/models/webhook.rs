@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{Display, EnumString};
+
+use crate::error::{LagoError, Result};
+use crate::models::{
+    CreditNote, Customer, Event, Fee, Invoice, Payment, Plan, Subscription, Wallet,
+    WalletTransaction,
+};
+
+/// Algorithm used by Lago to sign webhook payloads.
+///
+/// The algorithm of a delivery is sent in the `X-Lago-Signature-Algorithm`
+/// header, next to the signature itself in `X-Lago-Signature`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display, EnumString, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookSignatureAlgorithm {
+    /// RS256 JSON Web Token signed with the organization private key.
+    Jwt,
+    /// Base64-encoded HMAC-SHA256 of the payload keyed with the organization HMAC key.
+    Hmac,
+}
+
+/// A webhook delivered by Lago, keyed by its `webhook_type`.
+///
+/// Webhook types the crate doesn't know about deserialize to
+/// [`WebhookEvent::Unknown`] so new types added by Lago don't break receivers.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "webhook_type")]
+pub enum WebhookEvent {
+    #[serde(rename = "customer.created")]
+    CustomerCreated { customer: Customer },
+    #[serde(rename = "customer.updated")]
+    CustomerUpdated { customer: Customer },
+
+    #[serde(rename = "invoice.created")]
+    InvoiceCreated { invoice: Invoice },
+    #[serde(rename = "invoice.drafted")]
+    InvoiceDrafted { invoice: Invoice },
+    #[serde(rename = "invoice.generated")]
+    InvoiceGenerated { invoice: Invoice },
+    #[serde(rename = "invoice.one_off_created")]
+    InvoiceOneOffCreated { invoice: Invoice },
+    #[serde(rename = "invoice.paid_credit_added")]
+    InvoicePaidCreditAdded { invoice: Invoice },
+    #[serde(rename = "invoice.payment_status_updated")]
+    InvoicePaymentStatusUpdated { invoice: Invoice },
+    #[serde(rename = "invoice.payment_overdue")]
+    InvoicePaymentOverdue { invoice: Invoice },
+    #[serde(rename = "invoice.resynced")]
+    InvoiceResynced { invoice: Invoice },
+    #[serde(rename = "invoice.voided")]
+    InvoiceVoided { invoice: Invoice },
+
+    #[serde(rename = "fee.created")]
+    FeeCreated { fee: Fee },
+
+    #[serde(rename = "subscription.started")]
+    SubscriptionStarted { subscription: Subscription },
+    #[serde(rename = "subscription.terminated")]
+    SubscriptionTerminated { subscription: Subscription },
+    #[serde(rename = "subscription.termination_alert")]
+    SubscriptionTerminationAlert { subscription: Subscription },
+    #[serde(rename = "subscription.trial_ended")]
+    SubscriptionTrialEnded { subscription: Subscription },
+    #[serde(rename = "subscription.updated")]
+    SubscriptionUpdated { subscription: Subscription },
+
+    #[serde(rename = "credit_note.created")]
+    CreditNoteCreated { credit_note: CreditNote },
+    #[serde(rename = "credit_note.generated")]
+    CreditNoteGenerated { credit_note: CreditNote },
+
+    #[serde(rename = "payment.requires_action")]
+    PaymentRequiresAction { payment: Payment },
+
+    #[serde(rename = "event.error")]
+    EventError { event_error: EventError },
+
+    #[serde(rename = "plan.created")]
+    PlanCreated { plan: Plan },
+    #[serde(rename = "plan.updated")]
+    PlanUpdated { plan: Plan },
+    #[serde(rename = "plan.deleted")]
+    PlanDeleted { plan: Plan },
+
+    #[serde(rename = "wallet.depleted_ongoing_balance")]
+    WalletDepletedOngoingBalance { wallet: Wallet },
+    #[serde(rename = "wallet_transaction.created")]
+    WalletTransactionCreated {
+        wallet_transaction: WalletTransaction,
+    },
+    #[serde(rename = "wallet_transaction.updated")]
+    WalletTransactionUpdated {
+        wallet_transaction: WalletTransaction,
+    },
+
+    /// A webhook type not modeled by this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+impl WebhookEvent {
+    /// Deserializes a webhook body into a typed event.
+    ///
+    /// This does not verify the signature of the payload.
+    ///
+    /// # Arguments
+    /// * `body` - The raw body of the webhook request
+    ///
+    /// # Returns
+    /// A `Result` containing the typed event or a serialization error
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        serde_json::from_slice(body).map_err(LagoError::Serialization)
+    }
+}
+
+/// An event rejected during asynchronous ingestion.
+///
+/// Delivered with the `event.error` webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventError {
+    /// HTTP-like status describing the error (e.g. 422).
+    pub status: u16,
+    /// Short description of the error.
+    pub error: String,
+    /// Details of the error, usually a map of fields to error codes.
+    pub message: Value,
+    /// The event that failed.
+    pub event: Event,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_event_error() {
+        let body = br#"{
+            "webhook_type": "event.error",
+            "object_type": "event_error",
+            "event_error": {
+                "status": 422,
+                "error": "Unprocessable entity",
+                "message": {"transaction_id": ["value_already_exist"]},
+                "event": {
+                    "lago_id": "1a901a90-1a90-1a90-1a90-1a901a901a90",
+                    "transaction_id": "event_1234",
+                    "lago_customer_id": null,
+                    "code": "bm_code",
+                    "timestamp": "2025-07-03T15:35:00Z",
+                    "lago_subscription_id": null,
+                    "external_subscription_id": "sub_1234",
+                    "created_at": "2025-07-03T15:35:22Z",
+                    "precise_total_amount_cents": null,
+                    "properties": null
+                }
+            }
+        }"#;
+
+        match WebhookEvent::from_slice(body).unwrap() {
+            WebhookEvent::EventError { event_error } => {
+                assert_eq!(event_error.status, 422);
+                assert_eq!(event_error.event.transaction_id, "event_1234");
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn deserializes_unknown_webhook_type() {
+        let body =
+            br#"{"webhook_type": "dunning_campaign.finished", "object_type": "dunning_campaign"}"#;
+
+        assert!(matches!(
+            WebhookEvent::from_slice(body).unwrap(),
+            WebhookEvent::Unknown
+        ));
+    }
+
+    #[test]
+    fn parses_signature_algorithm() {
+        assert_eq!(
+            "jwt".parse::<WebhookSignatureAlgorithm>().unwrap(),
+            WebhookSignatureAlgorithm::Jwt
+        );
+        assert_eq!(WebhookSignatureAlgorithm::Hmac.to_string(), "hmac");
+    }
+}
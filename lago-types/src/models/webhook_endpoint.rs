@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::WebhookSignatureAlgorithm;
+
+/// Represents a webhook endpoint in the Lago billing system.
+///
+/// Lago sends every webhook of the organization to each of its endpoints,
+/// signed with the algorithm configured on the endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEndpoint {
+    /// Unique identifier for the webhook endpoint in Lago.
+    pub lago_id: Uuid,
+    /// Unique identifier of the organization owning the endpoint.
+    pub lago_organization_id: Uuid,
+    /// URL the webhooks are sent to.
+    pub webhook_url: String,
+    /// Algorithm used to sign the webhooks sent to this endpoint.
+    pub signature_algo: WebhookSignatureAlgorithm,
+    /// When the webhook endpoint was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// The public key Lago signs JWT webhooks with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPublicKey {
+    /// Base64-encoded PEM of the organization RSA public key.
+    pub public_key: String,
+}
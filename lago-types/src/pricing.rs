@@ -0,0 +1,541 @@
+//! Offline pricing engine.
+//!
+//! Computes the fee amount Lago would bill for a charge from an aggregated
+//! number of units, without calling the API. Amounts are computed in currency
+//! units (e.g. dollars) with `f64` precision, which is enough to match the
+//! `amount_cents` and `precise_amount` of fees on real invoices.
+//!
+//! # Example
+//! ```
+//! use lago_types::models::{ChargeProperties, PackageProperties};
+//! use lago_types::pricing::{ChargeUsage, price};
+//!
+//! let properties = ChargeProperties::Package(PackageProperties {
+//!     amount: "5".to_string(),
+//!     free_units: 100,
+//!     package_size: 1000,
+//!     ..Default::default()
+//! });
+//!
+//! // 2,500 units, 100 of them free: 3 packages started
+//! let amount = price(&properties, &ChargeUsage::new(2500.0)).unwrap();
+//! assert_eq!(amount, 15.0);
+//! ```
+
+use crate::error::{LagoError, Result};
+use crate::models::{
+    ChargeProperties, ChargeRange, Fee, GraduatedPercentageRange, PercentageProperties, PlanCharge,
+};
+
+/// Usage of a charge over a billing period.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChargeUsage {
+    /// Aggregated units, as reported by the billable metric.
+    pub units: f64,
+    /// Number of events behind the units, used by the percentage model.
+    pub events_count: u64,
+    /// Units of each event in ingestion order, used by the percentage model
+    /// for free events and per-transaction minimum and maximum amounts.
+    pub event_units: Option<Vec<f64>>,
+}
+
+impl ChargeUsage {
+    /// Creates usage from an aggregated number of units.
+    ///
+    /// # Arguments
+    /// * `units` - The aggregated units
+    ///
+    /// # Returns
+    /// A new `ChargeUsage` instance with no event details
+    pub fn new(units: f64) -> Self {
+        Self {
+            units,
+            events_count: 0,
+            event_units: None,
+        }
+    }
+
+    /// Creates usage from the units of each event, summed up.
+    ///
+    /// # Arguments
+    /// * `event_units` - The units of each event, in ingestion order
+    ///
+    /// # Returns
+    /// A new `ChargeUsage` instance
+    pub fn from_events(event_units: Vec<f64>) -> Self {
+        Self {
+            units: event_units.iter().sum(),
+            events_count: event_units.len() as u64,
+            event_units: Some(event_units),
+        }
+    }
+
+    /// Sets the number of events behind the units.
+    ///
+    /// # Arguments
+    /// * `events_count` - The number of events
+    ///
+    /// # Returns
+    /// The modified usage instance for method chaining
+    pub fn with_events_count(mut self, events_count: u64) -> Self {
+        self.events_count = events_count;
+        self
+    }
+}
+
+/// Fee computed for a charge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeEstimate {
+    /// Aggregated units the fee was computed from.
+    pub units: f64,
+    /// Fee amount in currency units, before taxes.
+    pub amount: f64,
+    /// Amount added to reach the charge's minimum, in currency units.
+    pub true_up_amount: f64,
+}
+
+impl FeeEstimate {
+    /// Returns the fee amount in cents, for currencies with two decimals.
+    pub fn amount_cents(&self) -> i64 {
+        self.amount_in_subunits(2)
+    }
+
+    /// Returns the fee amount in the smallest unit of the currency.
+    ///
+    /// # Arguments
+    /// * `exponent` - The number of decimals of the currency (2 for USD, 0 for JPY)
+    ///
+    /// # Returns
+    /// The rounded amount, as Lago reports it in `amount_cents`
+    pub fn amount_in_subunits(&self, exponent: u32) -> i64 {
+        (self.amount * 10f64.powi(exponent as i32)).round() as i64
+    }
+
+    /// Returns whether the estimate matches a fee from an invoice.
+    ///
+    /// Compares `precise_amount` when the fee has one, `amount_cents` otherwise.
+    ///
+    /// # Arguments
+    /// * `fee` - The fee to compare to, in a currency with two decimals
+    ///
+    /// # Returns
+    /// `true` if the amounts match to the cent
+    pub fn matches_fee(&self, fee: &Fee) -> bool {
+        match fee.precise_amount.as_deref().map(str::parse::<f64>) {
+            Some(Ok(precise_amount)) => (self.amount - precise_amount).abs() < 0.005,
+            _ => self.amount_cents() == fee.amount_cents,
+        }
+    }
+}
+
+/// Computes the fee of a plan charge.
+///
+/// The charge's `min_amount_cents` is applied as a true-up on top of the
+/// computed amount, for currencies with two decimals.
+///
+/// # Arguments
+/// * `charge` - The charge, with its model and properties
+/// * `usage` - The usage of the charge over the billing period
+///
+/// # Returns
+/// A `Result` containing the fee estimate, or an error if the properties are
+/// invalid or the model can't be priced offline
+pub fn estimate_fee(charge: &PlanCharge, usage: &ChargeUsage) -> Result<FeeEstimate> {
+    let properties = charge.charge_properties()?;
+    let amount = price(&properties, usage)?;
+
+    let min_amount = charge.min_amount_cents.unwrap_or(0) as f64 / 100.0;
+    let true_up_amount = (min_amount - amount).max(0.0);
+
+    Ok(FeeEstimate {
+        units: usage.units,
+        amount,
+        true_up_amount,
+    })
+}
+
+/// Computes the amount billed for typed charge properties.
+///
+/// # Arguments
+/// * `properties` - The charge properties
+/// * `usage` - The usage of the charge over the billing period
+///
+/// # Returns
+/// A `Result` containing the amount in currency units, or an error if the
+/// properties are invalid or the model can't be priced offline
+pub fn price(properties: &ChargeProperties, usage: &ChargeUsage) -> Result<f64> {
+    properties.validate()?;
+    let units = usage.units.max(0.0);
+
+    let amount = match properties {
+        ChargeProperties::Standard(standard) => units * decimal(&standard.amount),
+        ChargeProperties::Graduated(graduated) => {
+            graduated_amount(&graduated.graduated_ranges, units)
+        }
+        ChargeProperties::Volume(volume) => volume_amount(&volume.volume_ranges, units),
+        ChargeProperties::Package(package) => {
+            let paid_units = units - package.free_units as f64;
+            if paid_units <= 0.0 {
+                0.0
+            } else {
+                (paid_units / package.package_size as f64).ceil() * decimal(&package.amount)
+            }
+        }
+        ChargeProperties::Percentage(percentage) => percentage_amount(percentage, usage),
+        ChargeProperties::GraduatedPercentage(graduated) => {
+            graduated_percentage_amount(&graduated.graduated_percentage_ranges, units)
+        }
+        ChargeProperties::Dynamic(_) => {
+            return Err(LagoError::InvalidInput(
+                "dynamic charges are priced from the amounts sent with events".to_string(),
+            ));
+        }
+    };
+
+    Ok(amount)
+}
+
+/// Parses an amount already checked by `ChargeProperties::validate`.
+fn decimal(amount: &str) -> f64 {
+    amount.trim().parse().unwrap_or(0.0)
+}
+
+/// Number of units of `units` that fall in a range, counting from 1.
+fn range_units(from_value: u64, to_value: Option<u64>, units: f64) -> f64 {
+    let from = from_value.max(1) as f64;
+    match to_value {
+        Some(to_value) if units >= to_value as f64 => to_value as f64 - from + 1.0,
+        _ => (units - from + 1.0).max(0.0),
+    }
+}
+
+/// Walks the ranges reached by `units`, adding up the amount of each one.
+fn walk_ranges<R>(
+    ranges: &[R],
+    units: f64,
+    bounds: impl Fn(&R) -> (u64, Option<u64>),
+    range_amount: impl Fn(&R, f64) -> f64,
+) -> f64 {
+    if units == 0.0 {
+        return 0.0;
+    }
+
+    let mut amount = 0.0;
+    for range in ranges {
+        let (from_value, to_value) = bounds(range);
+        amount += range_amount(range, range_units(from_value, to_value, units));
+
+        match to_value {
+            Some(to_value) if (to_value as f64) < units => continue,
+            _ => break,
+        }
+    }
+    amount
+}
+
+fn graduated_amount(ranges: &[ChargeRange], units: f64) -> f64 {
+    walk_ranges(
+        ranges,
+        units,
+        |range| (range.from_value, range.to_value),
+        |range, range_units| {
+            decimal(&range.flat_amount) + range_units * decimal(&range.per_unit_amount)
+        },
+    )
+}
+
+fn graduated_percentage_amount(ranges: &[GraduatedPercentageRange], units: f64) -> f64 {
+    walk_ranges(
+        ranges,
+        units,
+        |range| (range.from_value, range.to_value),
+        |range, range_units| {
+            decimal(&range.flat_amount) + range_units * decimal(&range.rate) / 100.0
+        },
+    )
+}
+
+fn volume_amount(ranges: &[ChargeRange], units: f64) -> f64 {
+    if units == 0.0 {
+        return 0.0;
+    }
+
+    let rounded_units = units.ceil() as u64;
+    let Some(range) = ranges.iter().find(|range| {
+        range.from_value <= rounded_units && range.to_value.is_none_or(|to| rounded_units <= to)
+    }) else {
+        return 0.0;
+    };
+
+    decimal(&range.flat_amount) + units * decimal(&range.per_unit_amount)
+}
+
+fn percentage_amount(properties: &PercentageProperties, usage: &ChargeUsage) -> f64 {
+    let units = usage.units.max(0.0);
+    if units == 0.0 {
+        return 0.0;
+    }
+
+    let rate = decimal(&properties.rate) / 100.0;
+    let fixed_amount = properties.fixed_amount.as_deref().map_or(0.0, decimal);
+    let free_events = properties.free_units_per_events.unwrap_or(0) as usize;
+    let free_total = properties
+        .free_units_per_total_aggregation
+        .as_deref()
+        .map(decimal);
+
+    let has_bounds = properties.per_transaction_min_amount.is_some()
+        || properties.per_transaction_max_amount.is_some();
+
+    if let Some(event_units) = &usage.event_units {
+        let free_count = free_events.min(event_units.len());
+        let first_events_units = event_units.iter().take(free_count).sum();
+        let free_units = percentage_free_units(free_events, first_events_units, free_total, units);
+
+        if has_bounds {
+            let min = properties
+                .per_transaction_min_amount
+                .as_deref()
+                .map(decimal);
+            let max = properties
+                .per_transaction_max_amount
+                .as_deref()
+                .map(decimal);
+            let mut remaining_free = free_units;
+
+            // Free units are consumed in ingestion order; the free events pay
+            // no fixed amount and are not bounded.
+            return event_units
+                .iter()
+                .enumerate()
+                .map(|(index, &event)| {
+                    let billed_units = (event - remaining_free).max(0.0);
+                    remaining_free = (remaining_free - event).max(0.0);
+                    if index < free_count {
+                        return billed_units * rate;
+                    }
+                    let mut amount = billed_units * rate + fixed_amount;
+                    if let Some(min) = min {
+                        amount = amount.max(min);
+                    }
+                    if let Some(max) = max {
+                        amount = amount.min(max);
+                    }
+                    amount
+                })
+                .sum();
+        }
+
+        let paid_events = (event_units.len() - free_count) as f64;
+        return (units - free_units) * rate + paid_events * fixed_amount;
+    }
+
+    // Without per-event units, the free events are assumed to carry the
+    // average units of an event.
+    let free_count = (free_events as u64).min(usage.events_count);
+    let first_events_units = if usage.events_count == 0 {
+        0.0
+    } else {
+        units * free_count as f64 / usage.events_count as f64
+    };
+    let free_units = percentage_free_units(free_events, first_events_units, free_total, units);
+    let paid_events = (usage.events_count - free_count) as f64;
+    (units - free_units) * rate + paid_events * fixed_amount
+}
+
+/// Returns the free units of a percentage charge: the units of the first
+/// free events, capped by the free units per total aggregation.
+fn percentage_free_units(
+    free_events: usize,
+    first_events_units: f64,
+    free_total: Option<f64>,
+    units: f64,
+) -> f64 {
+    let free_units = match (free_events, free_total) {
+        (0, None) => 0.0,
+        (0, Some(total)) => total,
+        (_, None) => first_events_units,
+        (_, Some(total)) => first_events_units.min(total),
+    };
+    free_units.min(units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        GraduatedPercentageProperties, GraduatedProperties, PackageProperties, StandardProperties,
+        VolumeProperties,
+    };
+
+    fn range(from_value: u64, to_value: Option<u64>, per_unit: &str, flat: &str) -> ChargeRange {
+        ChargeRange {
+            from_value,
+            to_value,
+            per_unit_amount: per_unit.to_string(),
+            flat_amount: flat.to_string(),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn prices_standard() {
+        let properties = ChargeProperties::Standard(StandardProperties {
+            amount: "0.25".to_string(),
+            ..Default::default()
+        });
+
+        assert_close(price(&properties, &ChargeUsage::new(10.0)).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn prices_graduated() {
+        let properties = ChargeProperties::Graduated(GraduatedProperties {
+            graduated_ranges: vec![
+                range(0, Some(10), "2", "5"),
+                range(11, Some(20), "1", "3"),
+                range(21, None, "0.5", "0"),
+            ],
+            ..Default::default()
+        });
+
+        assert_close(price(&properties, &ChargeUsage::new(0.0)).unwrap(), 0.0);
+        // 5 + 8 * 2
+        assert_close(price(&properties, &ChargeUsage::new(8.0)).unwrap(), 21.0);
+        // 5 + 10 * 2 + 3 + 5 * 1
+        assert_close(price(&properties, &ChargeUsage::new(15.0)).unwrap(), 33.0);
+        // 5 + 10 * 2 + 3 + 10 * 1 + 5 * 0.5
+        assert_close(price(&properties, &ChargeUsage::new(25.0)).unwrap(), 40.5);
+    }
+
+    #[test]
+    fn prices_volume() {
+        let properties = ChargeProperties::Volume(VolumeProperties {
+            volume_ranges: vec![range(0, Some(100), "2", "10"), range(101, None, "1", "0")],
+            ..Default::default()
+        });
+
+        assert_close(price(&properties, &ChargeUsage::new(50.0)).unwrap(), 110.0);
+        assert_close(price(&properties, &ChargeUsage::new(150.0)).unwrap(), 150.0);
+    }
+
+    #[test]
+    fn prices_package() {
+        let properties = ChargeProperties::Package(PackageProperties {
+            amount: "5".to_string(),
+            free_units: 100,
+            package_size: 1000,
+            ..Default::default()
+        });
+
+        assert_close(price(&properties, &ChargeUsage::new(50.0)).unwrap(), 0.0);
+        assert_close(price(&properties, &ChargeUsage::new(1100.0)).unwrap(), 5.0);
+        assert_close(price(&properties, &ChargeUsage::new(1101.0)).unwrap(), 10.0);
+    }
+
+    #[test]
+    fn prices_percentage() {
+        let properties = ChargeProperties::Percentage(PercentageProperties {
+            rate: "2".to_string(),
+            fixed_amount: Some("0.5".to_string()),
+            free_units_per_events: Some(1),
+            ..Default::default()
+        });
+
+        let usage = ChargeUsage::from_events(vec![100.0, 200.0, 300.0]);
+        // 2% of the 500 units of the two paid events, plus two fixed fees
+        assert_close(price(&properties, &usage).unwrap(), 11.0);
+    }
+
+    #[test]
+    fn caps_percentage_free_units_at_total_aggregation() {
+        let properties = |free_total: &str| {
+            ChargeProperties::Percentage(PercentageProperties {
+                rate: "10".to_string(),
+                free_units_per_events: Some(2),
+                free_units_per_total_aggregation: Some(free_total.to_string()),
+                ..Default::default()
+            })
+        };
+        let usage = ChargeUsage::from_events(vec![6.0, 6.0]);
+
+        // 10 of the 12 units are free, 2 are billed
+        assert_close(price(&properties("10"), &usage).unwrap(), 0.2);
+        // Both events are free
+        assert_close(price(&properties("12"), &usage).unwrap(), 0.0);
+
+        // The same with aggregated units only
+        let usage = ChargeUsage::new(12.0).with_events_count(2);
+        assert_close(price(&properties("10"), &usage).unwrap(), 0.2);
+        assert_close(price(&properties("12"), &usage).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn prices_percentage_with_transaction_bounds() {
+        let properties = ChargeProperties::Percentage(PercentageProperties {
+            rate: "10".to_string(),
+            per_transaction_min_amount: Some("2".to_string()),
+            per_transaction_max_amount: Some("15".to_string()),
+            ..Default::default()
+        });
+
+        let usage = ChargeUsage::from_events(vec![10.0, 100.0, 1000.0]);
+        // 1 raised to 2, 10 kept, 100 capped to 15
+        assert_close(price(&properties, &usage).unwrap(), 27.0);
+    }
+
+    #[test]
+    fn prices_graduated_percentage() {
+        let properties = ChargeProperties::GraduatedPercentage(GraduatedPercentageProperties {
+            graduated_percentage_ranges: vec![
+                GraduatedPercentageRange {
+                    from_value: 0,
+                    to_value: Some(100),
+                    rate: "10".to_string(),
+                    flat_amount: "1".to_string(),
+                },
+                GraduatedPercentageRange {
+                    from_value: 101,
+                    to_value: None,
+                    rate: "5".to_string(),
+                    flat_amount: "0".to_string(),
+                },
+            ],
+            ..Default::default()
+        });
+
+        // 1 + 100 * 10% + 100 * 5%
+        assert_close(price(&properties, &ChargeUsage::new(200.0)).unwrap(), 16.0);
+    }
+
+    #[test]
+    fn rejects_invalid_properties() {
+        let properties = ChargeProperties::Standard(StandardProperties {
+            amount: "abc".to_string(),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            price(&properties, &ChargeUsage::new(1.0)),
+            Err(LagoError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn rounds_to_subunits() {
+        let estimate = FeeEstimate {
+            units: 3.0,
+            amount: 12.345,
+            true_up_amount: 0.0,
+        };
+
+        assert_eq!(estimate.amount_cents(), 1235);
+        assert_eq!(estimate.amount_in_subunits(0), 12);
+    }
+}
@@ -0,0 +1,334 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::{add_on::AddOnFilter, common::ListFilters};
+use crate::models::PaginationParams;
+
+/// Request parameters for retrieving a specific add-on.
+#[derive(Debug, Clone)]
+pub struct GetAddOnRequest {
+    pub code: String,
+}
+
+impl GetAddOnRequest {
+    /// Creates a new get add-on request.
+    ///
+    /// # Arguments
+    /// * `code` - The unique code of the add-on to retrieve
+    ///
+    /// # Returns
+    /// A new `GetAddOnRequest` instance
+    pub fn new(code: String) -> Self {
+        Self { code }
+    }
+}
+
+/// Input for creating an add-on.
+///
+/// This struct contains the data needed to create a new add-on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAddOnInput {
+    pub name: String,
+    pub code: String,
+    pub amount_cents: i64,
+    pub amount_currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_codes: Option<Vec<String>>,
+}
+
+impl CreateAddOnInput {
+    /// Creates a new add-on input.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the add-on
+    /// * `code` - The unique code for the add-on
+    /// * `amount_cents` - The default price in cents
+    /// * `amount_currency` - The currency code (e.g., "USD")
+    ///
+    /// # Returns
+    /// A new `CreateAddOnInput` instance
+    pub fn new(name: String, code: String, amount_cents: i64, amount_currency: String) -> Self {
+        Self {
+            name,
+            code,
+            amount_cents,
+            amount_currency,
+            invoice_display_name: None,
+            description: None,
+            tax_codes: None,
+        }
+    }
+
+    /// Sets the name displayed on invoices.
+    ///
+    /// # Arguments
+    /// * `invoice_display_name` - The name displayed on invoices
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_invoice_display_name(mut self, invoice_display_name: String) -> Self {
+        self.invoice_display_name = Some(invoice_display_name);
+        self
+    }
+
+    /// Sets the description of the add-on.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the add-on
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the tax codes applied to the add-on.
+    ///
+    /// # Arguments
+    /// * `tax_codes` - The codes of the taxes to apply
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_tax_codes(mut self, tax_codes: Vec<String>) -> Self {
+        self.tax_codes = Some(tax_codes);
+        self
+    }
+}
+
+/// Request for creating an add-on.
+///
+/// This struct wraps the create add-on input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAddOnRequest {
+    pub add_on: CreateAddOnInput,
+}
+
+impl CreateAddOnRequest {
+    /// Creates a new create add-on request.
+    ///
+    /// # Arguments
+    /// * `input` - The create add-on input data
+    ///
+    /// # Returns
+    /// A new `CreateAddOnRequest` instance
+    pub fn new(input: CreateAddOnInput) -> Self {
+        Self { add_on: input }
+    }
+}
+
+/// Request parameters for listing add-ons.
+///
+/// This struct combines pagination parameters and add-on-specific filters
+/// to build a comprehensive request for retrieving add-on lists.
+#[derive(Debug, Clone)]
+pub struct ListAddOnsRequest {
+    pub pagination: PaginationParams,
+    pub filters: AddOnFilter,
+}
+
+impl ListAddOnsRequest {
+    /// Creates a new empty list add-ons request.
+    ///
+    /// # Returns
+    /// A new `ListAddOnsRequest` instance with default pagination and no filters.
+    pub fn new() -> Self {
+        Self {
+            pagination: PaginationParams::default(),
+            filters: AddOnFilter::default(),
+        }
+    }
+
+    /// Sets the pagination parameters for the request.
+    ///
+    /// # Arguments
+    /// * `pagination` - The pagination parameters to use
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_pagination(mut self, pagination: PaginationParams) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Sets the add-on filters for the request.
+    ///
+    /// # Arguments
+    /// * `filters` - The add-on filters to apply
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_filters(mut self, filters: AddOnFilter) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Converts the request parameters into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing both pagination and filter criteria.
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = self.pagination.to_query_params();
+        params.extend(self.filters.to_query_params());
+        params
+    }
+}
+
+impl Default for ListAddOnsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Request parameters for deleting an add-on.
+#[derive(Debug, Clone)]
+pub struct DeleteAddOnRequest {
+    pub code: String,
+}
+
+impl DeleteAddOnRequest {
+    /// Creates a new delete add-on request.
+    ///
+    /// # Arguments
+    /// * `code` - The unique code of the add-on to delete
+    ///
+    /// # Returns
+    /// A new `DeleteAddOnRequest` instance
+    pub fn new(code: String) -> Self {
+        Self { code }
+    }
+}
+
+/// Input for updating an add-on.
+///
+/// This struct contains the data that can be updated on an existing add-on.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateAddOnInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_cents: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_codes: Option<Vec<String>>,
+}
+
+impl UpdateAddOnInput {
+    /// Creates a new empty update add-on input.
+    ///
+    /// # Returns
+    /// A new `UpdateAddOnInput` instance with all fields set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name for the add-on.
+    ///
+    /// # Arguments
+    /// * `name` - The new name for the add-on
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Renames the code of the add-on.
+    ///
+    /// # Arguments
+    /// * `code` - The new unique code for the add-on
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_code(mut self, code: String) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sets the default price of the add-on.
+    ///
+    /// # Arguments
+    /// * `amount_cents` - The price in cents
+    /// * `amount_currency` - The currency code (e.g., "USD")
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_amount(mut self, amount_cents: i64, amount_currency: String) -> Self {
+        self.amount_cents = Some(amount_cents);
+        self.amount_currency = Some(amount_currency);
+        self
+    }
+
+    /// Sets the name displayed on invoices.
+    ///
+    /// # Arguments
+    /// * `invoice_display_name` - The name displayed on invoices
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_invoice_display_name(mut self, invoice_display_name: String) -> Self {
+        self.invoice_display_name = Some(invoice_display_name);
+        self
+    }
+
+    /// Sets the description of the add-on.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the add-on
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Replaces the tax codes applied to the add-on.
+    ///
+    /// # Arguments
+    /// * `tax_codes` - The codes of the taxes to apply
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_tax_codes(mut self, tax_codes: Vec<String>) -> Self {
+        self.tax_codes = Some(tax_codes);
+        self
+    }
+}
+
+/// Request for updating an add-on.
+///
+/// This struct wraps the update add-on input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAddOnRequest {
+    pub code: String,
+    pub add_on: UpdateAddOnInput,
+}
+
+impl UpdateAddOnRequest {
+    /// Creates a new update add-on request.
+    ///
+    /// # Arguments
+    /// * `code` - The unique code of the add-on to update
+    /// * `input` - The update add-on input data
+    ///
+    /// # Returns
+    /// A new `UpdateAddOnRequest` instance
+    pub fn new(code: String, input: UpdateAddOnInput) -> Self {
+        Self {
+            code,
+            add_on: input,
+        }
+    }
+}
@@ -163,6 +163,9 @@ impl ApplyCouponInput {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplyCouponRequest {
     pub applied_coupon: ApplyCouponInput,
+    /// Key sent in the `Idempotency-Key` header rather than in the body.
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 impl ApplyCouponRequest {
@@ -176,6 +179,21 @@ impl ApplyCouponRequest {
     pub fn new(input: ApplyCouponInput) -> Self {
         Self {
             applied_coupon: input,
+            idempotency_key: None,
         }
     }
+
+    /// Sets the idempotency key of the request.
+    ///
+    /// A request sent again with the same key applies the coupon only once.
+    ///
+    /// # Arguments
+    /// * `key` - The key sent in the `Idempotency-Key` header
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining
+    pub fn with_idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
 }
@@ -150,12 +150,32 @@ impl CreateCreditNoteInput {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateCreditNoteRequest {
     pub credit_note: CreateCreditNoteInput,
+    /// Key sent in the `Idempotency-Key` header rather than in the body.
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 impl CreateCreditNoteRequest {
     /// Creates a new create credit note request.
     pub fn new(credit_note: CreateCreditNoteInput) -> Self {
-        Self { credit_note }
+        Self {
+            credit_note,
+            idempotency_key: None,
+        }
+    }
+
+    /// Sets the idempotency key of the request.
+    ///
+    /// A request sent again with the same key issues the credit note only once.
+    ///
+    /// # Arguments
+    /// * `key` - The key sent in the `Idempotency-Key` header
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining
+    pub fn with_idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
     }
 }
 
@@ -207,3 +227,66 @@ impl UpdateCreditNoteRequest {
         }
     }
 }
+
+/// Request for voiding a credit note.
+///
+/// Voiding a credit note sets its remaining credit balance to zero.
+#[derive(Debug, Clone)]
+pub struct VoidCreditNoteRequest {
+    /// The Lago ID of the credit note to void
+    pub lago_id: String,
+}
+
+impl VoidCreditNoteRequest {
+    /// Creates a new void credit note request.
+    pub fn new(lago_id: String) -> Self {
+        Self { lago_id }
+    }
+}
+
+/// Request for downloading the PDF of a credit note.
+#[derive(Debug, Clone)]
+pub struct DownloadCreditNoteRequest {
+    /// The Lago ID of the credit note to download
+    pub lago_id: String,
+}
+
+impl DownloadCreditNoteRequest {
+    /// Creates a new download credit note request.
+    pub fn new(lago_id: String) -> Self {
+        Self { lago_id }
+    }
+}
+
+/// Input parameters for estimating a credit note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateCreditNoteInput {
+    /// The Lago ID of the invoice to credit
+    pub invoice_id: String,
+    /// The line items to estimate
+    pub items: Vec<CreateCreditNoteItemInput>,
+}
+
+impl EstimateCreditNoteInput {
+    /// Creates a new estimate credit note input.
+    ///
+    /// # Arguments
+    /// * `invoice_id` - The Lago ID of the invoice to credit
+    /// * `items` - The line items to estimate
+    pub fn new(invoice_id: String, items: Vec<CreateCreditNoteItemInput>) -> Self {
+        Self { invoice_id, items }
+    }
+}
+
+/// Request wrapper for estimating a credit note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateCreditNoteRequest {
+    pub credit_note: EstimateCreditNoteInput,
+}
+
+impl EstimateCreditNoteRequest {
+    /// Creates a new estimate credit note request.
+    pub fn new(credit_note: EstimateCreditNoteInput) -> Self {
+        Self { credit_note }
+    }
+}
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::filters::{common::ListFilters, customer::CustomerFilter};
 use crate::models::{
-    CustomerFinalizeZeroAmountInvoice, CustomerPaymentProvider, CustomerType, PaginationParams,
+    CustomerFinalizeZeroAmountInvoice, CustomerIntegrationType, CustomerPaymentProvider,
+    CustomerType, PaginationParams,
 };
 
 #[derive(Debug, Clone)]
@@ -109,6 +110,10 @@ pub struct CreateCustomerInput {
     pub billing_configuration: Option<CreateCustomerBillingConfiguration>,
     pub shipping_address: Option<CreateCustomerShippingAddress>,
     pub metadata: Option<Vec<CreateCustomerMetadata>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_codes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_customers: Option<Vec<CreateCustomerIntegration>>,
 }
 
 impl CreateCustomerInput {
@@ -146,6 +151,8 @@ impl CreateCustomerInput {
             billing_configuration: None,
             shipping_address: None,
             metadata: None,
+            tax_codes: None,
+            integration_customers: None,
         }
     }
 
@@ -253,6 +260,23 @@ impl CreateCustomerInput {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Sets the codes of the taxes applied to the customer, replacing the
+    /// ones already applied.
+    pub fn with_tax_codes(mut self, tax_codes: Vec<String>) -> Self {
+        self.tax_codes = Some(tax_codes);
+        self
+    }
+
+    /// Sets the settings of the customer in the integrations of the
+    /// organization (Netsuite, Anrok, Xero, ...).
+    pub fn with_integration_customers(
+        mut self,
+        integration_customers: Vec<CreateCustomerIntegration>,
+    ) -> Self {
+        self.integration_customers = Some(integration_customers);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -284,6 +308,66 @@ pub struct CreateCustomerMetadata {
     pub display_in_invoice: bool,
 }
 
+/// Settings of a customer in one of the integrations of the organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomerIntegration {
+    pub integration_type: CustomerIntegrationType,
+    pub integration_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_customer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_with_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsidiary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targeted_object: Option<String>,
+}
+
+impl CreateCustomerIntegration {
+    /// Creates the settings of a customer in an integration.
+    ///
+    /// # Arguments
+    /// * `integration_type` - The type of the integration
+    /// * `integration_code` - The code of the integration in Lago
+    ///
+    /// # Returns
+    /// A new `CreateCustomerIntegration` instance
+    pub fn new(integration_type: CustomerIntegrationType, integration_code: String) -> Self {
+        Self {
+            integration_type,
+            integration_code,
+            external_customer_id: None,
+            sync_with_provider: None,
+            subsidiary_id: None,
+            targeted_object: None,
+        }
+    }
+
+    /// Sets the ID of the customer in the integration.
+    pub fn with_external_customer_id(mut self, external_customer_id: String) -> Self {
+        self.external_customer_id = Some(external_customer_id);
+        self
+    }
+
+    /// Sets whether Lago creates the customer in the integration.
+    pub fn with_sync_with_provider(mut self, sync: bool) -> Self {
+        self.sync_with_provider = Some(sync);
+        self
+    }
+
+    /// Sets the Netsuite subsidiary of the customer.
+    pub fn with_subsidiary_id(mut self, subsidiary_id: String) -> Self {
+        self.subsidiary_id = Some(subsidiary_id);
+        self
+    }
+
+    /// Sets the Hubspot object the customer is synced to (`companies` or `contacts`).
+    pub fn with_targeted_object(mut self, targeted_object: String) -> Self {
+        self.targeted_object = Some(targeted_object);
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateCustomerRequest {
     pub customer: CreateCustomerInput,
@@ -301,3 +385,330 @@ impl CreateCustomerRequest {
         Self { customer }
     }
 }
+
+/// Request for applying taxes to a customer.
+///
+/// The taxes are added to the ones already applied to the customer.
+#[derive(Debug, Clone)]
+pub struct AttachCustomerTaxesRequest {
+    pub external_customer_id: String,
+    pub tax_codes: Vec<String>,
+}
+
+impl AttachCustomerTaxesRequest {
+    /// Creates a new attach customer taxes request.
+    ///
+    /// # Arguments
+    /// * `external_customer_id` - The external ID of the customer
+    /// * `tax_codes` - The codes of the taxes to apply
+    ///
+    /// # Returns
+    /// A new `AttachCustomerTaxesRequest` instance
+    pub fn new(external_customer_id: String, tax_codes: Vec<String>) -> Self {
+        Self {
+            external_customer_id,
+            tax_codes,
+        }
+    }
+}
+
+/// Request for removing taxes from a customer.
+///
+/// The other taxes applied to the customer are kept.
+#[derive(Debug, Clone)]
+pub struct DetachCustomerTaxesRequest {
+    pub external_customer_id: String,
+    pub tax_codes: Vec<String>,
+}
+
+impl DetachCustomerTaxesRequest {
+    /// Creates a new detach customer taxes request.
+    ///
+    /// # Arguments
+    /// * `external_customer_id` - The external ID of the customer
+    /// * `tax_codes` - The codes of the taxes to remove
+    ///
+    /// # Returns
+    /// A new `DetachCustomerTaxesRequest` instance
+    pub fn new(external_customer_id: String, tax_codes: Vec<String>) -> Self {
+        Self {
+            external_customer_id,
+            tax_codes,
+        }
+    }
+}
+
+/// Input for updating a customer.
+///
+/// Only the fields that are set are sent, so the others keep their current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateCustomerInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firstname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zipcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_identification_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_payment_term: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_type: Option<CustomerType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalize_zero_amount_invoice: Option<CustomerFinalizeZeroAmountInvoice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_configuration: Option<CreateCustomerBillingConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<CreateCustomerShippingAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Vec<CreateCustomerMetadata>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_codes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_customers: Option<Vec<CreateCustomerIntegration>>,
+}
+
+impl UpdateCustomerInput {
+    /// Creates a new empty update customer input.
+    ///
+    /// # Returns
+    /// A new `UpdateCustomerInput` instance with all fields set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_firstname(mut self, firstname: String) -> Self {
+        self.firstname = Some(firstname);
+        self
+    }
+
+    pub fn with_lastname(mut self, lastname: String) -> Self {
+        self.lastname = Some(lastname);
+        self
+    }
+
+    pub fn with_email(mut self, email: String) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    pub fn with_address(
+        mut self,
+        address_line1: String,
+        address_line2: Option<String>,
+        city: Option<String>,
+        country: Option<String>,
+        state: Option<String>,
+        zipcode: Option<String>,
+    ) -> Self {
+        self.address_line1 = Some(address_line1);
+        self.address_line2 = address_line2;
+        self.city = city;
+        self.country = country;
+        self.state = state;
+        self.zipcode = zipcode;
+        self
+    }
+
+    pub fn with_phone(mut self, phone: String) -> Self {
+        self.phone = Some(phone);
+        self
+    }
+
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    pub fn with_legal_info(mut self, legal_name: String, legal_number: Option<String>) -> Self {
+        self.legal_name = Some(legal_name);
+        self.legal_number = legal_number;
+        self
+    }
+
+    pub fn with_tax_identification_number(mut self, tax_id: String) -> Self {
+        self.tax_identification_number = Some(tax_id);
+        self
+    }
+
+    pub fn with_timezone(mut self, timezone: String) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    pub fn with_currency(mut self, currency: String) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub fn with_net_payment_term(mut self, days: i32) -> Self {
+        self.net_payment_term = Some(days);
+        self
+    }
+
+    pub fn with_customer_type(mut self, customer_type: CustomerType) -> Self {
+        self.customer_type = Some(customer_type);
+        self
+    }
+
+    pub fn with_finalize_zero_amount_invoice(
+        mut self,
+        setting: CustomerFinalizeZeroAmountInvoice,
+    ) -> Self {
+        self.finalize_zero_amount_invoice = Some(setting);
+        self
+    }
+
+    pub fn with_billing_configuration(
+        mut self,
+        config: CreateCustomerBillingConfiguration,
+    ) -> Self {
+        self.billing_configuration = Some(config);
+        self
+    }
+
+    pub fn with_shipping_address(mut self, address: CreateCustomerShippingAddress) -> Self {
+        self.shipping_address = Some(address);
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: Vec<CreateCustomerMetadata>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the codes of the taxes applied to the customer, replacing the
+    /// ones already applied.
+    pub fn with_tax_codes(mut self, tax_codes: Vec<String>) -> Self {
+        self.tax_codes = Some(tax_codes);
+        self
+    }
+
+    /// Sets the settings of the customer in the integrations of the organization.
+    pub fn with_integration_customers(
+        mut self,
+        integration_customers: Vec<CreateCustomerIntegration>,
+    ) -> Self {
+        self.integration_customers = Some(integration_customers);
+        self
+    }
+}
+
+/// Request for updating a customer.
+///
+/// Lago updates customers through the create endpoint, matching them on
+/// their external ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCustomerRequest {
+    pub external_id: String,
+    pub customer: UpdateCustomerInput,
+}
+
+impl UpdateCustomerRequest {
+    /// Creates a new update customer request.
+    ///
+    /// # Arguments
+    /// * `external_id` - The external ID of the customer to update
+    /// * `input` - The update customer input data
+    ///
+    /// # Returns
+    /// A new `UpdateCustomerRequest` instance
+    pub fn new(external_id: String, input: UpdateCustomerInput) -> Self {
+        Self {
+            external_id,
+            customer: input,
+        }
+    }
+}
+
+/// Request parameters for deleting a customer.
+#[derive(Debug, Clone)]
+pub struct DeleteCustomerRequest {
+    pub external_id: String,
+}
+
+impl DeleteCustomerRequest {
+    /// Creates a new delete customer request.
+    ///
+    /// # Arguments
+    /// * `external_id` - The external ID of the customer to delete
+    ///
+    /// # Returns
+    /// A new `DeleteCustomerRequest` instance
+    pub fn new(external_id: String) -> Self {
+        Self { external_id }
+    }
+}
+
+/// Request parameters for retrieving the portal URL of a customer.
+#[derive(Debug, Clone)]
+pub struct GetCustomerPortalUrlRequest {
+    pub external_id: String,
+}
+
+impl GetCustomerPortalUrlRequest {
+    /// Creates a new get customer portal URL request.
+    ///
+    /// # Arguments
+    /// * `external_id` - The external ID of the customer
+    ///
+    /// # Returns
+    /// A new `GetCustomerPortalUrlRequest` instance
+    pub fn new(external_id: String) -> Self {
+        Self { external_id }
+    }
+}
+
+/// Request parameters for generating a checkout URL, used to collect the
+/// payment method of a customer through their payment provider.
+#[derive(Debug, Clone)]
+pub struct GenerateCustomerCheckoutUrlRequest {
+    pub external_id: String,
+}
+
+impl GenerateCustomerCheckoutUrlRequest {
+    /// Creates a new generate customer checkout URL request.
+    ///
+    /// # Arguments
+    /// * `external_id` - The external ID of the customer
+    ///
+    /// # Returns
+    /// A new `GenerateCustomerCheckoutUrlRequest` instance
+    pub fn new(external_id: String) -> Self {
+        Self { external_id }
+    }
+}
@@ -305,3 +305,75 @@ impl CreateEventRequest {
         Self { event }
     }
 }
+
+/// Maximum number of events accepted by Lago in a single batch call.
+pub const MAX_EVENTS_PER_BATCH: usize = 100;
+
+/// Request for ingesting several usage events at once.
+///
+/// The events are split into chunks of at most [`MAX_EVENTS_PER_BATCH`]
+/// events, each sent as a separate `POST /events/batch` call.
+#[derive(Debug, Clone)]
+pub struct CreateEventsBatchRequest {
+    /// The events to create, in order
+    pub events: Vec<CreateEventInput>,
+    /// Number of events sent per API call
+    pub chunk_size: usize,
+    /// Key the idempotency key of each chunk is derived from
+    pub idempotency_key: Option<String>,
+}
+
+impl CreateEventsBatchRequest {
+    /// Creates a new batch request with the default chunk size.
+    ///
+    /// # Arguments
+    /// * `events` - The events to create
+    ///
+    /// # Returns
+    /// A new `CreateEventsBatchRequest` instance
+    pub fn new(events: Vec<CreateEventInput>) -> Self {
+        Self {
+            events,
+            chunk_size: MAX_EVENTS_PER_BATCH,
+            idempotency_key: None,
+        }
+    }
+
+    /// Sets the idempotency key of the request.
+    ///
+    /// Chunk `n` is sent with the key `{key}-{n}`, so that sending the batch
+    /// again with the same key only replays the chunks that were already ingested.
+    ///
+    /// # Arguments
+    /// * `key` - The key sent in the `Idempotency-Key` header
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining
+    pub fn with_idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Sets the number of events sent per API call.
+    ///
+    /// The value is clamped between 1 and [`MAX_EVENTS_PER_BATCH`].
+    ///
+    /// # Arguments
+    /// * `chunk_size` - The number of events per chunk
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.clamp(1, MAX_EVENTS_PER_BATCH);
+        self
+    }
+
+    /// Splits the events into API-sized chunks.
+    ///
+    /// # Returns
+    /// An iterator over the chunks of events, in order
+    pub fn chunks(&self) -> impl Iterator<Item = &[CreateEventInput]> {
+        self.events
+            .chunks(self.chunk_size.clamp(1, MAX_EVENTS_PER_BATCH))
+    }
+}
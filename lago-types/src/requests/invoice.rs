@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::PaginationParams;
+use crate::models::{AddOn, PaginationParams};
 
 use crate::filters::{common::ListFilters, invoice::InvoiceFilters};
 
@@ -500,6 +500,18 @@ impl CreateInvoiceFeeInput {
         }
     }
 
+    /// Creates a fee input charging an add-on at its default price.
+    ///
+    /// # Arguments
+    /// * `add_on` - The add-on to charge
+    /// * `units` - The number of units to charge
+    ///
+    /// # Returns
+    /// A new `CreateInvoiceFeeInput` instance
+    pub fn from_add_on(add_on: &AddOn, units: f64) -> Self {
+        Self::new(add_on.code.clone(), units)
+    }
+
     /// Sets a custom unit amount in cents.
     pub fn with_unit_amount_cents(mut self, unit_amount_cents: i64) -> Self {
         self.unit_amount_cents = Some(unit_amount_cents);
@@ -561,6 +573,9 @@ impl CreateInvoiceInput {
 pub struct CreateInvoiceRequest {
     /// The invoice input data.
     pub invoice: CreateInvoiceInput,
+    /// Key sent in the `Idempotency-Key` header rather than in the body.
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 impl CreateInvoiceRequest {
@@ -572,7 +587,25 @@ impl CreateInvoiceRequest {
     /// # Returns
     /// A new `CreateInvoiceRequest` instance
     pub fn new(input: CreateInvoiceInput) -> Self {
-        Self { invoice: input }
+        Self {
+            invoice: input,
+            idempotency_key: None,
+        }
+    }
+
+    /// Sets the idempotency key of the request.
+    ///
+    /// A request sent again with the same key, e.g. after a restart, creates
+    /// the invoice only once.
+    ///
+    /// # Arguments
+    /// * `key` - The key sent in the `Idempotency-Key` header
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining
+    pub fn with_idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
     }
 }
 
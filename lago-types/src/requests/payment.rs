@@ -221,6 +221,9 @@ impl CreatePaymentInput {
 pub struct CreatePaymentRequest {
     /// The payment input data.
     pub payment: CreatePaymentInput,
+    /// Key sent in the `Idempotency-Key` header rather than in the body.
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 impl CreatePaymentRequest {
@@ -232,6 +235,24 @@ impl CreatePaymentRequest {
     /// # Returns
     /// A new `CreatePaymentRequest` instance.
     pub fn new(input: CreatePaymentInput) -> Self {
-        Self { payment: input }
+        Self {
+            payment: input,
+            idempotency_key: None,
+        }
+    }
+
+    /// Sets the idempotency key of the request.
+    ///
+    /// Derive it from your own records, e.g. the order being paid, so that
+    /// the payment can be sent again after a restart without being applied twice.
+    ///
+    /// # Arguments
+    /// * `key` - The key sent in the `Idempotency-Key` header
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining
+    pub fn with_idempotency_key(mut self, key: String) -> Self {
+        self.idempotency_key = Some(key);
+        self
     }
 }
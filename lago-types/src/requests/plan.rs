@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::filters::common::ListFilters;
 use crate::filters::plan::PlanFilters;
-use crate::models::{ChargeModel, PaginationParams, PlanInterval};
+use crate::models::{ChargeModel, ChargeProperties, PaginationParams, PlanInterval};
 
 /// Request parameters for listing plans.
 #[derive(Debug, Clone)]
@@ -150,6 +150,13 @@ impl CreatePlanChargeInput {
         self
     }
 
+    /// Sets typed charge properties, along with the matching charge model.
+    pub fn with_charge_properties(mut self, properties: ChargeProperties) -> Self {
+        self.charge_model = properties.charge_model();
+        self.properties = Some(properties.to_value());
+        self
+    }
+
     /// Sets the tax codes.
     pub fn with_tax_codes(mut self, tax_codes: Vec<String>) -> Self {
         self.tax_codes = Some(tax_codes);
@@ -1,9 +1,25 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::{ChargeModel, FixedChargeModel, PaginationParams};
+use crate::error::{LagoError, Result};
+use crate::models::{ChargeModel, ChargeProperties, FixedChargeModel, PaginationParams};
 
 // ─── Charge input types ──────────────────────────────────────────────────────
 
+/// Returns the untyped value of properties set on a charge with the given model.
+fn properties_for(
+    charge_model: &ChargeModel,
+    properties: &ChargeProperties,
+) -> Result<serde_json::Value> {
+    if properties.charge_model() != *charge_model {
+        return Err(LagoError::InvalidInput(format!(
+            "{:?} properties don't apply to a {:?} charge",
+            properties.charge_model(),
+            charge_model
+        )));
+    }
+    Ok(properties.to_value())
+}
+
 /// Input data for creating a standalone charge on a plan.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateChargeInput {
@@ -83,6 +99,13 @@ impl CreateChargeInput {
         self
     }
 
+    /// Sets typed charge properties, along with the matching charge model.
+    pub fn with_charge_properties(mut self, properties: ChargeProperties) -> Self {
+        self.charge_model = properties.charge_model();
+        self.properties = Some(properties.to_value());
+        self
+    }
+
     pub fn with_tax_codes(mut self, tax_codes: Vec<String>) -> Self {
         self.tax_codes = Some(tax_codes);
         self
@@ -120,6 +143,23 @@ impl UpdateChargeInput {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Sets typed charge properties of a charge with the given model.
+    ///
+    /// The charge model can't be changed by an update, so it is only used to
+    /// check the properties.
+    ///
+    /// # Returns
+    /// The updated input, or [`LagoError::InvalidInput`] for properties of
+    /// another model
+    pub fn with_charge_properties(
+        mut self,
+        charge_model: ChargeModel,
+        properties: ChargeProperties,
+    ) -> Result<Self> {
+        self.properties = Some(properties_for(&charge_model, &properties)?);
+        Ok(self)
+    }
 }
 
 /// Filter values used in charge input.
@@ -135,6 +175,22 @@ pub struct ChargeFilterInputValue {
     pub cascade_updates: Option<bool>,
 }
 
+impl ChargeFilterInputValue {
+    /// Sets typed properties of the filter, for a charge with the given model.
+    ///
+    /// # Returns
+    /// The updated filter, or [`LagoError::InvalidInput`] for properties of
+    /// another model
+    pub fn with_charge_properties(
+        mut self,
+        charge_model: ChargeModel,
+        properties: ChargeProperties,
+    ) -> Result<Self> {
+        self.properties = Some(properties_for(&charge_model, &properties)?);
+        Ok(self)
+    }
+}
+
 // ─── Fixed charge input types ────────────────────────────────────────────────
 
 /// Input data for creating a standalone fixed charge on a plan.
@@ -199,6 +255,27 @@ impl CreateFixedChargeInput {
         self.units = Some(units);
         self
     }
+
+    pub fn with_properties(mut self, properties: serde_json::Value) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Sets typed charge properties, along with the matching charge model.
+    ///
+    /// # Returns
+    /// The updated input, or [`LagoError::InvalidInput`] for properties of a
+    /// model fixed charges don't support (package, percentage, ...)
+    pub fn with_charge_properties(mut self, properties: ChargeProperties) -> Result<Self> {
+        self.charge_model = properties.fixed_charge_model().ok_or_else(|| {
+            LagoError::InvalidInput(format!(
+                "fixed charges don't support the {:?} charge model",
+                properties.charge_model()
+            ))
+        })?;
+        self.properties = Some(properties.to_value());
+        Ok(self)
+    }
 }
 
 /// Input data for updating a standalone fixed charge.
@@ -222,6 +299,27 @@ impl UpdateFixedChargeInput {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Sets typed charge properties of a fixed charge with the given model.
+    ///
+    /// # Returns
+    /// The updated input, or [`LagoError::InvalidInput`] for properties of
+    /// another model
+    pub fn with_charge_properties(
+        mut self,
+        charge_model: FixedChargeModel,
+        properties: ChargeProperties,
+    ) -> Result<Self> {
+        if properties.fixed_charge_model() != Some(charge_model.clone()) {
+            return Err(LagoError::InvalidInput(format!(
+                "{:?} properties don't apply to a {:?} fixed charge",
+                properties.charge_model(),
+                charge_model
+            )));
+        }
+        self.properties = Some(properties.to_value());
+        Ok(self)
+    }
 }
 
 // ─── Charge filter input types ───────────────────────────────────────────────
@@ -243,6 +341,20 @@ impl ChargeFilterInput {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Sets typed properties of the filter, for a charge with the given model.
+    ///
+    /// # Returns
+    /// The updated input, or [`LagoError::InvalidInput`] for properties of
+    /// another model
+    pub fn with_charge_properties(
+        mut self,
+        charge_model: ChargeModel,
+        properties: ChargeProperties,
+    ) -> Result<Self> {
+        self.properties = Some(properties_for(&charge_model, &properties)?);
+        Ok(self)
+    }
 }
 
 // ─── Plan charge requests ────────────────────────────────────────────────────
@@ -598,3 +710,47 @@ impl DeletePlanChargeFilterRequest {
         params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PackageProperties, StandardProperties};
+
+    #[test]
+    fn fixed_charge_rejects_unsupported_properties() {
+        let input = CreateFixedChargeInput::new("add_on_1".to_string(), FixedChargeModel::Volume)
+            .with_charge_properties(ChargeProperties::Standard(StandardProperties {
+                amount: "10".to_string(),
+                ..Default::default()
+            }))
+            .unwrap();
+        assert!(matches!(input.charge_model, FixedChargeModel::Standard));
+
+        let result =
+            input.with_charge_properties(ChargeProperties::Package(PackageProperties::default()));
+        assert!(matches!(result, Err(LagoError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn updates_check_properties_against_the_charge_model() {
+        let standard = ChargeProperties::Standard(StandardProperties {
+            amount: "10".to_string(),
+            ..Default::default()
+        });
+
+        let input = UpdateChargeInput::new()
+            .with_charge_properties(ChargeModel::Standard, standard.clone())
+            .unwrap();
+        assert_eq!(input.properties, Some(standard.to_value()));
+        let result =
+            UpdateChargeInput::new().with_charge_properties(ChargeModel::Package, standard.clone());
+        assert!(matches!(result, Err(LagoError::InvalidInput(_))));
+
+        let result = UpdateFixedChargeInput::new()
+            .with_charge_properties(FixedChargeModel::Volume, standard.clone());
+        assert!(matches!(result, Err(LagoError::InvalidInput(_))));
+        let result =
+            ChargeFilterInput::new().with_charge_properties(ChargeModel::Graduated, standard);
+        assert!(matches!(result, Err(LagoError::InvalidInput(_))));
+    }
+}
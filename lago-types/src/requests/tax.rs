@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::{common::ListFilters, tax::TaxFilter};
+use crate::models::PaginationParams;
+
+/// Request parameters for retrieving a specific tax.
+#[derive(Debug, Clone)]
+pub struct GetTaxRequest {
+    pub code: String,
+}
+
+impl GetTaxRequest {
+    /// Creates a new get tax request.
+    ///
+    /// # Arguments
+    /// * `code` - The unique code of the tax to retrieve
+    ///
+    /// # Returns
+    /// A new `GetTaxRequest` instance
+    pub fn new(code: String) -> Self {
+        Self { code }
+    }
+}
+
+/// Input for creating a tax.
+///
+/// This struct contains the data needed to create a new tax.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaxInput {
+    pub name: String,
+    pub code: String,
+    pub rate: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_to_organization: Option<bool>,
+}
+
+impl CreateTaxInput {
+    /// Creates a new tax input.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the tax
+    /// * `code` - The unique code for the tax
+    /// * `rate` - The tax rate (e.g., 20.0 for 20%)
+    ///
+    /// # Returns
+    /// A new `CreateTaxInput` instance
+    pub fn new(name: String, code: String, rate: f64) -> Self {
+        Self {
+            name,
+            code,
+            rate,
+            description: None,
+            applied_to_organization: None,
+        }
+    }
+
+    /// Sets the description of the tax.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the tax
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets whether the tax applies to every customer of the organization.
+    ///
+    /// # Arguments
+    /// * `applied` - Whether the tax is applied by default
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_applied_to_organization(mut self, applied: bool) -> Self {
+        self.applied_to_organization = Some(applied);
+        self
+    }
+}
+
+/// Request for creating a tax.
+///
+/// This struct wraps the create tax input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaxRequest {
+    pub tax: CreateTaxInput,
+}
+
+impl CreateTaxRequest {
+    /// Creates a new create tax request.
+    ///
+    /// # Arguments
+    /// * `input` - The create tax input data
+    ///
+    /// # Returns
+    /// A new `CreateTaxRequest` instance
+    pub fn new(input: CreateTaxInput) -> Self {
+        Self { tax: input }
+    }
+}
+
+/// Request parameters for listing taxes.
+///
+/// This struct combines pagination parameters and tax-specific filters
+/// to build a comprehensive request for retrieving tax lists.
+#[derive(Debug, Clone)]
+pub struct ListTaxesRequest {
+    pub pagination: PaginationParams,
+    pub filters: TaxFilter,
+}
+
+impl ListTaxesRequest {
+    /// Creates a new empty list taxes request.
+    ///
+    /// # Returns
+    /// A new `ListTaxesRequest` instance with default pagination and no filters.
+    pub fn new() -> Self {
+        Self {
+            pagination: PaginationParams::default(),
+            filters: TaxFilter::default(),
+        }
+    }
+
+    /// Sets the pagination parameters for the request.
+    ///
+    /// # Arguments
+    /// * `pagination` - The pagination parameters to use
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_pagination(mut self, pagination: PaginationParams) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Sets the tax filters for the request.
+    ///
+    /// # Arguments
+    /// * `filters` - The tax filters to apply
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_filters(mut self, filters: TaxFilter) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Converts the request parameters into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing both pagination and filter criteria.
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = self.pagination.to_query_params();
+        params.extend(self.filters.to_query_params());
+        params
+    }
+}
+
+impl Default for ListTaxesRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Request parameters for deleting a tax.
+#[derive(Debug, Clone)]
+pub struct DeleteTaxRequest {
+    pub code: String,
+}
+
+impl DeleteTaxRequest {
+    /// Creates a new delete tax request.
+    ///
+    /// # Arguments
+    /// * `code` - The unique code of the tax to delete
+    ///
+    /// # Returns
+    /// A new `DeleteTaxRequest` instance
+    pub fn new(code: String) -> Self {
+        Self { code }
+    }
+}
+
+/// Input for updating a tax.
+///
+/// This struct contains the data that can be updated on an existing tax.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateTaxInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_to_organization: Option<bool>,
+}
+
+impl UpdateTaxInput {
+    /// Creates a new empty update tax input.
+    ///
+    /// # Returns
+    /// A new `UpdateTaxInput` instance with all fields set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name for the tax.
+    ///
+    /// # Arguments
+    /// * `name` - The new name for the tax
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Renames the code of the tax.
+    ///
+    /// # Arguments
+    /// * `code` - The new unique code for the tax
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_code(mut self, code: String) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sets the tax rate.
+    ///
+    /// # Arguments
+    /// * `rate` - The tax rate (e.g., 20.0 for 20%)
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        self.rate = Some(rate);
+        self
+    }
+
+    /// Sets the description of the tax.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the tax
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets whether the tax applies to every customer of the organization.
+    ///
+    /// # Arguments
+    /// * `applied` - Whether the tax is applied by default
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_applied_to_organization(mut self, applied: bool) -> Self {
+        self.applied_to_organization = Some(applied);
+        self
+    }
+}
+
+/// Request for updating a tax.
+///
+/// This struct wraps the update tax input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaxRequest {
+    pub code: String,
+    pub tax: UpdateTaxInput,
+}
+
+impl UpdateTaxRequest {
+    /// Creates a new update tax request.
+    ///
+    /// # Arguments
+    /// * `code` - The unique code of the tax to update
+    /// * `input` - The update tax input data
+    ///
+    /// # Returns
+    /// A new `UpdateTaxRequest` instance
+    pub fn new(code: String, input: UpdateTaxInput) -> Self {
+        Self { code, tax: input }
+    }
+}
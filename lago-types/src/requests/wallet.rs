@@ -0,0 +1,739 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::{common::ListFilters, wallet::WalletTransactionFilter};
+use crate::models::{
+    PaginationParams, RecurringTransactionInterval, RecurringTransactionMethod,
+    RecurringTransactionTrigger, WalletTransactionMetadata,
+};
+
+/// Request parameters for retrieving a specific wallet.
+#[derive(Debug, Clone)]
+pub struct GetWalletRequest {
+    pub lago_id: String,
+}
+
+impl GetWalletRequest {
+    /// Creates a new get wallet request.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the wallet to retrieve
+    ///
+    /// # Returns
+    /// A new `GetWalletRequest` instance
+    pub fn new(lago_id: String) -> Self {
+        Self { lago_id }
+    }
+}
+
+/// Input for a recurring transaction rule on a wallet.
+///
+/// Rules are either triggered on a fixed interval or when the ongoing
+/// balance drops below a credit threshold. When updating a wallet, pass the
+/// `lago_id` of an existing rule to modify it instead of creating a new one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringTransactionRuleInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lago_id: Option<String>,
+    pub trigger: RecurringTransactionTrigger,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<RecurringTransactionInterval>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<RecurringTransactionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granted_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_ongoing_balance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_requires_successful_payment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_metadata: Option<Vec<WalletTransactionMetadata>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_at: Option<String>,
+}
+
+impl RecurringTransactionRuleInput {
+    /// Creates a new rule triggered on a fixed interval.
+    ///
+    /// # Arguments
+    /// * `interval` - The interval at which the wallet is topped up
+    ///
+    /// # Returns
+    /// A new `RecurringTransactionRuleInput` instance
+    pub fn interval(interval: RecurringTransactionInterval) -> Self {
+        Self {
+            interval: Some(interval),
+            ..Self::empty(RecurringTransactionTrigger::Interval)
+        }
+    }
+
+    /// Creates a new rule triggered when the ongoing balance drops below a threshold.
+    ///
+    /// # Arguments
+    /// * `threshold_credits` - The credit balance below which the wallet is topped up
+    ///
+    /// # Returns
+    /// A new `RecurringTransactionRuleInput` instance
+    pub fn threshold(threshold_credits: String) -> Self {
+        Self {
+            threshold_credits: Some(threshold_credits),
+            ..Self::empty(RecurringTransactionTrigger::Threshold)
+        }
+    }
+
+    fn empty(trigger: RecurringTransactionTrigger) -> Self {
+        Self {
+            lago_id: None,
+            trigger,
+            interval: None,
+            threshold_credits: None,
+            method: None,
+            paid_credits: None,
+            granted_credits: None,
+            target_ongoing_balance: None,
+            invoice_requires_successful_payment: None,
+            transaction_metadata: None,
+            started_at: None,
+            expiration_at: None,
+        }
+    }
+
+    /// Sets the Lago ID of the existing rule to update.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the rule
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_lago_id(mut self, lago_id: String) -> Self {
+        self.lago_id = Some(lago_id);
+        self
+    }
+
+    /// Tops up a fixed amount of paid and granted credits.
+    ///
+    /// # Arguments
+    /// * `paid_credits` - The number of paid credits to add
+    /// * `granted_credits` - The number of free credits to add
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_fixed_credits(mut self, paid_credits: String, granted_credits: String) -> Self {
+        self.method = Some(RecurringTransactionMethod::Fixed);
+        self.paid_credits = Some(paid_credits);
+        self.granted_credits = Some(granted_credits);
+        self
+    }
+
+    /// Tops up the credits needed to reach a target ongoing balance.
+    ///
+    /// # Arguments
+    /// * `target_ongoing_balance` - The ongoing balance to reach
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_target_ongoing_balance(mut self, target_ongoing_balance: String) -> Self {
+        self.method = Some(RecurringTransactionMethod::Target);
+        self.target_ongoing_balance = Some(target_ongoing_balance);
+        self
+    }
+
+    /// Sets whether credits are only added once the top-up invoice is paid.
+    ///
+    /// # Arguments
+    /// * `required` - Whether a successful payment is required
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_invoice_requires_successful_payment(mut self, required: bool) -> Self {
+        self.invoice_requires_successful_payment = Some(required);
+        self
+    }
+
+    /// Sets the metadata attached to the transactions created by the rule.
+    ///
+    /// # Arguments
+    /// * `metadata` - The transaction metadata
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_transaction_metadata(mut self, metadata: Vec<WalletTransactionMetadata>) -> Self {
+        self.transaction_metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the date from which the rule applies.
+    ///
+    /// # Arguments
+    /// * `started_at` - The start timestamp (ISO 8601 format)
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_started_at(mut self, started_at: String) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Sets the date after which the rule no longer applies.
+    ///
+    /// # Arguments
+    /// * `expiration_at` - The expiration timestamp (ISO 8601 format)
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_expiration_at(mut self, expiration_at: String) -> Self {
+        self.expiration_at = Some(expiration_at);
+        self
+    }
+}
+
+/// Input for creating a wallet.
+///
+/// This struct contains the data needed to create a new wallet for a customer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletInput {
+    pub external_customer_id: String,
+    pub rate_amount: String,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granted_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_requires_successful_payment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_metadata: Option<Vec<WalletTransactionMetadata>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_transaction_rules: Option<Vec<RecurringTransactionRuleInput>>,
+}
+
+impl CreateWalletInput {
+    /// Creates a new wallet input.
+    ///
+    /// # Arguments
+    /// * `external_customer_id` - The external ID of the customer owning the wallet
+    /// * `rate_amount` - The value of one credit in the wallet currency (e.g., "1.5")
+    /// * `currency` - The currency code (e.g., "USD")
+    ///
+    /// # Returns
+    /// A new `CreateWalletInput` instance
+    pub fn new(external_customer_id: String, rate_amount: String, currency: String) -> Self {
+        Self {
+            external_customer_id,
+            rate_amount,
+            currency,
+            name: None,
+            paid_credits: None,
+            granted_credits: None,
+            expiration_at: None,
+            invoice_requires_successful_payment: None,
+            transaction_metadata: None,
+            recurring_transaction_rules: None,
+        }
+    }
+
+    /// Sets the name of the wallet.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the wallet
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the number of paid credits purchased when the wallet is created.
+    ///
+    /// # Arguments
+    /// * `paid_credits` - The number of paid credits
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_paid_credits(mut self, paid_credits: String) -> Self {
+        self.paid_credits = Some(paid_credits);
+        self
+    }
+
+    /// Sets the number of free credits granted when the wallet is created.
+    ///
+    /// # Arguments
+    /// * `granted_credits` - The number of granted credits
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_granted_credits(mut self, granted_credits: String) -> Self {
+        self.granted_credits = Some(granted_credits);
+        self
+    }
+
+    /// Sets the expiration date of the wallet.
+    ///
+    /// # Arguments
+    /// * `expiration_at` - The expiration timestamp (ISO 8601 format)
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_expiration_at(mut self, expiration_at: String) -> Self {
+        self.expiration_at = Some(expiration_at);
+        self
+    }
+
+    /// Sets whether paid credits are only added once the invoice is paid.
+    ///
+    /// # Arguments
+    /// * `required` - Whether a successful payment is required
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_invoice_requires_successful_payment(mut self, required: bool) -> Self {
+        self.invoice_requires_successful_payment = Some(required);
+        self
+    }
+
+    /// Sets the metadata attached to the initial transactions.
+    ///
+    /// # Arguments
+    /// * `metadata` - The transaction metadata
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_transaction_metadata(mut self, metadata: Vec<WalletTransactionMetadata>) -> Self {
+        self.transaction_metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the recurring transaction rules of the wallet.
+    ///
+    /// # Arguments
+    /// * `rules` - The recurring transaction rules
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_recurring_transaction_rules(
+        mut self,
+        rules: Vec<RecurringTransactionRuleInput>,
+    ) -> Self {
+        self.recurring_transaction_rules = Some(rules);
+        self
+    }
+}
+
+/// Request for creating a wallet.
+///
+/// This struct wraps the create wallet input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletRequest {
+    pub wallet: CreateWalletInput,
+}
+
+impl CreateWalletRequest {
+    /// Creates a new create wallet request.
+    ///
+    /// # Arguments
+    /// * `input` - The create wallet input data
+    ///
+    /// # Returns
+    /// A new `CreateWalletRequest` instance
+    pub fn new(input: CreateWalletInput) -> Self {
+        Self { wallet: input }
+    }
+}
+
+/// Input for updating a wallet.
+///
+/// This struct contains the data that can be updated on an existing wallet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateWalletInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_requires_successful_payment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_transaction_rules: Option<Vec<RecurringTransactionRuleInput>>,
+}
+
+impl UpdateWalletInput {
+    /// Creates a new empty update wallet input.
+    ///
+    /// # Returns
+    /// A new `UpdateWalletInput` instance with all fields set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the wallet.
+    ///
+    /// # Arguments
+    /// * `name` - The new name of the wallet
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the expiration date of the wallet.
+    ///
+    /// # Arguments
+    /// * `expiration_at` - The expiration timestamp (ISO 8601 format)
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_expiration_at(mut self, expiration_at: String) -> Self {
+        self.expiration_at = Some(expiration_at);
+        self
+    }
+
+    /// Sets whether paid credits are only added once the invoice is paid.
+    ///
+    /// # Arguments
+    /// * `required` - Whether a successful payment is required
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_invoice_requires_successful_payment(mut self, required: bool) -> Self {
+        self.invoice_requires_successful_payment = Some(required);
+        self
+    }
+
+    /// Replaces the recurring transaction rules of the wallet.
+    ///
+    /// # Arguments
+    /// * `rules` - The recurring transaction rules
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_recurring_transaction_rules(
+        mut self,
+        rules: Vec<RecurringTransactionRuleInput>,
+    ) -> Self {
+        self.recurring_transaction_rules = Some(rules);
+        self
+    }
+}
+
+/// Request for updating a wallet.
+///
+/// This struct wraps the update wallet input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWalletRequest {
+    pub lago_id: String,
+    pub wallet: UpdateWalletInput,
+}
+
+impl UpdateWalletRequest {
+    /// Creates a new update wallet request.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the wallet to update
+    /// * `input` - The update wallet input data
+    ///
+    /// # Returns
+    /// A new `UpdateWalletRequest` instance
+    pub fn new(lago_id: String, input: UpdateWalletInput) -> Self {
+        Self {
+            lago_id,
+            wallet: input,
+        }
+    }
+}
+
+/// Request parameters for terminating a wallet.
+#[derive(Debug, Clone)]
+pub struct TerminateWalletRequest {
+    pub lago_id: String,
+}
+
+impl TerminateWalletRequest {
+    /// Creates a new terminate wallet request.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the wallet to terminate
+    ///
+    /// # Returns
+    /// A new `TerminateWalletRequest` instance
+    pub fn new(lago_id: String) -> Self {
+        Self { lago_id }
+    }
+}
+
+/// Request parameters for listing the wallets of a customer.
+#[derive(Debug, Clone)]
+pub struct ListWalletsRequest {
+    /// The external customer ID.
+    pub external_customer_id: String,
+    /// Pagination parameters.
+    pub pagination: PaginationParams,
+}
+
+impl ListWalletsRequest {
+    /// Creates a new list wallets request.
+    ///
+    /// # Arguments
+    /// * `external_customer_id` - The external ID of the customer owning the wallets
+    ///
+    /// # Returns
+    /// A new `ListWalletsRequest` instance
+    pub fn new(external_customer_id: String) -> Self {
+        Self {
+            external_customer_id,
+            pagination: PaginationParams::default(),
+        }
+    }
+
+    /// Sets the pagination parameters for the request.
+    ///
+    /// # Arguments
+    /// * `pagination` - The pagination parameters to use
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_pagination(mut self, pagination: PaginationParams) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Converts the request parameters into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing the customer and pagination criteria.
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("external_customer_id", self.external_customer_id.clone())];
+        params.extend(self.pagination.to_query_params());
+        params
+    }
+}
+
+/// Input for creating wallet transactions.
+///
+/// A single call can top up paid credits, grant free credits and void
+/// existing credits; Lago creates one transaction per non-empty amount.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletTransactionInput {
+    pub wallet_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granted_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voided_credits: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_requires_successful_payment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Vec<WalletTransactionMetadata>>,
+}
+
+impl CreateWalletTransactionInput {
+    /// Creates a new wallet transaction input with no amounts set.
+    ///
+    /// # Arguments
+    /// * `wallet_id` - The Lago ID of the wallet
+    ///
+    /// # Returns
+    /// A new `CreateWalletTransactionInput` instance
+    pub fn new(wallet_id: String) -> Self {
+        Self {
+            wallet_id,
+            paid_credits: None,
+            granted_credits: None,
+            voided_credits: None,
+            invoice_requires_successful_payment: None,
+            metadata: None,
+        }
+    }
+
+    /// Creates a new input topping up paid credits.
+    ///
+    /// # Arguments
+    /// * `wallet_id` - The Lago ID of the wallet
+    /// * `paid_credits` - The number of credits to purchase
+    ///
+    /// # Returns
+    /// A new `CreateWalletTransactionInput` instance
+    pub fn top_up(wallet_id: String, paid_credits: String) -> Self {
+        Self::new(wallet_id).with_paid_credits(paid_credits)
+    }
+
+    /// Creates a new input granting free credits.
+    ///
+    /// # Arguments
+    /// * `wallet_id` - The Lago ID of the wallet
+    /// * `granted_credits` - The number of credits to grant
+    ///
+    /// # Returns
+    /// A new `CreateWalletTransactionInput` instance
+    pub fn grant(wallet_id: String, granted_credits: String) -> Self {
+        Self::new(wallet_id).with_granted_credits(granted_credits)
+    }
+
+    /// Creates a new input voiding credits.
+    ///
+    /// # Arguments
+    /// * `wallet_id` - The Lago ID of the wallet
+    /// * `voided_credits` - The number of credits to void
+    ///
+    /// # Returns
+    /// A new `CreateWalletTransactionInput` instance
+    pub fn void(wallet_id: String, voided_credits: String) -> Self {
+        Self::new(wallet_id).with_voided_credits(voided_credits)
+    }
+
+    /// Sets the number of paid credits to purchase.
+    ///
+    /// # Arguments
+    /// * `paid_credits` - The number of paid credits
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_paid_credits(mut self, paid_credits: String) -> Self {
+        self.paid_credits = Some(paid_credits);
+        self
+    }
+
+    /// Sets the number of free credits to grant.
+    ///
+    /// # Arguments
+    /// * `granted_credits` - The number of granted credits
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_granted_credits(mut self, granted_credits: String) -> Self {
+        self.granted_credits = Some(granted_credits);
+        self
+    }
+
+    /// Sets the number of credits to void.
+    ///
+    /// # Arguments
+    /// * `voided_credits` - The number of voided credits
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_voided_credits(mut self, voided_credits: String) -> Self {
+        self.voided_credits = Some(voided_credits);
+        self
+    }
+
+    /// Sets whether paid credits are only added once the invoice is paid.
+    ///
+    /// # Arguments
+    /// * `required` - Whether a successful payment is required
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_invoice_requires_successful_payment(mut self, required: bool) -> Self {
+        self.invoice_requires_successful_payment = Some(required);
+        self
+    }
+
+    /// Sets the metadata attached to the transactions.
+    ///
+    /// # Arguments
+    /// * `metadata` - The transaction metadata
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_metadata(mut self, metadata: Vec<WalletTransactionMetadata>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+}
+
+/// Request for creating wallet transactions.
+///
+/// This struct wraps the create wallet transaction input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletTransactionRequest {
+    pub wallet_transaction: CreateWalletTransactionInput,
+}
+
+impl CreateWalletTransactionRequest {
+    /// Creates a new create wallet transaction request.
+    ///
+    /// # Arguments
+    /// * `input` - The create wallet transaction input data
+    ///
+    /// # Returns
+    /// A new `CreateWalletTransactionRequest` instance
+    pub fn new(input: CreateWalletTransactionInput) -> Self {
+        Self {
+            wallet_transaction: input,
+        }
+    }
+}
+
+/// Request parameters for listing the transactions of a wallet.
+#[derive(Debug, Clone)]
+pub struct ListWalletTransactionsRequest {
+    /// The Lago ID of the wallet.
+    pub wallet_id: String,
+    /// Pagination parameters.
+    pub pagination: PaginationParams,
+    /// Wallet transaction filters.
+    pub filters: WalletTransactionFilter,
+}
+
+impl ListWalletTransactionsRequest {
+    /// Creates a new list wallet transactions request.
+    ///
+    /// # Arguments
+    /// * `wallet_id` - The Lago ID of the wallet
+    ///
+    /// # Returns
+    /// A new `ListWalletTransactionsRequest` instance
+    pub fn new(wallet_id: String) -> Self {
+        Self {
+            wallet_id,
+            pagination: PaginationParams::default(),
+            filters: WalletTransactionFilter::default(),
+        }
+    }
+
+    /// Sets the pagination parameters for the request.
+    ///
+    /// # Arguments
+    /// * `pagination` - The pagination parameters to use
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_pagination(mut self, pagination: PaginationParams) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Sets the wallet transaction filters for the request.
+    ///
+    /// # Arguments
+    /// * `filters` - The wallet transaction filters to apply
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_filters(mut self, filters: WalletTransactionFilter) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Converts the request parameters into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing both pagination and filter criteria.
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = self.pagination.to_query_params();
+        params.extend(self.filters.to_query_params());
+        params
+    }
+}
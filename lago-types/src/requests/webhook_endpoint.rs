@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+
+use crate::filters::{common::ListFilters, webhook_endpoint::WebhookEndpointFilter};
+use crate::models::{PaginationParams, WebhookSignatureAlgorithm};
+
+/// Request parameters for retrieving a specific webhook endpoint.
+#[derive(Debug, Clone)]
+pub struct GetWebhookEndpointRequest {
+    /// The Lago ID of the webhook endpoint to retrieve
+    pub lago_id: String,
+}
+
+impl GetWebhookEndpointRequest {
+    /// Creates a new get webhook endpoint request.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the webhook endpoint to retrieve
+    ///
+    /// # Returns
+    /// A new `GetWebhookEndpointRequest` instance
+    pub fn new(lago_id: String) -> Self {
+        Self { lago_id }
+    }
+}
+
+/// Input for creating a webhook endpoint.
+///
+/// This struct contains the data needed to register a new webhook endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookEndpointInput {
+    pub webhook_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_algo: Option<WebhookSignatureAlgorithm>,
+}
+
+impl CreateWebhookEndpointInput {
+    /// Creates a new webhook endpoint input.
+    ///
+    /// Lago signs the webhooks of the endpoint with a JWT unless another
+    /// algorithm is set with [`Self::with_signature_algo`].
+    ///
+    /// # Arguments
+    /// * `webhook_url` - The URL the webhooks are sent to
+    ///
+    /// # Returns
+    /// A new `CreateWebhookEndpointInput` instance
+    pub fn new(webhook_url: String) -> Self {
+        Self {
+            webhook_url,
+            signature_algo: None,
+        }
+    }
+
+    /// Sets the algorithm used to sign the webhooks of the endpoint.
+    ///
+    /// # Arguments
+    /// * `signature_algo` - The signature algorithm
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_signature_algo(mut self, signature_algo: WebhookSignatureAlgorithm) -> Self {
+        self.signature_algo = Some(signature_algo);
+        self
+    }
+}
+
+/// Request for creating a webhook endpoint.
+///
+/// This struct wraps the create webhook endpoint input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookEndpointRequest {
+    pub webhook_endpoint: CreateWebhookEndpointInput,
+}
+
+impl CreateWebhookEndpointRequest {
+    /// Creates a new create webhook endpoint request.
+    ///
+    /// # Arguments
+    /// * `input` - The create webhook endpoint input data
+    ///
+    /// # Returns
+    /// A new `CreateWebhookEndpointRequest` instance
+    pub fn new(input: CreateWebhookEndpointInput) -> Self {
+        Self {
+            webhook_endpoint: input,
+        }
+    }
+}
+
+/// Request parameters for listing webhook endpoints.
+///
+/// This struct combines pagination parameters and webhook endpoint filters
+/// to build a comprehensive request for retrieving webhook endpoint lists.
+#[derive(Debug, Clone)]
+pub struct ListWebhookEndpointsRequest {
+    pub pagination: PaginationParams,
+    pub filters: WebhookEndpointFilter,
+}
+
+impl ListWebhookEndpointsRequest {
+    /// Creates a new empty list webhook endpoints request.
+    ///
+    /// # Returns
+    /// A new `ListWebhookEndpointsRequest` instance with default pagination and no filters.
+    pub fn new() -> Self {
+        Self {
+            pagination: PaginationParams::default(),
+            filters: WebhookEndpointFilter::default(),
+        }
+    }
+
+    /// Sets the pagination parameters for the request.
+    ///
+    /// # Arguments
+    /// * `pagination` - The pagination parameters to use
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_pagination(mut self, pagination: PaginationParams) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Sets the webhook endpoint filters for the request.
+    ///
+    /// # Arguments
+    /// * `filters` - The webhook endpoint filters to apply
+    ///
+    /// # Returns
+    /// The modified request instance for method chaining.
+    pub fn with_filters(mut self, filters: WebhookEndpointFilter) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Converts the request parameters into HTTP query parameters.
+    ///
+    /// # Returns
+    /// A vector of query parameter tuples containing both pagination and filter criteria.
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = self.pagination.to_query_params();
+        params.extend(self.filters.to_query_params());
+        params
+    }
+}
+
+impl Default for ListWebhookEndpointsRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Input for updating a webhook endpoint.
+///
+/// This struct contains the data that can be updated on an existing webhook endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateWebhookEndpointInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_algo: Option<WebhookSignatureAlgorithm>,
+}
+
+impl UpdateWebhookEndpointInput {
+    /// Creates a new empty update webhook endpoint input.
+    ///
+    /// # Returns
+    /// A new `UpdateWebhookEndpointInput` instance with all fields set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the URL the webhooks are sent to.
+    ///
+    /// # Arguments
+    /// * `webhook_url` - The new URL of the endpoint
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_webhook_url(mut self, webhook_url: String) -> Self {
+        self.webhook_url = Some(webhook_url);
+        self
+    }
+
+    /// Sets the algorithm used to sign the webhooks of the endpoint.
+    ///
+    /// # Arguments
+    /// * `signature_algo` - The signature algorithm
+    ///
+    /// # Returns
+    /// The modified input instance for method chaining.
+    pub fn with_signature_algo(mut self, signature_algo: WebhookSignatureAlgorithm) -> Self {
+        self.signature_algo = Some(signature_algo);
+        self
+    }
+}
+
+/// Request for updating a webhook endpoint.
+///
+/// This struct wraps the update webhook endpoint input in the expected API format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWebhookEndpointRequest {
+    pub lago_id: String,
+    pub webhook_endpoint: UpdateWebhookEndpointInput,
+}
+
+impl UpdateWebhookEndpointRequest {
+    /// Creates a new update webhook endpoint request.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the webhook endpoint to update
+    /// * `input` - The update webhook endpoint input data
+    ///
+    /// # Returns
+    /// A new `UpdateWebhookEndpointRequest` instance
+    pub fn new(lago_id: String, input: UpdateWebhookEndpointInput) -> Self {
+        Self {
+            lago_id,
+            webhook_endpoint: input,
+        }
+    }
+}
+
+/// Request parameters for deleting a webhook endpoint.
+#[derive(Debug, Clone)]
+pub struct DeleteWebhookEndpointRequest {
+    /// The Lago ID of the webhook endpoint to delete
+    pub lago_id: String,
+}
+
+impl DeleteWebhookEndpointRequest {
+    /// Creates a new delete webhook endpoint request.
+    ///
+    /// # Arguments
+    /// * `lago_id` - The Lago ID of the webhook endpoint to delete
+    ///
+    /// # Returns
+    /// A new `DeleteWebhookEndpointRequest` instance
+    pub fn new(lago_id: String) -> Self {
+        Self { lago_id }
+    }
+}
+
+/// Request for retrieving the public key Lago signs JWT webhooks with.
+#[derive(Debug, Clone, Default)]
+pub struct GetWebhookPublicKeyRequest {}
+
+impl GetWebhookPublicKeyRequest {
+    /// Creates a new get webhook public key request.
+    ///
+    /// # Returns
+    /// A new `GetWebhookPublicKeyRequest` instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
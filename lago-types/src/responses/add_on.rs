@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{AddOn, PaginationMeta};
+
+/// Response for retrieving an add-on.
+///
+/// This struct represents the response returned when retrieving an add-on
+/// by its code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAddOnResponse {
+    pub add_on: AddOn,
+}
+
+/// Response for creating an add-on.
+///
+/// This struct represents the response returned when successfully creating
+/// a new add-on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAddOnResponse {
+    pub add_on: AddOn,
+}
+
+/// Response for listing add-ons.
+///
+/// This struct represents the response returned when requesting a list of
+/// add-ons, including pagination metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAddOnsResponse {
+    pub add_ons: Vec<AddOn>,
+    pub meta: PaginationMeta,
+}
+
+/// Response for updating an add-on.
+///
+/// This struct represents the response returned when successfully updating
+/// an existing add-on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAddOnResponse {
+    pub add_on: AddOn,
+}
+
+/// Response for deleting an add-on.
+///
+/// This struct represents the response returned when successfully deleting
+/// an add-on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteAddOnResponse {
+    pub add_on: AddOn,
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::{CreditNote, PaginationMeta};
+use crate::models::{CreditNote, EstimatedCreditNote, PaginationMeta};
 
 /// Response for retrieving a single credit note.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,3 +26,23 @@ pub struct CreateCreditNoteResponse {
 pub struct UpdateCreditNoteResponse {
     pub credit_note: CreditNote,
 }
+
+/// Response for voiding a credit note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoidCreditNoteResponse {
+    pub credit_note: CreditNote,
+}
+
+/// Response for downloading a credit note.
+///
+/// The `file_url` of the credit note is set once its PDF is generated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadCreditNoteResponse {
+    pub credit_note: CreditNote,
+}
+
+/// Response for estimating a credit note.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateCreditNoteResponse {
+    pub estimated_credit_note: EstimatedCreditNote,
+}
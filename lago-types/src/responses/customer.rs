@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::{Customer, PaginationMeta};
+use crate::models::{Customer, CustomerCheckoutUrl, CustomerPortalUrl, PaginationMeta};
 
 /// Response for listing customers.
 ///
@@ -29,3 +29,40 @@ pub struct GetCustomerResponse {
 pub struct CreateCustomerResponse {
     pub customer: Customer,
 }
+
+/// Response for attaching or detaching customer taxes.
+///
+/// This struct represents the customer returned after its taxes were updated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCustomerTaxesResponse {
+    pub customer: Customer,
+}
+
+/// Response for updating a customer.
+///
+/// This struct represents the customer returned after it was updated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCustomerResponse {
+    pub customer: Customer,
+}
+
+/// Response for deleting a customer.
+///
+/// This struct represents the response returned when successfully deleting
+/// a customer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteCustomerResponse {
+    pub customer: Customer,
+}
+
+/// Response for retrieving the portal URL of a customer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetCustomerPortalUrlResponse {
+    pub customer: CustomerPortalUrl,
+}
+
+/// Response for generating a checkout URL for a customer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateCustomerCheckoutUrlResponse {
+    pub customer: CustomerCheckoutUrl,
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::LagoError;
 use crate::models::{Event, PaginationMeta};
 
 /// Response for listing events.
@@ -41,3 +42,52 @@ pub struct EventCreated {
     /// The billable metric code
     pub code: String,
 }
+
+/// Response for a batch of events, with one entry per chunk sent to the API.
+#[derive(Debug)]
+pub struct CreateEventsBatchResponse {
+    /// The outcome of each chunk, in the order the events were given
+    pub chunks: Vec<EventsBatchChunk>,
+}
+
+impl CreateEventsBatchResponse {
+    /// Returns whether every chunk was accepted.
+    pub fn is_success(&self) -> bool {
+        self.chunks.iter().all(EventsBatchChunk::is_success)
+    }
+
+    /// Returns the acknowledgments of all accepted events.
+    pub fn events(&self) -> impl Iterator<Item = &EventCreated> {
+        self.chunks.iter().flat_map(|chunk| chunk.events.iter())
+    }
+
+    /// Returns the transaction IDs of the events in rejected chunks.
+    pub fn failed_transaction_ids(&self) -> Vec<&str> {
+        self.chunks
+            .iter()
+            .filter(|chunk| !chunk.is_success())
+            .flat_map(|chunk| chunk.transaction_ids.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+/// Outcome of a single `POST /events/batch` call.
+///
+/// Lago validates a batch as a whole: when a chunk is rejected, none of its
+/// events are ingested.
+#[derive(Debug)]
+pub struct EventsBatchChunk {
+    /// The transaction IDs of the events sent in this chunk
+    pub transaction_ids: Vec<String>,
+    /// The acknowledgments returned by the API, empty if the chunk was rejected
+    pub events: Vec<EventCreated>,
+    /// The error that rejected the chunk, once retries were exhausted
+    pub error: Option<LagoError>,
+}
+
+impl EventsBatchChunk {
+    /// Returns whether the chunk was accepted.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
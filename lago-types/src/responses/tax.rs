@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{PaginationMeta, Tax};
+
+/// Response for retrieving a tax.
+///
+/// This struct represents the response returned when retrieving a tax
+/// by its code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTaxResponse {
+    pub tax: Tax,
+}
+
+/// Response for creating a tax.
+///
+/// This struct represents the response returned when successfully creating
+/// a new tax.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaxResponse {
+    pub tax: Tax,
+}
+
+/// Response for listing taxes.
+///
+/// This struct represents the response returned when requesting a list of
+/// taxes, including pagination metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListTaxesResponse {
+    pub taxes: Vec<Tax>,
+    pub meta: PaginationMeta,
+}
+
+/// Response for updating a tax.
+///
+/// This struct represents the response returned when successfully updating
+/// an existing tax.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaxResponse {
+    pub tax: Tax,
+}
+
+/// Response for deleting a tax.
+///
+/// This struct represents the response returned when successfully deleting
+/// a tax.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteTaxResponse {
+    pub tax: Tax,
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{PaginationMeta, Wallet, WalletTransaction};
+
+/// Response for retrieving a wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetWalletResponse {
+    pub wallet: Wallet,
+}
+
+/// Response for creating a wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletResponse {
+    pub wallet: Wallet,
+}
+
+/// Response for updating a wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWalletResponse {
+    pub wallet: Wallet,
+}
+
+/// Response for terminating a wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminateWalletResponse {
+    pub wallet: Wallet,
+}
+
+/// Response for listing the wallets of a customer.
+///
+/// This struct represents the response returned when requesting a list of
+/// wallets, including pagination metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWalletsResponse {
+    pub wallets: Vec<Wallet>,
+    pub meta: PaginationMeta,
+}
+
+/// Response for creating wallet transactions.
+///
+/// Lago creates one transaction for each of the paid, granted and voided
+/// amounts present in the request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWalletTransactionResponse {
+    pub wallet_transactions: Vec<WalletTransaction>,
+}
+
+/// Response for listing the transactions of a wallet.
+///
+/// This struct represents the response returned when requesting a list of
+/// wallet transactions, including pagination metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWalletTransactionsResponse {
+    pub wallet_transactions: Vec<WalletTransaction>,
+    pub meta: PaginationMeta,
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{PaginationMeta, WebhookEndpoint, WebhookPublicKey};
+
+/// Response for retrieving a webhook endpoint.
+///
+/// This struct represents the response returned when retrieving a webhook
+/// endpoint by its Lago ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetWebhookEndpointResponse {
+    pub webhook_endpoint: WebhookEndpoint,
+}
+
+/// Response for creating a webhook endpoint.
+///
+/// This struct represents the response returned when successfully creating
+/// a new webhook endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookEndpointResponse {
+    pub webhook_endpoint: WebhookEndpoint,
+}
+
+/// Response for listing webhook endpoints.
+///
+/// This struct represents the response returned when requesting a list of
+/// webhook endpoints, including pagination metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWebhookEndpointsResponse {
+    pub webhook_endpoints: Vec<WebhookEndpoint>,
+    pub meta: PaginationMeta,
+}
+
+/// Response for updating a webhook endpoint.
+///
+/// This struct represents the response returned when successfully updating
+/// an existing webhook endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWebhookEndpointResponse {
+    pub webhook_endpoint: WebhookEndpoint,
+}
+
+/// Response for deleting a webhook endpoint.
+///
+/// This struct represents the response returned when successfully deleting
+/// a webhook endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteWebhookEndpointResponse {
+    pub webhook_endpoint: WebhookEndpoint,
+}
+
+/// Response for retrieving the webhook public key.
+///
+/// This struct represents the response returned when retrieving the public
+/// key of the organization, used to verify JWT signed webhooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetWebhookPublicKeyResponse {
+    pub webhook: WebhookPublicKey,
+}